@@ -0,0 +1,59 @@
+//! Compares traversing a linked list whose nodes were allocated in a
+//! [`Group`] against one whose nodes were allocated on their own in
+//! between the other allocations of a program, simulated by
+//! allocating a buffer of varying size after each node.
+//!
+//! Run with `cargo +nightly bench --bench group_traversal`.
+#![feature(test)]
+extern crate test;
+
+use test::Bencher;
+use unique_pointer::group::Group;
+use unique_pointer::UniquePointer;
+
+const SIZE: u64 = 10_000;
+
+#[derive(Debug)]
+struct Node {
+    value: u64,
+    next: UniquePointer<Node>,
+}
+
+/// links `SIZE` nodes allocated by `alloc`
+fn list(mut alloc: impl FnMut(&mut UniquePointer<Node>)) -> UniquePointer<Node> {
+    let mut head = UniquePointer::<Node>::null();
+    for value in 0..SIZE {
+        let mut node = UniquePointer::<Node>::null();
+        alloc(&mut node);
+        node.write(Node { value, next: head });
+        head = node;
+    }
+    head
+}
+
+fn sum(head: &UniquePointer<Node>) -> u64 {
+    let mut sum = 0;
+    let mut node = head.as_ref();
+    while let Some(current) = node {
+        sum += current.value;
+        node = current.next.as_ref();
+    }
+    sum
+}
+
+#[bench]
+fn bench_traverse_group(b: &mut Bencher) {
+    let group = Group::new();
+    let head = list(|node| unsafe { node.alloc_in_group(&group) });
+    b.iter(|| sum(&head));
+}
+
+#[bench]
+fn bench_traverse_scattered(b: &mut Bencher) {
+    let mut buffers = Vec::new();
+    let head = list(|node| {
+        node.alloc();
+        buffers.push(vec![0u8; 64 + (buffers.len() * 7919) % 512]);
+    });
+    b.iter(|| sum(&head));
+}
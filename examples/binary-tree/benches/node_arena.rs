@@ -0,0 +1,66 @@
+//! Compares building a binary search tree out of nodes allocated from
+//! a [`node_arena::Arena`] against allocating every node on its own.
+//!
+//! Run with `cargo +nightly bench`.
+#![feature(test)]
+extern crate test;
+
+use std::cmp::Ordering;
+
+use binary_tree::node_arena::Arena;
+use binary_tree::{Node, Value};
+use test::Bencher;
+
+const SIZE: u64 = 1000;
+
+fn values() -> impl Iterator<Item = Value<'static>> {
+    (0..SIZE).map(|value| Value::from((value * 367) % SIZE))
+}
+
+#[bench]
+fn bench_insert_arena(b: &mut Bencher) {
+    b.iter(|| {
+        let arena = Arena::new();
+        let root = arena.alloc(Value::from(SIZE / 2));
+        for value in values() {
+            arena.insert(root, value);
+        }
+        arena.len()
+    });
+}
+
+#[bench]
+fn bench_insert_boxed(b: &mut Bencher) {
+    b.iter(|| {
+        let mut nodes = vec![Box::new(Node::new(Value::from(SIZE / 2)))];
+        for value in values() {
+            let mut node = nodes[0].as_mut() as *mut Node;
+            loop {
+                let current = unsafe { &mut *node };
+                let next = match value.cmp(&current.item()) {
+                    Ordering::Equal => break,
+                    Ordering::Less => current.left_mut(),
+                    Ordering::Greater => current.right_mut(),
+                };
+                match next {
+                    Some(next) => node = next,
+                    None => {
+                        let less = value < current.item();
+                        let mut child = Box::new(Node::new(value));
+                        if less {
+                            current.set_left(&mut child);
+                        } else {
+                            current.set_right(&mut child);
+                        }
+                        nodes.push(child);
+                        break;
+                    }
+                }
+            }
+        }
+        for node in nodes.iter_mut() {
+            node.parent = unique_pointer::UniquePointer::null();
+        }
+        nodes.len()
+    });
+}
@@ -3,7 +3,8 @@ pub use traits::ListValue;
 pub mod value;
 pub use value::Value;
 pub mod node;
-pub use node::{subtree_delete, Node};
+pub use node::{subtree_delete, swap_nodes, Node, RangeIter, Side};
+pub mod node_arena;
 pub mod color;
 pub mod macros;
 pub mod test;
@@ -1,7 +1,30 @@
+use std::cmp::Ordering;
 use std::convert::{AsMut, AsRef};
+use std::hash::{Hash, Hasher};
+use std::ops::{Bound, Range, RangeBounds};
 
 use crate::Value;
-use unique_pointer::{RefCounter, UniquePointer};
+use unique_pointer::{GraphNode, RefCounter, UniquePointer};
+
+/// `Side` tells apart the two children of a [`Node`], such that
+/// code symmetric in left and right, like rotations, is written once
+/// in terms of a side and its [opposite](Side::opposite).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    pub const BOTH: [Side; 2] = [Side::Left, Side::Right];
+
+    pub fn opposite(self) -> Side {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
 
 pub struct Node<'c> {
     pub parent: UniquePointer<Node<'c>>,
@@ -84,82 +107,164 @@ impl<'c> Node<'c> {
         }
     }
 
-    pub fn set_left(&mut self, left: &mut Node<'c>) {
-        self.incr_ref();
-        left.parent = self.ptr();
-        self.left = left.ptr();
-        left.incr_ref();
+    /// returns the pointer to the child of `self` on `side`
+    fn link(&self, side: Side) -> &UniquePointer<Node<'c>> {
+        match side {
+            Side::Left => &self.left,
+            Side::Right => &self.right,
+        }
     }
 
-    pub fn set_right(&mut self, right: &mut Node<'c>) {
+    fn link_mut(&mut self, side: Side) -> &mut UniquePointer<Node<'c>> {
+        match side {
+            Side::Left => &mut self.left,
+            Side::Right => &mut self.right,
+        }
+    }
+
+    pub fn child(&self, side: Side) -> Option<&'c Node<'c>> {
+        self.link(side).as_ref()
+    }
+
+    pub fn child_mut(&mut self, side: Side) -> Option<&'c mut Node<'c>> {
+        self.link_mut(side).as_mut()
+    }
+
+    pub fn child_value(&self, side: Side) -> Option<Value<'c>> {
+        self.child(side)?.value()
+    }
+
+    pub fn child_addr(&self, side: Side) -> usize {
+        self.link(side).addr()
+    }
+
+    pub fn set_child(&mut self, side: Side, child: &mut Node<'c>) {
         self.incr_ref();
-        right.parent = self.ptr();
-        self.right = right.ptr();
-        right.incr_ref();
+        child.parent = self.ptr();
+        *self.link_mut(side) = child.ptr();
+        child.incr_ref();
     }
 
-    pub fn delete_left(&mut self) {
-        if self.left.is_null() {
+    pub fn delete_child(&mut self, side: Side) {
+        let Some(child) = self.child_mut(side) else {
             return;
+        };
+        child.decr_ref();
+        let link = self.link_mut(side);
+        link.dealloc(true);
+        *link = UniquePointer::null();
+    }
+
+    /// unlinks the subtree rooted at `self` from its parent, taking
+    /// the references it held on its ancestors back from them, and
+    /// returns a pointer to `self` suitable for [graft](Self::graft)
+    pub fn detach(&mut self) -> UniquePointer<Node<'c>> {
+        if let Some(side) = self.side() {
+            let parent = self.parent.inner_mut();
+            // subtrees linked before being attached never incremented
+            // the references of their ancestors, hence the parent
+            // holds no more than the references added since it has
+            // been linked to `self`
+            let held = (*self.refs - 1).min(parent.refs().saturating_sub(2));
+            self.decr_ancestors(held);
+            self.refs -= 1;
+            let link = parent.link_mut(side);
+            link.dealloc(true);
+            *link = UniquePointer::null();
+            parent.decr_ref();
+            self.parent.dealloc(true);
+            self.parent = UniquePointer::null();
         }
-        let left = self.left.inner_mut();
-        left.decr_ref();
-        self.left.dealloc(true);
-        self.left = UniquePointer::null();
+        self.ptr()
+    }
+
+    /// attaches `subtree` on `side` of `self`, detaching it from its
+    /// former parent first, and returns the subtree it replaces,
+    /// detached, or NULL
+    pub fn graft(
+        &mut self,
+        side: Side,
+        mut subtree: UniquePointer<Node<'c>>,
+    ) -> UniquePointer<Node<'c>> {
+        let replaced = match self.child_mut(side) {
+            Some(child) => child.detach(),
+            None => UniquePointer::null(),
+        };
+        if let Some(subtree) = subtree.as_mut() {
+            subtree.detach();
+            let held = *subtree.refs - 1;
+            self.set_child(side, subtree);
+            subtree.incr_ancestors(held);
+        }
+        replaced
+    }
+
+    /// returns the side of its parent `self` hangs from, if any
+    pub fn side(&self) -> Option<Side> {
+        let parent = self.parent()?;
+        Side::BOTH
+            .into_iter()
+            .find(|side| parent.child_addr(*side) == self.addr())
+    }
+
+    /// returns an iterator over the children of `self`, left before
+    /// right, skipping the missing ones
+    pub fn children(&self) -> impl Iterator<Item = (Side, &'c Node<'c>)> + '_ {
+        Side::BOTH
+            .into_iter()
+            .filter_map(|side| Some((side, self.child(side)?)))
+    }
+
+    pub fn set_left(&mut self, left: &mut Node<'c>) {
+        self.set_child(Side::Left, left)
+    }
+
+    pub fn set_right(&mut self, right: &mut Node<'c>) {
+        self.set_child(Side::Right, right)
+    }
+
+    pub fn delete_left(&mut self) {
+        self.delete_child(Side::Left)
     }
 
     pub fn left(&self) -> Option<&'c Node<'c>> {
-        let left = self.left.as_ref();
-        left
+        self.child(Side::Left)
     }
 
     pub fn left_mut(&mut self) -> Option<&'c mut Node<'c>> {
-        self.left.as_mut()
+        self.child_mut(Side::Left)
     }
 
     pub fn left_value(&self) -> Option<Value<'c>> {
-        if let Some(left) = self.left() {
-            left.value()
-        } else {
-            None
-        }
+        self.child_value(Side::Left)
     }
 
     pub fn delete_right(&mut self) {
-        if self.right.is_null() {
-            return;
-        }
-        let right = self.right.inner_mut();
-        right.decr_ref();
-        self.right.dealloc(true);
-        self.right = UniquePointer::null();
+        self.delete_child(Side::Right)
     }
 
     pub fn right(&self) -> Option<&'c Node<'c>> {
-        self.right.as_ref()
+        self.child(Side::Right)
     }
 
     pub fn right_mut(&mut self) -> Option<&'c mut Node<'c>> {
-        self.right.as_mut()
+        self.child_mut(Side::Right)
     }
 
     pub fn right_value(&self) -> Option<Value<'c>> {
-        if let Some(right) = self.right() {
-            right.value()
-        } else {
-            None
-        }
+        self.child_value(Side::Right)
     }
 
+    /// returns the number of edges along the longest downward path
+    /// from `self` to a leaf
     pub fn height(&self) -> usize {
-        let mut node = self;
-        let mut vertices = 0;
-
-        while !node.left.is_null() {
-            node = node.left.inner_ref();
-            vertices += 1;
+        let mut height = 0;
+        let mut pending = vec![(self, 0)];
+        while let Some((node, depth)) = pending.pop() {
+            height = height.max(depth);
+            pending.extend(node.children().map(|(_, child)| (child, depth + 1)));
         }
-        vertices
+        height
     }
 
     pub fn depth(&self) -> usize {
@@ -185,11 +290,11 @@ impl<'c> Node<'c> {
     }
 
     pub fn left_addr(&self) -> usize {
-        self.left.addr()
+        self.child_addr(Side::Left)
     }
 
     pub fn right_addr(&self) -> usize {
-        self.right.addr()
+        self.child_addr(Side::Right)
     }
 
     pub fn parent_addr(&self) -> usize {
@@ -200,6 +305,155 @@ impl<'c> Node<'c> {
         *self.refs
     }
 
+    /// returns the smallest value of the binary search tree rooted
+    /// at `self`
+    pub fn first(&self) -> Option<Value<'c>> {
+        self.subtree_first().value()
+    }
+
+    /// returns the greatest value of the binary search tree rooted
+    /// at `self`
+    pub fn last(&self) -> Option<Value<'c>> {
+        let mut node = unsafe { &*(self as *const Node<'c>) };
+        while let Some(right) = node.right() {
+            node = right;
+        }
+        node.value()
+    }
+
+    /// returns an iterator over the values within `range` of the
+    /// binary search tree rooted at `self`, in order, which only
+    /// descends into subtrees that may hold values within `range`.
+    pub fn range<R: RangeBounds<Value<'c>>>(&self, range: R) -> RangeIter<'c> {
+        RangeIter::new(
+            unsafe { &*(self as *const Node<'c>) },
+            range.start_bound().cloned(),
+            range.end_bound().cloned(),
+        )
+    }
+
+    /// returns the number of nodes in the subtree rooted at `self`
+    pub fn subtree_size(&self) -> usize {
+        GraphNode::size(self)
+    }
+
+    /// returns the node at the zero-based position `k` of the
+    /// traversal order of the subtree rooted at `self`, which is the
+    /// `k`-th smallest node when the subtree is a binary search tree.
+    pub fn subtree_at(&self, k: usize) -> Option<&'c Node<'c>> {
+        let mut node = unsafe { &*(self as *const Node<'c>) };
+        let mut k = k;
+        loop {
+            let left_size = node.left().map(|left| left.subtree_size()).unwrap_or(0);
+            if k < left_size {
+                node = node.left()?;
+            } else if k == left_size {
+                return Some(node);
+            } else {
+                k -= left_size + 1;
+                node = node.right()?;
+            }
+        }
+    }
+
+    /// returns the `k`-th smallest value, counting from zero, of the
+    /// binary search tree rooted at `self`
+    pub fn kth_smallest(&self, k: usize) -> Option<Value<'c>> {
+        self.subtree_at(k).and_then(|node| node.value())
+    }
+
+    /// returns the number of values of the subtree rooted at `self`
+    /// which are smaller than `value`, that is the position `value`
+    /// has or would have in traversal order when the subtree is a
+    /// binary search tree.
+    pub fn rank(&self, value: &Value<'c>) -> usize {
+        let mut rank = 0;
+        let mut node = Some(unsafe { &*(self as *const Node<'c>) });
+        while let Some(current) = node {
+            if current.item() < *value {
+                rank += 1 + current.left().map(|left| left.subtree_size()).unwrap_or(0);
+                node = current.right();
+            } else {
+                node = current.left();
+            }
+        }
+        rank
+    }
+
+    /// returns the node holding `value` in the binary search tree
+    /// rooted at `self`
+    pub fn find(&self, value: &Value<'c>) -> Option<&'c Node<'c>> {
+        let mut node = unsafe { &*(self as *const Node<'c>) };
+        loop {
+            node = match node.item().cmp(value) {
+                Ordering::Equal => return Some(node),
+                Ordering::Greater => node.left()?,
+                Ordering::Less => node.right()?,
+            };
+        }
+    }
+
+    /// returns the node holding `value` in the binary search tree
+    /// rooted at `self`
+    pub fn find_mut(&mut self, value: &Value<'c>) -> Option<&'c mut Node<'c>> {
+        let mut node = unsafe { &mut *(self as *mut Node<'c>) };
+        loop {
+            node = match node.item().cmp(value) {
+                Ordering::Equal => return Some(node),
+                Ordering::Greater => node.left_mut()?,
+                Ordering::Less => node.right_mut()?,
+            };
+        }
+    }
+
+    /// returns the first pair of consecutive values of the in-order
+    /// traversal of the subtree rooted at `self` that are in
+    /// descending order, if any
+    pub fn is_bst(&self) -> Result<(), (Value<'c>, Value<'c>)> {
+        let mut stack = Vec::new();
+        let mut node = Some(self);
+        let mut previous: Option<Value<'c>> = None;
+        loop {
+            while let Some(current) = node {
+                stack.push(current);
+                node = current.left();
+            }
+            let Some(current) = stack.pop() else {
+                return Ok(());
+            };
+            let value = current.item();
+            if let Some(previous) = previous.take()
+                && previous > value
+            {
+                return Err((previous, value));
+            }
+            previous = Some(value);
+            node = current.right();
+        }
+    }
+
+    /// returns the values at the positions `range` of the traversal
+    /// order of the subtree rooted at `self`, in order
+    pub fn select_range(&self, range: Range<usize>) -> Vec<Value<'c>> {
+        let mut values = Vec::new();
+        self.collect_range(&range, 0, &mut values);
+        values
+    }
+
+    fn collect_range(&self, range: &Range<usize>, offset: usize, values: &mut Vec<Value<'c>>) {
+        let left_size = self.left().map(|left| left.subtree_size()).unwrap_or(0);
+        let position = offset + left_size;
+        if let Some(left) = self.left().filter(|_| range.start < position) {
+            left.collect_range(range, offset, values);
+        }
+        if range.contains(&position) {
+            values.push(self.item());
+        }
+        if let Some(right) = self.right().filter(|_| position + 1 < range.end) {
+            right.collect_range(range, position + 1, values);
+        }
+    }
+
     pub fn subtree_first(&self) -> &'c Node<'c> {
         if self.left.is_null() {
             let node = self as *const Node<'c>;
@@ -247,6 +501,38 @@ impl<'c> Node<'c> {
         unsafe { &*successor }
     }
 
+    /// returns the node following `self` in the in-order traversal
+    /// of its tree, or `None` if `self` is the last node
+    pub fn try_successor(&self) -> Option<&'c Node<'c>> {
+        self.next_in_order(Side::Right, None)
+    }
+
+    /// returns the node preceding `self` in the in-order traversal
+    /// of its tree, or `None` if `self` is the first node
+    pub fn try_predecessor(&self) -> Option<&'c Node<'c>> {
+        self.next_in_order(Side::Left, None)
+    }
+
+    /// returns the closest node on `side` of `self` in the in-order
+    /// traversal, without climbing past the node at `root` if given
+    fn next_in_order(&self, side: Side, root: Option<usize>) -> Option<&'c Node<'c>> {
+        if let Some(mut node) = self.child(side) {
+            while let Some(child) = node.child(side.opposite()) {
+                node = child;
+            }
+            return Some(node);
+        }
+        let mut node = unsafe { &*(self as *const Node<'c>) };
+        while Some(node.addr()) != root {
+            let parent = node.parent()?;
+            if node.side() == Some(side.opposite()) {
+                return Some(parent);
+            }
+            node = parent;
+        }
+        None
+    }
+
     pub fn subtree_first_mut(&mut self) -> &'c mut Node<'c> {
         if self.left.is_null() {
             let node = self as *mut Node<'c>;
@@ -510,6 +796,61 @@ pub fn subtree_delete<'c>(node: &mut Node<'c>) {
     }
 }
 
+/// swaps the positions of `a` and `b` within their trees, such that
+/// each takes the parent, side and children of the other, unlike
+/// [`Node::swap_item`] which swaps their items only.
+///
+/// `a` and `b` may be adjacent or either be an ancestor of the other.
+pub fn swap_nodes<'c>(a: &mut Node<'c>, b: &mut Node<'c>) {
+    if a.addr() == b.addr() {
+        return;
+    }
+    let (a_ptr, b_ptr) = (a.ptr(), b.ptr());
+    // pointers to `a` become pointers to `b` and vice-versa
+    let swapped = |node: &Node<'c>| -> UniquePointer<Node<'c>> {
+        if node.addr() == a_ptr.addr() {
+            b_ptr.clone()
+        } else if node.addr() == b_ptr.addr() {
+            a_ptr.clone()
+        } else {
+            node.ptr()
+        }
+    };
+    let position = |node: &Node<'c>| {
+        let parent = node.parent().zip(node.side());
+        let parent = parent.map(|(parent, side)| (swapped(parent), side));
+        let children = Side::BOTH.map(|side| node.child(side).map(swapped));
+        (parent, children)
+    };
+    let (a_position, b_position) = (position(a), position(b));
+
+    for node in [&mut *a, &mut *b] {
+        for side in Side::BOTH {
+            if let Some(child) = node.child_mut(side) {
+                child.detach();
+            }
+        }
+    }
+    a.detach();
+    b.detach();
+
+    for (node, (parent, children)) in [(&mut *b, a_position), (&mut *a, b_position)] {
+        for (side, child) in Side::BOTH.into_iter().zip(children) {
+            if let Some(child) = child {
+                node.graft(side, child);
+            }
+        }
+        // a parent swapped for the other node has just been linked as
+        // one of its children
+        if let Some((mut parent, side)) = parent
+            && parent.addr() != a_ptr.addr()
+            && parent.addr() != b_ptr.addr()
+        {
+            parent.inner_mut().graft(side, node.ptr());
+        }
+    }
+}
+
 /// Node private methods
 impl<'c> Node<'c> {
     pub fn ptr(&self) -> UniquePointer<Node<'c>> {
@@ -539,58 +880,75 @@ impl<'c> Node<'c> {
         }
     }
 
-    fn item_eq(&self, other: &Node<'c>) -> bool {
-        if self.item.addr() == other.item.addr() {
-            self.item.addr() == other.item.addr()
-        } else {
-            self.value() == other.value()
-        }
-    }
-
-    fn left_eq(&self, other: &Node<'c>) -> bool {
-        if self.left.addr() == other.left.addr() {
-            self.left.addr() == other.left.addr()
-        } else {
-            self.left_value() == other.left_value()
+    /// adds `by` to the references of every ancestor of `self`
+    fn incr_ancestors(&mut self, by: usize) {
+        let mut node = self;
+        while let Some(parent) = node.parent_mut() {
+            parent.refs += by;
+            node = parent;
         }
     }
 
-    fn right_eq(&self, other: &Node<'c>) -> bool {
-        if self.right.addr() == other.right.addr() {
-            self.right.addr() == other.right.addr()
-        } else {
-            self.right_value() == other.right_value()
+    /// subtracts `by` from the references of every ancestor of `self`
+    fn decr_ancestors(&mut self, by: usize) {
+        let mut node = self;
+        while let Some(parent) = node.parent_mut() {
+            parent.refs -= by;
+            node = parent;
         }
     }
 
-    fn parent_eq(&self, other: &Node<'c>) -> bool {
-        if self.parent.addr() == other.parent.addr() {
-            self.parent.addr() == other.parent.addr()
-        } else {
-            self.parent_value() == other.parent_value()
+    /// compares the values and shapes of the subtrees rooted at
+    /// `self` and `other`, regardless of their parents
+    fn subtree_eq(&self, other: &Node<'c>) -> bool {
+        let mut pending = vec![(self, other)];
+        while let Some((node, other)) = pending.pop() {
+            if node.addr() == other.addr() {
+                continue;
+            }
+            if node.value() != other.value() {
+                return false;
+            }
+            for side in Side::BOTH {
+                match (node.child(side), other.child(side)) {
+                    (Some(child), Some(other)) => pending.push((child, other)),
+                    (None, None) => {}
+                    _ => return false,
+                }
+            }
         }
+        true
     }
 }
 
+/// nodes are equal when the subtrees rooted at them hold the same
+/// values in the same shape, wherever they are allocated
 impl<'c> PartialEq<Node<'c>> for Node<'c> {
     fn eq(&self, other: &Node<'c>) -> bool {
-        if self.item_eq(other) {
-            let eq = self.value().unwrap_or_default() == other.value().unwrap_or_default();
-            eq
-        } else {
-            false
-        }
+        self.subtree_eq(other)
     }
 }
 
 impl<'c> PartialEq<&mut Node<'c>> for Node<'c> {
     fn eq(&self, other: &&mut Node<'c>) -> bool {
-        let other = unsafe { &**other };
-        if self.item_eq(other) {
-            let eq = self.value().unwrap_or_default() == other.value().unwrap_or_default();
-            eq
-        } else {
-            false
+        self.subtree_eq(other)
+    }
+}
+
+impl<'c> Eq for Node<'c> {}
+
+/// hashes the values of the subtree rooted at `self` in pre-order
+/// along with the missing children, such that equal subtrees hash
+/// alike
+impl<'c> Hash for Node<'c> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut pending = vec![Some(self)];
+        while let Some(node) = pending.pop() {
+            node.map(Node::value).hash(state);
+            if let Some(node) = node {
+                pending.push(node.right());
+                pending.push(node.left());
+            }
         }
     }
 }
@@ -700,3 +1058,82 @@ impl<'c> std::fmt::Debug for Node<'c> {
         )
     }
 }
+
+/// `RangeIter` iterates in order over the values within a range of a
+/// binary search tree, see [`Node::range`].
+pub struct RangeIter<'c> {
+    next: Option<&'c Node<'c>>,
+    root: usize,
+    end: Bound<Value<'c>>,
+}
+
+impl<'c> RangeIter<'c> {
+    /// starts at the smallest node of `root`'s subtree within range,
+    /// skipping left subtrees that lie before it
+    fn new(root: &'c Node<'c>, start: Bound<Value<'c>>, end: Bound<Value<'c>>) -> RangeIter<'c> {
+        let mut next = None;
+        let mut node = Some(root);
+        while let Some(current) = node {
+            let value = current.item();
+            let before_start = match &start {
+                Bound::Included(start) => value < *start,
+                Bound::Excluded(start) => value <= *start,
+                Bound::Unbounded => false,
+            };
+            if before_start {
+                node = current.right();
+            } else {
+                next = Some(current);
+                node = current.left();
+            }
+        }
+        RangeIter {
+            next,
+            root: root.addr(),
+            end,
+        }
+    }
+
+    fn after_end(&self, value: &Value<'c>) -> bool {
+        match &self.end {
+            Bound::Included(end) => value > end,
+            Bound::Excluded(end) => value >= end,
+            Bound::Unbounded => false,
+        }
+    }
+}
+
+impl<'c> Iterator for RangeIter<'c> {
+    type Item = Value<'c>;
+
+    fn next(&mut self) -> Option<Value<'c>> {
+        let node = self.next.take()?;
+        let value = node.item();
+        if self.after_end(&value) {
+            return None;
+        }
+        self.next = node.next_in_order(Side::Right, Some(self.root));
+        Some(value)
+    }
+}
+
+impl<'c> GraphNode for Node<'c> {
+    fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node<'c>>)> {
+        vec![
+            ("parent", &self.parent),
+            ("left", &self.left),
+            ("right", &self.right),
+        ]
+    }
+
+    fn children(&self) -> Vec<&UniquePointer<Node<'c>>> {
+        vec![&self.left, &self.right]
+    }
+
+    fn label(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.value() {
+            Some(value) => write!(f, "{:#?}", value),
+            None => write!(f, "null"),
+        }
+    }
+}
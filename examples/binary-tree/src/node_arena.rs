@@ -0,0 +1,121 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+use unique_pointer::UniquePointer;
+
+use crate::{Node, Value};
+
+/// number of nodes the first chunk of an [`Arena`] holds, each
+/// following chunk holding twice as many as the previous one
+const CHUNK: usize = 64;
+
+/// [Arena](Self) allocates [`Node`]s in chunks that are never
+/// reallocated, such that nodes keep their address for as long as the
+/// arena lives and may be linked to one another through the
+/// [`UniquePointer`](unique_pointer::UniquePointer)s of
+/// [`Node::set_left`] and [`Node::set_right`].
+///
+/// All nodes are dropped together with the arena, which trades the
+/// ability to release individual nodes for one allocation per chunk
+/// instead of one per node.
+///
+/// ```
+/// use binary_tree::node_arena::Arena;
+/// use binary_tree::Value;
+///
+/// let arena = Arena::new();
+/// let root = arena.alloc(Value::from(4u8));
+/// for value in [2u8, 6, 1, 3] {
+///     arena.insert(root, Value::from(value));
+/// }
+/// assert_eq!(arena.len(), 5);
+/// assert_eq!(root.first(), Some(Value::from(1u8)));
+/// assert_eq!(root.left().and_then(|left| left.right_value()), Some(Value::from(3u8)));
+/// ```
+pub struct Arena<'c> {
+    chunks: RefCell<Vec<Vec<Node<'c>>>>,
+}
+
+impl<'c> Arena<'c> {
+    pub fn new() -> Arena<'c> {
+        Arena::with_capacity(CHUNK)
+    }
+
+    /// creates an arena whose first chunk holds `capacity` nodes
+    pub fn with_capacity(capacity: usize) -> Arena<'c> {
+        Arena {
+            chunks: RefCell::new(vec![Vec::with_capacity(capacity.max(1))]),
+        }
+    }
+
+    /// returns the number of nodes allocated so far
+    pub fn len(&self) -> usize {
+        self.chunks.borrow().iter().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// moves a new node holding `value` into the arena
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc(&self, value: Value<'c>) -> &mut Node<'c> {
+        let mut chunks = self.chunks.borrow_mut();
+        let chunk = match chunks.last_mut() {
+            Some(chunk) if chunk.len() < chunk.capacity() => chunk,
+            _ => {
+                let capacity = chunks.last().map(Vec::capacity).unwrap_or(CHUNK) * 2;
+                chunks.push(Vec::with_capacity(capacity));
+                chunks.last_mut().expect("a chunk has just been pushed")
+            }
+        };
+        chunk.push(Node::new(value));
+        let node = chunk.last_mut().expect("a node has just been pushed");
+        // chunks never grow past their capacity, hence never move
+        // their nodes, which live as long as the arena
+        unsafe { &mut *(node as *mut Node<'c>) }
+    }
+
+    /// allocates a node holding `value` and links it into the binary
+    /// search tree rooted at `root`, returning the node holding
+    /// `value`, which is not allocated again if already present
+    #[allow(clippy::mut_from_ref)]
+    pub fn insert(&self, root: &mut Node<'c>, value: Value<'c>) -> &mut Node<'c> {
+        let mut node = unsafe { &mut *(root as *mut Node<'c>) };
+        loop {
+            let next = match value.cmp(&node.item()) {
+                Ordering::Equal => return node,
+                Ordering::Less => node.left_mut(),
+                Ordering::Greater => node.right_mut(),
+            };
+            node = match next {
+                Some(next) => next,
+                None => break,
+            };
+        }
+        let less = value < node.item();
+        let child = self.alloc(value);
+        if less {
+            node.set_left(child);
+        } else {
+            node.set_right(child);
+        }
+        child
+    }
+}
+
+/// unlinks every node from its parent before dropping the nodes, such
+/// that no node updates the references of a parent dropped before it
+impl<'c> Drop for Arena<'c> {
+    fn drop(&mut self) {
+        for node in self.chunks.get_mut().iter_mut().flatten() {
+            node.parent = UniquePointer::null();
+        }
+    }
+}
+
+impl<'c> Default for Arena<'c> {
+    fn default() -> Arena<'c> {
+        Arena::new()
+    }
+}
@@ -1,5 +1,6 @@
 use binary_tree::{subtree_delete, Node, Value};
 use k9::assert_equal;
+use unique_pointer::defer_scope;
 
 struct MitOpenCourseWare6006Tree<'t> {
     pub node_a: Node<'t>,
@@ -10,7 +11,7 @@ struct MitOpenCourseWare6006Tree<'t> {
     pub node_f: Node<'t>,
 }
 impl<'t> MitOpenCourseWare6006Tree<'t> {
-    pub fn initial_state() -> MitOpenCourseWare6006Tree<'t> {
+    pub fn initial_state() -> Box<MitOpenCourseWare6006Tree<'t>> {
         ///|||||||||||||||||||||||||||||||||||||||||||||\\\
         ///                                             \\\
         ///              INITIAL TREE STATE             \\\
@@ -30,12 +31,23 @@ impl<'t> MitOpenCourseWare6006Tree<'t> {
         // Scenario: Create nodes and test the equality of its items
         //
         // Given that I create disconnected nodes with values A through F
-        let mut node_a = Node::new(Value::from("A"));
-        let mut node_b = Node::new(Value::from("B"));
-        let mut node_c = Node::new(Value::from("C"));
-        let mut node_d = Node::new(Value::from("D"));
-        let mut node_e = Node::new(Value::from("E"));
-        let mut node_f = Node::new(Value::from("F"));
+        // in place, since linked nodes must not move
+        let mut tree = Box::new(MitOpenCourseWare6006Tree {
+            node_a: Node::new(Value::from("A")),
+            node_b: Node::new(Value::from("B")),
+            node_c: Node::new(Value::from("C")),
+            node_d: Node::new(Value::from("D")),
+            node_e: Node::new(Value::from("E")),
+            node_f: Node::new(Value::from("F")),
+        });
+        let MitOpenCourseWare6006Tree {
+            node_a,
+            node_b,
+            node_c,
+            node_d,
+            node_e,
+            node_f,
+        } = &mut *tree;
 
         // Then each node has its corresponding value
         assert_equal!(node_a.value(), Some(Value::from("A")));
@@ -49,24 +61,24 @@ impl<'t> MitOpenCourseWare6006Tree<'t> {
         /// Scenario: Connect nodes and check the equality of the items parents ///
         ///                                                                     ///
         /// Given that I set D as in left of B                                  ///
-        node_b.set_left(&mut node_d);
+        node_b.set_left(node_d);
         ///
         ///                                                                     ///
         /// And that I set B as in left of A before setting E as right of B     ///
         /// so as to test that memory references are set correctly*             ///
-        node_a.set_left(&mut node_b);
+        node_a.set_left(node_b);
         ///
         ///                                                                     ///
         /// And that I set C as left of A                                       ///
-        node_a.set_right(&mut node_c);
+        node_a.set_right(node_c);
         ///
         ///                                                                     ///
         /// And that I set E in right of B*                                     ///
-        node_b.set_right(&mut node_e);
+        node_b.set_right(node_e);
         ///
         ///                                                                     ///
         /// And that I set F in left of D                                       ///
-        node_d.set_left(&mut node_f);
+        node_d.set_left(node_f);
         ///
         ///                                                                     ///
         /// Then the parent of node B parent has value "A"                      ///
@@ -93,24 +105,24 @@ impl<'t> MitOpenCourseWare6006Tree<'t> {
         /// Given that all nodes have been connected         ///
         ///                                                  ///
         /// Then the parent of node B is node A              ///
-        assert_equal!(node_b.parent(), Some(&node_a));
+        assert_equal!(node_b.parent(), Some(&*node_a));
         ///
         ///                                                  ///
         /// And the parent of node C is node A               ///
-        assert_equal!(node_c.parent(), Some(&node_a));
+        assert_equal!(node_c.parent(), Some(&*node_a));
         ///
         ///                                                  ///
         ///                                                  ///
         /// And the parent of node D is node B               ///
-        assert_equal!(node_d.parent(), Some(&node_b));
+        assert_equal!(node_d.parent(), Some(&*node_b));
         ///
         ///                                                  ///
         /// And the parent of node E is node B               ///
-        assert_equal!(node_e.parent(), Some(&node_b));
+        assert_equal!(node_e.parent(), Some(&*node_b));
         ///
         ///                                                  ///
         /// And the parent of node F is node D               ///
-        assert_equal!(node_f.parent(), Some(&node_d));
+        assert_equal!(node_f.parent(), Some(&*node_d));
         ///
         ///                                                  ///
 
@@ -121,11 +133,11 @@ impl<'t> MitOpenCourseWare6006Tree<'t> {
         /// Given that all nodes have been connected                                               ///
         ///                                                                                        ///
         /// Then the left of node A is node B                                                      ///
-        assert_equal!(node_a.left(), Some(&node_b));
+        assert_equal!(node_a.left(), Some(&*node_b));
         ///
         ///                                                                                        ///
         /// And the right of node A is node C                                                      ///
-        assert_equal!(node_a.right(), Some(&node_c));
+        assert_equal!(node_a.right(), Some(&*node_c));
         ///
         ///                                                                                        ///
         /// And node A is the root node (no parent)                                                ///
@@ -134,15 +146,15 @@ impl<'t> MitOpenCourseWare6006Tree<'t> {
         ///                                                                                        ///
         ///                                                                                        ///
         /// And the left of node B is node D                                                       ///
-        assert_equal!(node_b.left(), Some(&node_d));
+        assert_equal!(node_b.left(), Some(&*node_d));
         ///
         ///                                                                                        ///
         /// And the right of node B is node E                                                      ///
-        assert_equal!(node_b.right(), Some(&node_e));
+        assert_equal!(node_b.right(), Some(&*node_e));
         ///
         ///                                                                                        ///
         /// And the parent of node B is node A                                                     ///
-        assert_equal!(node_b.parent(), Some(&node_a));
+        assert_equal!(node_b.parent(), Some(&*node_a));
         ///
         ///                                                                                        ///
         /// And node B has no grand-parent                                                         ///
@@ -155,22 +167,22 @@ impl<'t> MitOpenCourseWare6006Tree<'t> {
         assert_equal!(node_c.right(), None);
         ///
         ///                                                                                        ///
-        assert_equal!(node_c.parent(), Some(&node_a));
+        assert_equal!(node_c.parent(), Some(&*node_a));
         ///
         ///                                                                                        ///
         assert_equal!(node_c.parent().unwrap().parent(), None);
         ///
         ///                                                                                        ///
-        assert_equal!(node_d.left(), Some(&node_f));
+        assert_equal!(node_d.left(), Some(&*node_f));
         ///
         ///                                                                                        ///
         assert_equal!(node_d.right(), None);
         ///
         ///                                                                                        ///
-        assert_equal!(node_d.parent(), Some(&node_b));
+        assert_equal!(node_d.parent(), Some(&*node_b));
         ///
         ///                                                                                        ///
-        assert_equal!(node_d.parent().unwrap().parent(), Some(&node_a));
+        assert_equal!(node_d.parent().unwrap().parent(), Some(&*node_a));
         ///
         ///                                                                                        ///
         assert_equal!(node_d.parent().unwrap().parent().unwrap().parent(), None);
@@ -182,15 +194,15 @@ impl<'t> MitOpenCourseWare6006Tree<'t> {
         assert_equal!(node_f.right(), None);
         ///
         ///                                                                                        ///
-        assert_equal!(node_f.parent(), Some(&node_d));
+        assert_equal!(node_f.parent(), Some(&*node_d));
         ///
         ///                                                                                        ///
-        assert_equal!(node_f.parent().unwrap().parent(), Some(&node_b));
+        assert_equal!(node_f.parent().unwrap().parent(), Some(&*node_b));
         ///
         ///                                                                                        ///
         assert_equal!(
             node_f.parent().unwrap().parent().unwrap().parent(),
-            Some(&node_a)
+            Some(&*node_a)
         );
         ///
         ///                                                                                        ///
@@ -225,16 +237,6 @@ impl<'t> MitOpenCourseWare6006Tree<'t> {
         assert_equal!(node_f.refs(), 2);
         ///
         ///                                                                                        ///
-        let mut tree = unsafe {
-            MitOpenCourseWare6006Tree {
-                node_a,
-                node_b,
-                node_c,
-                node_d,
-                node_e,
-                node_f,
-            }
-        };
         assert_equal!(tree.node_a.refs(), 9);
         assert_equal!(tree.node_b.refs(), 8);
         assert_equal!(tree.node_c.refs(), 2);
@@ -242,15 +244,20 @@ impl<'t> MitOpenCourseWare6006Tree<'t> {
         assert_equal!(tree.node_e.refs(), 2);
         assert_equal!(tree.node_f.refs(), 2);
 
-        tree.node_a.dealloc();
-        tree.node_b.dealloc();
-        tree.node_c.dealloc();
-        tree.node_d.dealloc();
-        tree.node_e.dealloc();
-        tree.node_f.dealloc();
+        defer_scope(|scope| {
+            scope.dealloc(&mut tree.node_a, Node::dealloc);
+            scope.dealloc(&mut tree.node_b, Node::dealloc);
+            scope.dealloc(&mut tree.node_c, Node::dealloc);
+            scope.dealloc(&mut tree.node_d, Node::dealloc);
+            scope.dealloc(&mut tree.node_e, Node::dealloc);
+            scope.dealloc(&mut tree.node_f, Node::dealloc);
+        });
 
         unsafe {
-            std::mem::transmute::<MitOpenCourseWare6006Tree, MitOpenCourseWare6006Tree<'t>>(tree)
+            std::mem::transmute::<
+                Box<MitOpenCourseWare6006Tree>,
+                Box<MitOpenCourseWare6006Tree<'t>>,
+            >(tree)
         }
     }
 }
@@ -478,8 +485,8 @@ fn test_tree_operation_subtree_delete_leaf_nodes() {
     // Then node F has no more references
     assert_equal!(tree.node_f.refs(), 1);
 
-    // And node F is dangling in the left of node D
-    assert_equal!(tree.node_d.left(), Some(&tree.node_f));
+    // And node D has no node in its left
+    assert_equal!(tree.node_d.left(), None);
 
     // And node D has 1 reference
     assert_equal!(tree.node_d.refs(), 1);
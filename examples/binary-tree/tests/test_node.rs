@@ -1,5 +1,6 @@
-use binary_tree::{Node, Value};
+use binary_tree::{swap_nodes, Node, Side, Value};
 use k9::assert_equal;
+use unique_pointer::GraphNode;
 
 #[test]
 fn test_node_nil() {
@@ -164,3 +165,487 @@ fn test_clone_non_null() {
     let tree = node.clone();
     assert_equal!(node, tree);
 }
+
+#[test]
+fn test_node_debug_graph() {
+    let mut node_a = Node::new(Value::from("A"));
+    let mut node_b = Node::new(Value::from("B"));
+    node_a.set_left(&mut node_b);
+
+    let graph = format!("{:?}", node_a.ptr().debug_graph(2));
+    assert_equal!(
+        graph.contains(&format!("parent: <cycle {:016x}>", node_a.addr())),
+        true
+    );
+    assert_equal!(graph.contains("left: NULL, right: NULL"), true);
+
+    let graph = format!("{:#?}", node_a.ptr().debug_graph(0));
+    assert_equal!(graph.ends_with("\"A\" {...}"), true);
+}
+
+#[test]
+fn test_node_order_statistics() {
+    ///              4
+    ///            /   \
+    ///           2     6
+    ///          / \     \
+    ///         1   3     7
+    let mut node_4 = Node::new(Value::from(4u8));
+    let mut node_2 = Node::new(Value::from(2u8));
+    let mut node_6 = Node::new(Value::from(6u8));
+    let mut node_1 = Node::new(Value::from(1u8));
+    let mut node_3 = Node::new(Value::from(3u8));
+    let mut node_7 = Node::new(Value::from(7u8));
+    node_2.set_left(&mut node_1);
+    node_2.set_right(&mut node_3);
+    node_6.set_right(&mut node_7);
+    node_4.set_left(&mut node_2);
+    node_4.set_right(&mut node_6);
+
+    assert_equal!(node_4.subtree_size(), 6);
+    assert_equal!(node_2.subtree_size(), 3);
+    assert_equal!(node_6.size(), 2);
+    assert_equal!(node_7.size(), 1);
+    assert_equal!(node_4.count_if(|node| node.item() > Value::from(3u8)), 3);
+    assert_equal!(node_4.count_if(|node| node.leaf()), 3);
+    assert_equal!(node_2.count_if(|node| node.leaf()), 2);
+
+    assert_equal!(node_4.find(&Value::from(3u8)), Some(&node_3));
+    assert_equal!(node_4.find(&Value::from(5u8)), None);
+    assert_equal!(node_2.find(&Value::from(7u8)), None);
+    let found = node_4.find_mut(&Value::from(7u8)).unwrap();
+    assert_equal!(found.addr(), node_7.addr());
+    assert_equal!(found.parent_value(), Some(Value::from(6u8)));
+
+    assert_equal!(node_4.is_bst(), Ok(()));
+    assert_equal!(Node::nil().is_bst(), Ok(()));
+
+    assert_equal!(node_4.kth_smallest(0), Some(Value::from(1u8)));
+    assert_equal!(node_4.kth_smallest(3), Some(Value::from(4u8)));
+    assert_equal!(node_4.kth_smallest(5), Some(Value::from(7u8)));
+    assert_equal!(node_4.kth_smallest(6), None);
+
+    assert_equal!(node_4.rank(&Value::from(1u8)), 0);
+    assert_equal!(node_4.rank(&Value::from(4u8)), 3);
+    assert_equal!(node_4.rank(&Value::from(5u8)), 4);
+    assert_equal!(node_4.rank(&Value::from(8u8)), 6);
+
+    assert_equal!(
+        node_4.select_range(1..4),
+        vec![Value::from(2u8), Value::from(3u8), Value::from(4u8)]
+    );
+    assert_equal!(
+        node_4.select_range(4..10),
+        vec![Value::from(6u8), Value::from(7u8)]
+    );
+    assert_equal!(node_4.select_range(2..2), Vec::<Value>::new());
+}
+
+#[test]
+fn test_node_range_first_last() {
+    ///              4
+    ///            /   \
+    ///           2     6
+    ///          / \   / \
+    ///         1   3 5   7
+    let mut node_4 = Node::new(Value::from(4u8));
+    let mut node_2 = Node::new(Value::from(2u8));
+    let mut node_6 = Node::new(Value::from(6u8));
+    let mut node_1 = Node::new(Value::from(1u8));
+    let mut node_3 = Node::new(Value::from(3u8));
+    let mut node_5 = Node::new(Value::from(5u8));
+    let mut node_7 = Node::new(Value::from(7u8));
+    node_2.set_left(&mut node_1);
+    node_2.set_right(&mut node_3);
+    node_6.set_left(&mut node_5);
+    node_6.set_right(&mut node_7);
+    node_4.set_left(&mut node_2);
+    node_4.set_right(&mut node_6);
+
+    assert_equal!(node_4.first(), Some(Value::from(1u8)));
+    assert_equal!(node_4.last(), Some(Value::from(7u8)));
+    assert_equal!(node_6.first(), Some(Value::from(5u8)));
+
+    assert_equal!(
+        node_4
+            .range(Value::from(3u8)..Value::from(6u8))
+            .collect::<Vec<Value>>(),
+        vec![Value::from(3u8), Value::from(4u8), Value::from(5u8)]
+    );
+    assert_equal!(
+        node_4.range(Value::from(6u8)..).collect::<Vec<Value>>(),
+        vec![Value::from(6u8), Value::from(7u8)]
+    );
+    assert_equal!(
+        node_4.range(..=Value::from(2u8)).collect::<Vec<Value>>(),
+        vec![Value::from(1u8), Value::from(2u8)]
+    );
+    assert_equal!(node_4.range(..).count(), 7);
+    assert_equal!(
+        node_4.range(Value::from(8u8)..).collect::<Vec<Value>>(),
+        Vec::<Value>::new()
+    );
+}
+
+#[test]
+fn test_node_child_by_side() {
+    let mut node = Node::new(Value::from("value"));
+    let mut left = Node::new(Value::from("left"));
+    let mut right = Node::new(Value::from("right"));
+
+    assert_equal!(node.child(Side::Left), None);
+    assert_equal!(node.children().count(), 0);
+
+    node.set_child(Side::Right, &mut right);
+    assert_equal!(node.right_value(), Some(Value::from("right")));
+    assert_equal!(node.child_value(Side::Left), None);
+    assert_equal!(right.side(), Some(Side::Right));
+
+    node.set_child(Side::Left, &mut left);
+    assert_equal!(node.child(Side::Left), Some(&left));
+    assert_equal!(node.child_addr(Side::Left), node.left_addr());
+    assert_equal!(left.side(), Some(Side::Left));
+    assert_equal!(left.side().map(Side::opposite), Some(Side::Right));
+    assert_equal!(node.side(), None);
+
+    let children = node
+        .children()
+        .map(|(side, child)| (side, child.item()))
+        .collect::<Vec<_>>();
+    assert_equal!(
+        children,
+        vec![
+            (Side::Left, Value::from("left")),
+            (Side::Right, Value::from("right")),
+        ]
+    );
+}
+
+#[test]
+fn test_node_detach_and_graft() {
+    let mut a = Node::new(Value::from("A"));
+    let mut b = Node::new(Value::from("B"));
+    let mut c = Node::new(Value::from("C"));
+    let mut d = Node::new(Value::from("D"));
+
+    a.set_left(&mut b);
+    b.set_left(&mut c);
+    assert_equal!(a.refs(), 5);
+    assert_equal!(b.refs(), 4);
+    assert_equal!(c.refs(), 2);
+
+    // Detaching B hands its references on A back
+    let subtree = b.detach();
+    assert_equal!(subtree.addr(), b.addr());
+    assert_equal!(a.left(), None);
+    assert_equal!(b.parent(), None);
+    assert_equal!(b.side(), None);
+    assert_equal!(a.refs(), 1);
+    assert_equal!(b.refs(), 3);
+    assert_equal!(c.refs(), 2);
+    assert_equal!(c.parent_value(), Some(Value::from("B")));
+
+    // Detaching a root is a no-op
+    a.detach();
+    assert_equal!(a.refs(), 1);
+
+    // Grafting B back restores the references it held on A
+    let replaced = a.graft(Side::Right, subtree);
+    assert_equal!(replaced.is_null(), true);
+    assert_equal!(a.right_value(), Some(Value::from("B")));
+    assert_equal!(b.side(), Some(Side::Right));
+    assert_equal!(a.refs(), 5);
+    assert_equal!(b.refs(), 4);
+
+    // Grafting D in place of B hands B back, detached
+    let replaced = a.graft(Side::Right, d.ptr());
+    assert_equal!(replaced.addr(), b.addr());
+    assert_equal!(b.parent(), None);
+    assert_equal!(b.refs(), 3);
+    assert_equal!(a.right_value(), Some(Value::from("D")));
+    assert_equal!(a.refs(), 3);
+    assert_equal!(d.refs(), 2);
+
+    // Grafting C moves it from B onto D
+    c.detach();
+    d.graft(Side::Left, c.ptr());
+    assert_equal!(b.left(), None);
+    assert_equal!(b.refs(), 1);
+    assert_equal!(c.parent_value(), Some(Value::from("D")));
+    assert_equal!(d.refs(), 4);
+    assert_equal!(a.refs(), 5);
+}
+
+#[test]
+fn test_node_detach_subtree_linked_before_attached() {
+    let mut a = Node::new(Value::from("A"));
+    let mut b = Node::new(Value::from("B"));
+    let mut c = Node::new(Value::from("C"));
+
+    b.set_left(&mut c);
+    a.set_left(&mut b);
+    assert_equal!(a.refs(), 3);
+    assert_equal!(b.refs(), 4);
+
+    b.detach();
+    assert_equal!(a.left(), None);
+    assert_equal!(a.refs(), 1);
+    assert_equal!(b.refs(), 3);
+    assert_equal!(c.refs(), 2);
+}
+
+fn values<'c>(node: &Node<'c>) -> (Option<Value<'c>>, Option<Value<'c>>, Option<Value<'c>>) {
+    (node.parent_value(), node.left_value(), node.right_value())
+}
+
+#[test]
+fn test_swap_nodes() {
+    //       A
+    //      / \
+    //     B   C
+    //    / \
+    //   D   E
+    let mut a = Node::new(Value::from("A"));
+    let mut b = Node::new(Value::from("B"));
+    let mut c = Node::new(Value::from("C"));
+    let mut d = Node::new(Value::from("D"));
+    let mut e = Node::new(Value::from("E"));
+    a.set_left(&mut b);
+    a.set_right(&mut c);
+    b.set_left(&mut d);
+    b.set_right(&mut e);
+    let refs = [a.refs(), b.refs(), c.refs(), d.refs(), e.refs()];
+
+    // Swapping siblings swaps their subtrees
+    swap_nodes(&mut b, &mut c);
+    assert_equal!(
+        values(&a),
+        (None, Some(Value::from("C")), Some(Value::from("B")))
+    );
+    assert_equal!(
+        values(&c),
+        (
+            Some(Value::from("A")),
+            Some(Value::from("D")),
+            Some(Value::from("E"))
+        )
+    );
+    assert_equal!(values(&b), (Some(Value::from("A")), None, None));
+    assert_equal!(d.parent_value(), Some(Value::from("C")));
+    assert_equal!(b.side(), Some(Side::Right));
+
+    swap_nodes(&mut b, &mut c);
+    assert_equal!(
+        values(&a),
+        (None, Some(Value::from("B")), Some(Value::from("C")))
+    );
+    assert_equal!(
+        values(&b),
+        (
+            Some(Value::from("A")),
+            Some(Value::from("D")),
+            Some(Value::from("E"))
+        )
+    );
+    assert_equal!([a.refs(), b.refs(), c.refs(), d.refs(), e.refs()], refs);
+
+    // Swapping a parent with its child
+    swap_nodes(&mut a, &mut b);
+    assert_equal!(
+        values(&b),
+        (None, Some(Value::from("A")), Some(Value::from("C")))
+    );
+    assert_equal!(
+        values(&a),
+        (
+            Some(Value::from("B")),
+            Some(Value::from("D")),
+            Some(Value::from("E"))
+        )
+    );
+    assert_equal!(c.parent_value(), Some(Value::from("B")));
+    assert_equal!(e.parent_value(), Some(Value::from("A")));
+
+    // Swapping the root with a leaf it is an ancestor of
+    swap_nodes(&mut b, &mut d);
+    assert_equal!(
+        values(&d),
+        (None, Some(Value::from("A")), Some(Value::from("C")))
+    );
+    assert_equal!(
+        values(&a),
+        (
+            Some(Value::from("D")),
+            Some(Value::from("B")),
+            Some(Value::from("E"))
+        )
+    );
+    assert_equal!(values(&b), (Some(Value::from("A")), None, None));
+    assert_equal!(b.side(), Some(Side::Left));
+}
+
+#[test]
+fn test_node_height_right_skewed() {
+    // 1
+    //  \
+    //   2
+    //    \
+    //     3
+    let mut node_1 = Node::new(Value::from(1u8));
+    let mut node_2 = Node::new(Value::from(2u8));
+    let mut node_3 = Node::new(Value::from(3u8));
+    node_2.set_right(&mut node_3);
+    node_1.set_right(&mut node_2);
+
+    assert_equal!(node_1.height(), 2);
+    assert_equal!(node_2.height(), 1);
+    assert_equal!(node_3.height(), 0);
+}
+
+#[test]
+fn test_node_height_balanced() {
+    //       4
+    //     /   \
+    //    2     6
+    //   / \   / \
+    //  1   3 5   7
+    let mut nodes = (1..=7u8)
+        .map(|value| Node::new(Value::from(value)))
+        .collect::<Vec<_>>();
+    let [node_1, node_2, node_3, node_4, node_5, node_6, node_7] = nodes.as_mut_slice() else {
+        unreachable!()
+    };
+    node_2.set_left(node_1);
+    node_2.set_right(node_3);
+    node_6.set_left(node_5);
+    node_6.set_right(node_7);
+    node_4.set_left(node_2);
+    node_4.set_right(node_6);
+
+    assert_equal!(node_4.height(), 2);
+    assert_equal!(node_6.height(), 1);
+    assert_equal!(node_7.height(), 0);
+
+    // Growing the right subtree deeper than the left one
+    let mut node_8 = Node::new(Value::from(8u8));
+    node_7.set_right(&mut node_8);
+    assert_equal!(node_4.height(), 3);
+    assert_equal!(node_2.height(), 1);
+}
+
+#[test]
+fn test_node_is_bst_reports_first_violation() {
+    //     2
+    //    / \
+    //   1   3
+    //      /
+    //     0
+    let mut node_2 = Node::new(Value::from(2u8));
+    let mut node_1 = Node::new(Value::from(1u8));
+    let mut node_3 = Node::new(Value::from(3u8));
+    let mut node_0 = Node::new(Value::from(0u8));
+    node_2.set_left(&mut node_1);
+    node_2.set_right(&mut node_3);
+    node_3.set_left(&mut node_0);
+
+    assert_equal!(node_3.is_bst(), Ok(()));
+    assert_equal!(node_2.is_bst(), Err((Value::from(2u8), Value::from(0u8))));
+
+    node_2.swap_item(&mut node_1);
+    assert_equal!(node_2.is_bst(), Err((Value::from(2u8), Value::from(1u8))));
+}
+
+#[test]
+fn test_node_eq_and_hash_by_structure() {
+    use std::collections::HashSet;
+
+    //   2      2      2
+    //  / \    / \    /
+    // 1   3  1   3  1
+    let mut trees = (0..3)
+        .map(|_| [1u8, 2, 3].map(|value| Node::new(Value::from(value))))
+        .collect::<Vec<_>>();
+    for (index, [node_1, node_2, node_3]) in trees.iter_mut().enumerate() {
+        node_2.set_left(node_1);
+        if index < 2 {
+            node_2.set_right(node_3);
+        }
+    }
+    assert_equal!(trees[0][1] == trees[1][1], true);
+    assert_equal!(trees[0][1] == trees[2][1], false);
+    assert_equal!(trees[0][0] == trees[2][0], true);
+    assert_equal!(trees[0][2] == trees[2][2], true);
+
+    let subtrees = trees
+        .iter()
+        .flat_map(|nodes| nodes.iter())
+        .collect::<HashSet<&Node>>();
+    assert_equal!(subtrees.len(), 4);
+    assert_equal!(subtrees.contains(&trees[1][1]), true);
+    assert_equal!(subtrees.contains(&trees[2][1]), true);
+    assert_equal!(subtrees.contains(&Node::new(Value::from(3u8))), true);
+    assert_equal!(subtrees.contains(&Node::new(Value::from(2u8))), false);
+}
+
+#[test]
+fn test_node_try_successor_and_predecessor() {
+    //       4
+    //     /   \
+    //    2     6
+    //   / \   /
+    //  1   3 5
+    let mut nodes = (1..=6u8)
+        .map(|value| Node::new(Value::from(value)))
+        .collect::<Vec<_>>();
+    let [node_1, node_2, node_3, node_4, node_5, node_6] = nodes.as_mut_slice() else {
+        unreachable!()
+    };
+    node_2.set_left(node_1);
+    node_2.set_right(node_3);
+    node_6.set_left(node_5);
+    node_4.set_left(node_2);
+    node_4.set_right(node_6);
+
+    let nodes = [&*node_1, node_2, node_3, node_4, node_5, node_6];
+    let successors = nodes
+        .iter()
+        .map(|node| node.try_successor().and_then(Node::value))
+        .collect::<Vec<_>>();
+    assert_equal!(
+        successors,
+        vec![
+            Some(Value::from(2u8)),
+            Some(Value::from(3u8)),
+            Some(Value::from(4u8)),
+            Some(Value::from(5u8)),
+            Some(Value::from(6u8)),
+            None,
+        ]
+    );
+    let predecessors = nodes
+        .iter()
+        .map(|node| node.try_predecessor().and_then(Node::value))
+        .collect::<Vec<_>>();
+    assert_equal!(
+        predecessors,
+        vec![
+            None,
+            Some(Value::from(1u8)),
+            Some(Value::from(2u8)),
+            Some(Value::from(3u8)),
+            Some(Value::from(4u8)),
+            Some(Value::from(5u8)),
+        ]
+    );
+
+    assert_equal!(
+        node_2.range(..).collect::<Vec<_>>(),
+        [1u8, 2, 3].map(Value::from).to_vec()
+    );
+    assert_equal!(
+        node_4
+            .range(Value::from(3u8)..Value::from(6u8))
+            .collect::<Vec<_>>(),
+        [3u8, 4, 5].map(Value::from).to_vec()
+    );
+}
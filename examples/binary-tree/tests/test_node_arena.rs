@@ -0,0 +1,27 @@
+use binary_tree::node_arena::Arena;
+use binary_tree::Value;
+use k9::assert_equal;
+
+#[test]
+fn test_node_arena_insert() {
+    let arena = Arena::with_capacity(2);
+    assert_equal!(arena.is_empty(), true);
+
+    let root = arena.alloc(Value::from(500u64));
+    let addr = root.addr();
+    for value in (0..1000u64).map(|value| (value * 367) % 1000) {
+        arena.insert(root, Value::from(value));
+    }
+    assert_equal!(arena.len(), 1000);
+    assert_equal!(root.addr(), addr);
+    assert_equal!(root.subtree_size(), 1000);
+    assert_equal!(root.is_bst(), Ok(()));
+    assert_equal!(root.first(), Some(Value::from(0u64)));
+    assert_equal!(root.last(), Some(Value::from(999u64)));
+    assert_equal!(root.kth_smallest(367), Some(Value::from(367u64)));
+
+    let node = arena.insert(root, Value::from(367u64));
+    assert_equal!(arena.len(), 1000);
+    assert_equal!(node.value(), Some(Value::from(367u64)));
+    assert_equal!(node.parent().is_some(), true);
+}
@@ -0,0 +1,90 @@
+//! Compares building a list of [`SIZE`] values by prepending them
+//! through [`cons`], by appending them through [`Cell::push_value`],
+//! which walks the whole list on every call, through [`ListBuilder`]
+//! and by converting a `Vec<Value>`.
+//!
+//! Each benchmark also prints the number of allocations made to build
+//! one list, as counted by the global allocator of this benchmark.
+//!
+//! Run with `cargo +nightly bench --bench list_construction -- --nocapture`
+//! for the allocation counts to show.
+#![feature(test)]
+extern crate test;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cons_cell::{cons, Cell, ListBuilder, Value};
+use test::Bencher;
+
+const SIZE: i64 = 64;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// builds one list through `build`, printing how many allocations it
+/// took, then benchmarks `build`
+fn bench_construction(b: &mut Bencher, name: &str, build: impl Fn() -> Cell<'static>) {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let list = build();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    assert_eq!(list.len(), SIZE as usize);
+    eprintln!("{}: {} allocations for {} values", name, allocations, SIZE);
+    b.iter(build);
+}
+
+#[bench]
+fn bench_cons(b: &mut Bencher) {
+    bench_construction(b, "cons", || {
+        let mut list = Cell::nil();
+        for value in (0..SIZE).rev() {
+            list = cons(Value::integer(value), &mut list);
+        }
+        list
+    });
+}
+
+#[bench]
+fn bench_push_value(b: &mut Bencher) {
+    bench_construction(b, "Cell::push_value", || {
+        let mut list = Cell::nil();
+        for value in 0..SIZE {
+            list.push_value(Value::integer(value));
+        }
+        list
+    });
+}
+
+#[bench]
+fn bench_list_builder(b: &mut Bencher) {
+    bench_construction(b, "ListBuilder", || {
+        let mut builder = ListBuilder::new();
+        for value in 0..SIZE {
+            builder.push_value(Value::integer(value));
+        }
+        builder.build()
+    });
+}
+
+#[bench]
+fn bench_vec_conversion(b: &mut Bencher) {
+    bench_construction(b, "Vec<Value>", || {
+        let values = (0..SIZE).map(Value::integer).collect::<Vec<_>>();
+        Cell::from_slice(&values)
+    });
+}
@@ -0,0 +1,28 @@
+//! Compares iterating over a long list by borrowing its values
+//! through [`Value::iter`] against cloning them through
+//! [`ValueIterator`](cons_cell::ValueIterator).
+//!
+//! Run with `cargo +nightly bench`.
+#![feature(test)]
+extern crate test;
+
+use cons_cell::{Cell, Value};
+use test::Bencher;
+
+const SIZE: i64 = 1000;
+
+fn list() -> Value<'static> {
+    Value::list(Cell::from_iter((0..SIZE).map(Value::integer)))
+}
+
+#[bench]
+fn bench_value_ref_iterator(b: &mut Bencher) {
+    let list = list();
+    b.iter(|| list.iter().count());
+}
+
+#[bench]
+fn bench_value_iterator(b: &mut Bencher) {
+    let list = list();
+    b.iter(|| (&list).into_iter().count());
+}
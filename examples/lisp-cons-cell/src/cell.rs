@@ -1,13 +1,15 @@
 #![allow(unused)]
 use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
 use std::hash::{Hash, Hasher};
 use std::iter::{Extend, IntoIterator, Iterator};
 use std::ops::Deref;
 
-use unique_pointer::{RefCounter, UniquePointer};
+use unique_pointer::{GraphNode, RefCounter, UniquePointer};
 
-use crate::{AsSymbol, AsValue, Quotable, Symbol, Value};
+use crate::{AsSymbol, AsValue, FormatOptions, Number, Quotable, Symbol, Value, ValueRefIterator};
 pub trait ListIterator<'c, T: AsCell<'c>>: IntoIterator<Item = T> + Debug + Quotable {
     fn iter_cells(&self) -> Cell<'c>;
 }
@@ -23,6 +25,7 @@ pub struct Cell<'c> {
     pub(crate) tail: UniquePointer<Cell<'c>>,
     pub(crate) refs: RefCounter,
     pub(crate) quoted: bool,
+    pub(crate) length: usize,
 }
 
 impl<'c> Cell<'c> {
@@ -36,6 +39,7 @@ impl<'c> Cell<'c> {
             tail: UniquePointer::<Cell<'c>>::null(),
             refs: RefCounter::new(),
             quoted,
+            length: 0,
         };
         cell.incr_ref();
         if let Some(item) = item {
@@ -70,6 +74,12 @@ impl<'c> Cell<'c> {
         // cell
     }
 
+    /// builds a list of clones of `values` through [`ListBuilder`]
+    /// in `O(n)`.
+    pub fn from_slice(values: &[Value<'c>]) -> Cell<'c> {
+        Cell::from_iter(values.iter().cloned())
+    }
+
     pub fn head(&self) -> Option<Value<'c>> {
         self.head.try_read()
     }
@@ -108,6 +118,7 @@ impl<'c> Cell<'c> {
                     // try new.swap_head(tail);
                 }
             }
+            new.update_length();
             self.tail = UniquePointer::from(new);
         } else {
             // when self.head *IS NOT* null
@@ -119,10 +130,31 @@ impl<'c> Cell<'c> {
                 self.tail.inner_mut().add(&new);
             }
         }
+        self.update_length();
+    }
+
+    /// replaces the tail of `self` with `tail` itself rather than a
+    /// copy of it, such that both lists share structure the way lisp
+    /// lists do.
+    ///
+    /// # Safety
+    ///
+    /// Dropping `self` walks through `tail`, which must therefore
+    /// neither move nor be dropped before `self` is. Lists made
+    /// circular through `setcdr` can never satisfy that for every one
+    /// of their cells and must be leaked, e.g. via
+    /// [`std::mem::forget`].
+    pub unsafe fn setcdr(&mut self, tail: &Cell<'c>) {
+        if tail.is_nil() {
+            self.tail = UniquePointer::null();
+        } else {
+            self.tail = UniquePointer::read_only(tail);
+        }
+        self.update_length();
     }
 
     pub fn pop(&mut self) -> bool {
-        if !self.tail.is_null() {
+        let popped = if !self.tail.is_null() {
             self.tail.drop_in_place();
             self.tail = UniquePointer::null();
             true
@@ -132,45 +164,302 @@ impl<'c> Cell<'c> {
             true
         } else {
             false
-        }
+        };
+        self.update_length();
+        popped
     }
 
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
-    /// `O(n)`
+    /// `O(1)`
     pub fn len(&self) -> usize {
-        let mut len = 0;
-        if !self.head.is_null() {
-            len += 1
-        }
-        if let Some(tail) = self.tail() {
-            len += tail.len();
-        }
-        len
+        self.length
+    }
+
+    /// returns the reference count of the cell
+    pub fn refs(&self) -> usize {
+        self.refs.read()
     }
 
     pub fn tail(&self) -> Option<&'c Cell<'c>> {
         self.tail.as_ref()
     }
 
+    /// returns the values of every cell of the list, visiting each
+    /// cell of circular lists only once.
     pub fn values(&self) -> Vec<Value<'c>> {
-        let mut values = Vec::<Value>::new();
-        if let Some(head) = self.head() {
-            // dbg!(&head);
-            values.push(head.clone());
+        self.cells()
+            .into_iter()
+            .filter_map(|cell| cell.head.as_ref().map(Clone::clone))
+            .collect()
+    }
+
+    /// returns true if the last cell of the list links back to one
+    /// of its cells.
+    pub fn is_circular(&self) -> bool {
+        match self.cells().last() {
+            Some(cell) => cell.tail.is_not_null(),
+            None => false,
+        }
+    }
+
+    /// returns a new list with the values of `self` in reverse
+    /// order, leaving `self` untouched.
+    pub fn reverse(&self) -> Cell<'c> {
+        let mut cell = Cell::from_iter(self.values().into_iter().rev());
+        cell.set_quoted(self.quoted);
+        cell
+    }
+
+    /// destructively reverses the list in place by relinking the
+    /// tails of its cells rather than copying them.
+    ///
+    /// Because the first cell of the list is owned by value, it
+    /// swaps heads with the last cell such that `self` remains the
+    /// first cell of the reversed list, whereas the cells in between
+    /// are relinked in reverse order ahead of the last one. The tail
+    /// of the last cell, a link back in circular lists, is kept.
+    pub fn nreverse(&mut self) {
+        if self.head.is_null() {
+            return;
+        }
+        let mut cells = self.cells_mut();
+        let last = cells.len() - 1;
+        if last == 0 {
+            return;
+        }
+        {
+            let (first, rest) = cells.split_at_mut(1);
+            first[0].swap_head(rest[last - 1]);
+        }
+        // `links[index]` is the pointer from `cells[index]` to
+        // `cells[index + 1]`
+        let mut links = cells[..last]
+            .iter_mut()
+            .map(|cell| std::mem::replace(&mut cell.tail, UniquePointer::null()))
+            .map(Some)
+            .collect::<Vec<Option<UniquePointer<Cell<'c>>>>>();
+        let order = std::iter::once(0)
+            .chain((1..last).rev())
+            .chain(std::iter::once(last))
+            .collect::<Vec<usize>>();
+        for pair in order.windows(2) {
+            if let Some(link) = links[pair[1] - 1].take() {
+                cells[pair[0]].tail = link;
+            }
         }
+        self.update_lengths();
+    }
+
+    /// destructively sorts the list with a stable merge sort
+    /// ordering values by `cmp`.
+    ///
+    /// The pointers to the values are moved between cells rather
+    /// than relinking tails, such that `self` remains the first cell
+    /// of the sorted list and no value is copied.
+    pub fn sort_by(&mut self, mut cmp: impl FnMut(&Value<'c>, &Value<'c>) -> Ordering) {
+        let mut cells = self
+            .cells_mut()
+            .into_iter()
+            .filter(|cell| cell.head.is_not_null())
+            .collect::<Vec<&'c mut Cell<'c>>>();
+        let heads = cells
+            .iter_mut()
+            .map(|cell| std::mem::replace(&mut cell.head, UniquePointer::null()))
+            .collect::<Vec<UniquePointer<Value<'c>>>>();
+        for (cell, head) in cells.into_iter().zip(merge_sort(heads, &mut cmp)) {
+            cell.head = head;
+        }
+    }
+
+    /// returns a copy of the list whose every cell and nested list
+    /// is copied as well, such that no cell is shared with `self`.
+    ///
+    /// The quoting of every cell is preserved and circular lists
+    /// remain circular, except that a list linking back to `self`
+    /// itself keeps linking back to `self`, which therefore must
+    /// outlive the copy.
+    pub fn deep_clone(&self) -> Cell<'c> {
+        self.copy_structure(true)
+    }
+
+    /// copies the cells of the list reached through tails it owns,
+    /// preserving the quoting of every cell, and links the copy to
+    /// the cells `self` shares with other lists through
+    /// [`Cell::setcdr`] rather than copying them, unless `deep`.
+    ///
+    /// Links back to cells already copied, as found in circular
+    /// lists, are redirected to their copies.
+    pub(crate) fn copy_structure(&self, deep: bool) -> Cell<'c> {
+        let copy_cell = |cell: &Cell<'c>| {
+            let head = cell.head.as_ref().map(|value| match value {
+                Value::List(list) if deep => Value::List(list.deep_clone()),
+                Value::QuotedList(list) if deep => Value::QuotedList(list.deep_clone()),
+                value => value.clone(),
+            });
+            Cell::quoted(head, cell.quoted)
+        };
+        let origin = UniquePointer::provenance_of_const_ptr(self);
+        let mut copies = BTreeMap::<usize, UniquePointer<Cell<'c>>>::new();
+        let mut head = copy_cell(self);
+        let mut last = UniquePointer::<Cell<'c>>::null();
+        let mut cell = self;
+        while let Some(tail) = cell.tail() {
+            let address = UniquePointer::provenance_of_const_ptr(tail);
+            let link = if let Some(copy) = copies.get(&address) {
+                Some(copy.clone())
+            } else if address == origin || (cell.tail.is_copy() && !deep) {
+                Some(UniquePointer::read_only(tail))
+            } else {
+                None
+            };
+            let last_cell = if last.is_null() {
+                &mut head
+            } else {
+                last.inner_mut()
+            };
+            if let Some(link) = link {
+                last_cell.tail = link;
+                break;
+            }
+            last_cell.tail = UniquePointer::from(copy_cell(tail));
+            last = UniquePointer::read_only(last_cell.tail.inner_ref());
+            copies.insert(address, last.clone());
+            cell = tail;
+        }
+        head.update_lengths();
+        head
+    }
+
+    /// returns references to every cell of the list, starting from
+    /// `self` and stopping short of revisiting cells of circular
+    /// lists.
+    pub(crate) fn cells(&self) -> Vec<&'c Cell<'c>> {
+        let mut visited = BTreeSet::<usize>::new();
+        let mut cells = Vec::<&'c Cell<'c>>::new();
+        let mut cell = UniquePointer::read_only(self).extend_lifetime();
+        while visited.insert(UniquePointer::provenance_of_const_ptr(cell)) {
+            cells.push(cell);
+            match cell.tail() {
+                Some(tail) => cell = tail,
+                None => break,
+            }
+        }
+        cells
+    }
+
+    /// destructively keeps only the values for which `keep` returns
+    /// true, given their position and value, by moving the heads
+    /// of kept cells towards the front of the list and truncating
+    /// the cells left over.
+    ///
+    /// Returns the number of values removed.
+    pub fn retain(&mut self, mut keep: impl FnMut(usize, &Value<'c>) -> bool) -> usize {
+        let mut cells = self
+            .cells_mut()
+            .into_iter()
+            .filter(|cell| cell.head.is_not_null())
+            .collect::<Vec<&'c mut Cell<'c>>>();
+        let mut kept = 0;
+        for index in 0..cells.len() {
+            if !keep(index, cells[index].head.inner_ref()) {
+                continue;
+            }
+            if kept != index {
+                let (left, right) = cells.split_at_mut(index);
+                left[kept].swap_head(right[0]);
+            }
+            kept += 1;
+        }
+        let removed = cells.len() - kept;
+        if removed == 0 {
+            return 0;
+        }
+        if kept == 0 {
+            self.head.drop_in_place();
+            self.head = UniquePointer::null();
+            self.tail.drop_in_place();
+            self.tail = UniquePointer::null();
+        } else {
+            let last = &mut cells[kept - 1];
+            last.tail.drop_in_place();
+            last.tail = UniquePointer::null();
+        }
+        self.update_lengths();
+        removed
+    }
+
+    /// destructively removes consecutive values that are
+    /// [`Value::equal`], keeping the first of each run.
+    ///
+    /// Returns the number of values removed.
+    pub fn dedup(&mut self) -> usize {
+        self.dedup_by(|value, previous| value.equal(previous))
+    }
+
+    /// destructively removes consecutive values for which `same`
+    /// returns true, given a value and the last value kept before
+    /// it, in the manner of [`Cell::retain`].
+    ///
+    /// Returns the number of values removed.
+    pub fn dedup_by(&mut self, mut same: impl FnMut(&Value<'c>, &Value<'c>) -> bool) -> usize {
+        let mut previous = Option::<Value<'c>>::None;
+        self.retain(|_, value| {
+            if let Some(previous) = &previous
+                && same(value, previous)
+            {
+                return false;
+            }
+            previous = Some(value.clone());
+            true
+        })
+    }
+
+    /// returns mutable references to every cell of the list, starting
+    /// from `self` and stopping short of revisiting cells of circular
+    /// lists.
+    pub(crate) fn cells_mut(&mut self) -> Vec<&'c mut Cell<'c>> {
+        let mut visited = BTreeSet::<usize>::new();
+        let mut cells = Vec::<&'c mut Cell<'c>>::new();
+        let mut cell = unsafe { UniquePointer::<Cell<'c>>::unlock_reference(self) };
+        while visited.insert(UniquePointer::provenance_of_mut_ptr(cell)) {
+            let next = if cell.tail.is_not_null() {
+                Some(cell.tail.inner_mut())
+            } else {
+                None
+            };
+            cells.push(cell);
+            match next {
+                Some(next) => cell = next,
+                None => break,
+            }
+        }
+        cells
+    }
+
+    /// recomputes the length of `self` from its head and the
+    /// length of its immediate tail in `O(1)`.
+    pub(crate) fn update_length(&mut self) {
+        let mut length = if self.head.is_null() { 0 } else { 1 };
         if let Some(tail) = self.tail() {
-            // dbg!(&tail);
-            values.extend(tail.values());
+            length += tail.length;
+        }
+        self.length = length;
+    }
+
+    /// recomputes the length of every cell of the list in `O(n)`.
+    pub(crate) fn update_lengths(&mut self) {
+        for cell in self.cells_mut().into_iter().rev() {
+            cell.update_length();
         }
-        values
     }
 
     pub(crate) fn write(&mut self, value: Value<'c>) {
         self.head.write(value);
         self.incr_ref();
+        self.update_length();
     }
 
     pub(crate) fn swap_head(&mut self, other: &mut Self) {
@@ -199,28 +488,188 @@ impl<'c> Cell<'c> {
         };
     }
 
+    /// returns true if `self` and `other` are the very same cons
+    /// cell, not unlike lisp's `eq`. All nil cells are `eq_ptr`.
+    pub fn eq_ptr(&self, other: &Cell<'c>) -> bool {
+        if self.is_nil() && other.is_nil() {
+            return true;
+        }
+        std::ptr::eq(self, other)
+    }
+
+    /// returns true if `self` and `other` are the same cons cell,
+    /// not unlike lisp's `eql`.
+    pub fn eql(&self, other: &Cell<'c>) -> bool {
+        self.eq_ptr(other)
+    }
+
+    /// returns true if both lists have the same length and their
+    /// values are [`Value::equal`] one-by-one, not unlike lisp's
+    /// `equal`.
+    ///
+    /// Pairs of cells already being compared are deemed equal rather
+    /// than compared again, such that circular lists compare equal
+    /// when they repeat the same values forever.
+    pub fn equal(&self, other: &Cell<'c>) -> bool {
+        self.equal_within(other, &mut BTreeSet::new())
+    }
+
+    /// compares the cells of `self` and `other` in tandem, skipping
+    /// those whose head is null, recording each pair of cells in
+    /// `visited` before comparing their heads.
+    pub(crate) fn equal_within(
+        &self,
+        other: &Cell<'c>,
+        visited: &mut BTreeSet<(usize, usize)>,
+    ) -> bool {
+        let mut lhs = Some(self);
+        let mut rhs = Some(other);
+        loop {
+            while let Some(cell) = lhs.filter(|cell| cell.head.is_null()) {
+                lhs = cell.tail();
+            }
+            while let Some(cell) = rhs.filter(|cell| cell.head.is_null()) {
+                rhs = cell.tail();
+            }
+            let (left, right) = match (lhs, rhs) {
+                (Some(left), Some(right)) => (left, right),
+                (None, None) => return true,
+                _ => return false,
+            };
+            let pair = (
+                UniquePointer::provenance_of_const_ptr(left),
+                UniquePointer::provenance_of_const_ptr(right),
+            );
+            if !visited.insert(pair) {
+                return true;
+            }
+            let (head, other_head) = (left.head.inner_ref(), right.head.inner_ref());
+            if !head.equal_within(other_head, visited) {
+                return false;
+            }
+            lhs = left.tail();
+            rhs = right.tail();
+        }
+    }
+
+    /// consumes the list, returning an iterator that moves the values
+    /// out of its cells and deallocates each cell as it goes.
+    pub fn into_values(self) -> IntoValues<'c> {
+        IntoValues::new(self)
+    }
+
+    /// returns an iterator borrowing the values of the list without
+    /// cloning them
+    pub fn iter(&self) -> ValueRefIterator<'_, 'c> {
+        ValueRefIterator::new(self)
+    }
+
+    /// returns an iterator over the `(car, cdr)` pairs of each cons
+    /// cell of the list, the `cdr` of the last cell being a nil
+    /// [`Cell`].
+    pub fn iter_pairs(&self) -> PairIterator<'c> {
+        PairIterator::new(self)
+    }
+
+    /// returns a list of two-value lists pairing the values of
+    /// `self` and `other` one-by-one, as long as the shorter of both.
+    pub fn zip(&self, other: &Cell<'c>) -> Cell<'c> {
+        self.iter_pairs()
+            .zip(other.iter_pairs())
+            .map(|((lhs, _), (rhs, _))| Value::list(Cell::from_slice(&[lhs.clone(), rhs.clone()])))
+            .collect()
+    }
+
+    /// splits a list of two-value lists, such as those returned by
+    /// [`Cell::zip`], into the list of their [`car`](crate::car)s and
+    /// the list of their `cadr`s.
+    pub fn unzip(&self) -> (Cell<'c>, Cell<'c>) {
+        let mut cars = ListBuilder::new();
+        let mut cadrs = ListBuilder::new();
+        for (value, _) in self.iter_pairs() {
+            cars.push_value(crate::car(value));
+            cadrs.push_value(crate::car(&crate::cdr(value)));
+        }
+        (cars.build(), cadrs.build())
+    }
+
+    /// returns a list of two-value lists pairing each value of `self`
+    /// with the value following it.
+    pub fn pairs(&self) -> Cell<'c> {
+        self.zip(self.tail().unwrap_or(&Cell::nil()))
+    }
+
+    /// returns a new list splicing the values of nested lists into
+    /// `self` up to `depth` levels deep, dropping empty lists on the
+    /// way.
+    ///
+    /// The values spliced from quoted lists are quoted in turn, the
+    /// way [`car`](crate::car) quotes the head of a quoted list, such
+    /// that `(a '(b (c)))` flattens to `(a 'b 'c)`.
+    pub fn flatten(&self, depth: usize) -> Cell<'c> {
+        let mut builder = ListBuilder::new();
+        self.flatten_into(&mut builder, depth, false);
+        let mut cell = builder.build();
+        cell.set_quoted(self.quoted);
+        cell
+    }
+
+    fn flatten_into(&self, builder: &mut ListBuilder<'c>, depth: usize, quoted: bool) {
+        for (value, _) in self.iter_pairs() {
+            let value = if quoted { value.quote() } else { value.clone() };
+            match &value {
+                Value::List(cell) if depth > 0 => cell.flatten_into(builder, depth - 1, false),
+                Value::QuotedList(cell) if depth > 0 => cell.flatten_into(builder, depth - 1, true),
+                Value::EmptyList | Value::EmptyQuotedList if depth > 0 => {}
+                _ => builder.push_value(value),
+            }
+        }
+    }
+
+    /// formats the values of the list the way [`Display`](std::fmt::Display)
+    /// does, printing numbers as told by `options`.
+    pub fn format(&self, options: &FormatOptions) -> String {
+        if self.is_nil() {
+            return String::new();
+        }
+        self.format_values(|value| value.format(options))
+    }
+
     pub fn to_vec(&self) -> Vec<Value<'c>> {
         Vec::<Value<'c>>::from_iter(self.clone().into_iter())
     }
 
     fn incr_ref(&mut self) {
-        self.refs.incr();
-        if !self.tail.is_null() {
-            if let Some(tail) = self.tail.as_mut() {
-                tail.incr_ref();
-            }
+        for cell in self.cells_mut() {
+            cell.refs.incr();
         }
     }
 
     fn decr_ref(&mut self) {
-        self.refs.decr();
-        if !self.tail.is_null() {
-            if let Some(tail) = self.tail.as_mut() {
-                tail.decr_ref();
-            }
+        for cell in self.cells_mut() {
+            cell.refs.decr();
         }
     }
 
+    /// formats the head of every cell of the list with `format`,
+    /// ending circular lists in `. #circular#`.
+    fn format_values(&self, format: impl Fn(&Value<'c>) -> String) -> String {
+        let cells = self.cells();
+        let mut parts = cells
+            .iter()
+            .filter_map(|cell| cell.head.as_ref().map(&format))
+            .collect::<Vec<String>>();
+        if cells
+            .last()
+            .map(|cell| cell.tail.is_not_null())
+            .unwrap_or(false)
+        {
+            parts.push(".".to_string());
+            parts.push("#circular#".to_string());
+        }
+        parts.join(" ").trim().to_string()
+    }
+
     fn dealloc(&mut self) {
         if self.refs > 0 {
             self.decr_ref();
@@ -260,6 +709,33 @@ impl<'c> Cell<'c> {
         .join("")
     }
 }
+/// sorts `heads` by splitting them in halves, sorting each half and
+/// merging both, taking from the left half unless the value on the
+/// right is strictly less.
+fn merge_sort<'c>(
+    mut heads: Vec<UniquePointer<Value<'c>>>,
+    cmp: &mut impl FnMut(&Value<'c>, &Value<'c>) -> Ordering,
+) -> Vec<UniquePointer<Value<'c>>> {
+    if heads.len() < 2 {
+        return heads;
+    }
+    let right = heads.split_off(heads.len() / 2);
+    let mut left = merge_sort(heads, cmp).into_iter().peekable();
+    let mut right = merge_sort(right, cmp).into_iter().peekable();
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    while let (Some(lhs), Some(rhs)) = (left.peek(), right.peek()) {
+        let next = if cmp(rhs.inner_ref(), lhs.inner_ref()) == Ordering::Less {
+            right.next()
+        } else {
+            left.next()
+        };
+        merged.extend(next);
+    }
+    merged.extend(left);
+    merged.extend(right);
+    merged
+}
+
 impl<'c> Quotable for Cell<'c> {
     fn is_quoted(&self) -> bool {
         self.quoted
@@ -344,11 +820,7 @@ impl<'c> From<u8> for Cell<'c> {
 }
 impl<'c> From<u32> for Cell<'c> {
     fn from(value: u32) -> Cell<'c> {
-        if value <= u8::MAX.into() {
-            Cell::new(Value::Byte(value as u8))
-        } else {
-            Cell::new(Value::UnsignedInteger(value.into()))
-        }
+        Cell::new(Value::from(Number::from(value)))
     }
 }
 impl<'c> From<f64> for Cell<'c> {
@@ -358,20 +830,12 @@ impl<'c> From<f64> for Cell<'c> {
 }
 impl<'c> From<u64> for Cell<'c> {
     fn from(value: u64) -> Cell<'c> {
-        if value <= u32::MAX.into() {
-            Cell::from(value as u32)
-        } else {
-            Cell::new(Value::UnsignedInteger(value.into()))
-        }
+        Cell::new(Value::from(Number::from(value)))
     }
 }
 impl<'c> From<i32> for Cell<'c> {
     fn from(value: i32) -> Cell<'c> {
-        if let Ok(value) = TryInto::<u32>::try_into(value) {
-            Cell::new(Value::unsigned_integer(value))
-        } else {
-            Cell::new(Value::integer(value))
-        }
+        Cell::new(Value::from(Number::from(value)))
     }
 }
 impl<'c> From<i64> for Cell<'c> {
@@ -395,35 +859,11 @@ impl<'c> From<Cow<'c, str>> for Cell<'c> {
     }
 }
 
+/// [`PartialEq`] implementation for [`Cell`] compares lists
+/// structurally, see [`Cell::equal`].
 impl<'c> PartialEq<Cell<'c>> for Cell<'c> {
     fn eq(&self, other: &Cell<'c>) -> bool {
-        if self.is_nil() && other.is_nil() {
-            return true;
-        }
-        let slen = self.len();
-        let olen = other.len();
-        if slen != olen {
-            return false;
-        }
-
-        let max_len = std::cmp::max(slen, olen);
-        let mut current = 0;
-
-        let mut iter = self.clone().into_iter().zip(other.clone().into_iter());
-        loop {
-            current += 1;
-            match iter.next() {
-                Some((lhs, rhs)) => {
-                    if lhs != rhs {
-                        return false;
-                    }
-                }
-                None => return current < max_len,
-            }
-            if current == max_len {
-                break true;
-            }
-        }
+        self.equal(other)
     }
 }
 
@@ -446,15 +886,19 @@ impl<'c> Clone for Cell<'c> {
         if let Some(tail) = self.tail().map(Clone::clone) {
             cell.tail.write(tail)
         }
+        cell.length = self.length;
         cell
     }
 }
+/// hashes the values of the list, visiting each cell of circular
+/// lists only once, consistently with [`Cell::equal`].
 impl<'c> Hash for Cell<'c> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.head().hash(state);
-        self.tail().hash(state);
-        self.refs.hash(state);
-        self.quoted.hash(state);
+        for cell in self.cells() {
+            if let Some(head) = cell.head.as_ref() {
+                head.hash(state);
+            }
+        }
     }
 }
 impl<'c> Drop for Cell<'c> {
@@ -471,21 +915,7 @@ impl std::fmt::Debug for Cell<'_> {
             if self.is_nil() {
                 "".to_string()
             } else {
-                let mut parts = Vec::<String>::new();
-                if self.head.is_not_null() {
-                    parts.push(
-                        self.head()
-                            .map(|value| format!("{:#?}", value))
-                            .unwrap_or_default(),
-                    )
-                }
-
-                if self.tail.is_not_null() {
-                    if let Some(tail) = self.tail() {
-                        parts.push(format!("{:#?}", tail));
-                    }
-                }
-                parts.join(" ").trim().to_string()
+                self.format_values(|value| format!("{:#?}", value))
             }
         )
     }
@@ -493,29 +923,20 @@ impl std::fmt::Debug for Cell<'_> {
 
 impl std::fmt::Display for Cell<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            if self.is_nil() {
-                "".to_string()
-            } else {
-                let mut parts = Vec::<String>::new();
-                if self.head.is_not_null() {
-                    parts.push(
-                        self.head()
-                            .map(|value| value.to_string())
-                            .unwrap_or_default(),
-                    )
-                }
+        write!(f, "{}", self.format(&FormatOptions::default()))
+    }
+}
 
-                if self.tail.is_not_null() {
-                    if let Some(tail) = self.tail() {
-                        parts.push(tail.to_string());
-                    }
-                }
-                parts.join(" ").trim().to_string()
-            }
-        )
+impl<'c> GraphNode for Cell<'c> {
+    fn edges(&self) -> Vec<(&'static str, &UniquePointer<Cell<'c>>)> {
+        vec![("tail", &self.tail)]
+    }
+
+    fn label(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.head() {
+            Some(head) => write!(f, "{}", head),
+            None => write!(f, "nil"),
+        }
     }
 }
 
@@ -580,6 +1001,100 @@ impl<'c> Iterator for CellIterator<'c> {
     }
 }
 
+pub struct PairIterator<'c> {
+    cell: UniquePointer<Cell<'c>>,
+    nil: UniquePointer<Cell<'c>>,
+}
+
+impl<'c> PairIterator<'c> {
+    pub fn new(cell: &Cell<'c>) -> PairIterator<'c> {
+        PairIterator {
+            cell: UniquePointer::read_only(cell),
+            nil: UniquePointer::from(Cell::nil()),
+        }
+    }
+}
+impl<'c> Iterator for PairIterator<'c> {
+    type Item = (&'c Value<'c>, &'c Cell<'c>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.cell.is_not_null() {
+            let cell = self.cell.inner_ref();
+            self.cell = match cell.tail() {
+                Some(tail) => UniquePointer::read_only(tail),
+                None => UniquePointer::null(),
+            };
+            if cell.head.is_not_null() {
+                let cdr = cell.tail().unwrap_or(self.nil.inner_ref());
+                return Some((cell.head.inner_ref(), cdr));
+            }
+        }
+        None
+    }
+}
+
+/// `IntoValues` consumes a [`Cell`] list, moving each value out of
+/// its cell and deallocating the cell before moving on to its tail.
+///
+/// Cells shared with other lists, such as tails linked through
+/// [`Cell::setcdr`] or cells whose pointer was cloned, are left
+/// allocated and their values are cloned instead.
+pub struct IntoValues<'c> {
+    head: Option<Value<'c>>,
+    tail: UniquePointer<Cell<'c>>,
+    visited: BTreeSet<usize>,
+}
+
+impl<'c> IntoValues<'c> {
+    pub fn new(mut cell: Cell<'c>) -> IntoValues<'c> {
+        let head = IntoValues::take_head(&mut cell);
+        let tail = std::mem::replace(&mut cell.tail, UniquePointer::null());
+        IntoValues {
+            head,
+            tail,
+            visited: BTreeSet::new(),
+        }
+    }
+
+    fn take_head(cell: &mut Cell<'c>) -> Option<Value<'c>> {
+        let head = match cell.head.take() {
+            Some(head) => Some(head),
+            None => cell.head.as_ref().cloned(),
+        };
+        cell.head = UniquePointer::null();
+        head
+    }
+}
+
+impl<'c> Iterator for IntoValues<'c> {
+    type Item = Value<'c>;
+
+    fn next(&mut self) -> Option<Value<'c>> {
+        loop {
+            if let Some(value) = self.head.take() {
+                return Some(value);
+            }
+            if self.tail.is_null() || !self.visited.insert(self.tail.addr()) {
+                return None;
+            }
+            match self.tail.take() {
+                Some(mut cell) => {
+                    self.head = IntoValues::take_head(&mut cell);
+                    self.tail = std::mem::replace(&mut cell.tail, UniquePointer::null());
+                }
+                None => {
+                    let cell = self.tail.inner_ref();
+                    self.head = cell.head.as_ref().cloned();
+                    self.tail = match cell.tail() {
+                        Some(tail) => UniquePointer::read_only(tail),
+                        None => UniquePointer::null(),
+                    };
+                }
+            }
+        }
+    }
+}
+
 impl<'c> IntoIterator for Cell<'c> {
     type IntoIter = CellIterator<'c>;
     type Item = Value<'c>;
@@ -591,11 +1106,83 @@ impl<'c> IntoIterator for Cell<'c> {
 
 impl<'c> FromIterator<Value<'c>> for Cell<'c> {
     fn from_iter<I: IntoIterator<Item = Value<'c>>>(iter: I) -> Cell<'c> {
-        let mut cell = Cell::nil();
+        let mut builder = ListBuilder::new();
+        builder.extend(iter);
+        builder.build()
+    }
+}
+
+/// `ListBuilder` builds a [`Cell`] list in `O(1)` per appended value
+/// by caching a pointer to the last cell of the list, as opposed to
+/// [`Cell::add`] which walks the whole list on every call.
+pub struct ListBuilder<'c> {
+    head: Cell<'c>,
+    last: UniquePointer<Cell<'c>>,
+    length: usize,
+}
+
+impl<'c> ListBuilder<'c> {
+    pub fn new() -> ListBuilder<'c> {
+        ListBuilder {
+            head: Cell::nil(),
+            last: UniquePointer::null(),
+            length: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// `O(1)`
+    pub fn push_value(&mut self, value: Value<'c>) {
+        let is_quoted = value.is_quoted();
+        self.push_cell(Cell::quoted(Some(value), is_quoted));
+    }
+
+    /// appends every value of `list` to the list being built.
+    pub fn append(&mut self, list: &Cell<'c>) {
+        for value in list.clone().into_iter() {
+            self.push_value(value);
+        }
+    }
+
+    pub fn build(mut self) -> Cell<'c> {
+        self.head.update_lengths();
+        self.head
+    }
+
+    fn push_cell(&mut self, cell: Cell<'c>) {
+        if self.length == 0 {
+            self.head = cell;
+        } else {
+            let last = if self.last.is_null() {
+                &mut self.head
+            } else {
+                self.last.inner_mut()
+            };
+            last.tail = UniquePointer::from(cell);
+            self.last = UniquePointer::read_only(last.tail.inner_ref());
+        }
+        self.length += 1;
+    }
+}
+
+impl<'c> Default for ListBuilder<'c> {
+    fn default() -> ListBuilder<'c> {
+        ListBuilder::new()
+    }
+}
+
+impl<'c> Extend<Value<'c>> for ListBuilder<'c> {
+    fn extend<T: IntoIterator<Item = Value<'c>>>(&mut self, iter: T) {
         for value in iter {
-            cell.push_value(value);
+            self.push_value(value);
         }
-        cell
     }
 }
 // impl<'c> Extend<Value<'c>> for Value<'c> {
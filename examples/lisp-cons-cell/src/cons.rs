@@ -1,4 +1,6 @@
-use crate::{AsCell, AsValue, Cell, ListIterator, Quotable, Symbol, Value};
+use unique_pointer::UniquePointer;
+
+use crate::{AsCell, AsValue, Cell, ListBuilder, ListIterator, Quotable, Symbol, Value};
 
 pub fn cons<'c, T: AsCell<'c>>(head: T, tail: &mut Cell<'c>) -> Cell<'c> {
     let mut head = head.as_cell();
@@ -7,13 +9,11 @@ pub fn cons<'c, T: AsCell<'c>>(head: T, tail: &mut Cell<'c>) -> Cell<'c> {
 }
 pub fn append<'c, T: ListIterator<'c, Value<'c>>>(list: T) -> Value<'c> {
     let is_quoted = list.is_quoted();
-    let mut items = Cell::nil();
+    let mut items = ListBuilder::new();
     for value in list {
         match &value {
             Value::List(h) | Value::QuotedList(h) => {
-                for item in h.clone().into_iter() {
-                    items.push_value(item.clone());
-                }
+                items.append(h);
             }
             Value::EmptyList | Value::EmptyQuotedList | Value::Nil => {}
             _ => {
@@ -21,6 +21,7 @@ pub fn append<'c, T: ListIterator<'c, Value<'c>>>(list: T) -> Value<'c> {
             }
         }
     }
+    let items = items.build();
     if is_quoted {
         Value::quoted_list(items)
     } else {
@@ -52,15 +53,171 @@ pub fn cdr<'c>(item: &Value<'c>) -> Value<'c> {
         _ => Value::Nil,
     }
 }
+
+/// defines the compound accessors of lists, each given as the
+/// sequence of [`car`] and [`cdr`] it applies, from the innermost to
+/// the outermost.
+macro_rules! compound_accessors {
+    ($($name:ident $form:literal => $first:ident $(, $accessor:ident)+;)+) => {
+        $(
+            #[doc = concat!("returns `", $form, "`.")]
+            pub fn $name<'c>(value: &Value<'c>) -> Value<'c> {
+                let value = $first(value);
+                $(let value = $accessor(&value);)+
+                value
+            }
+        )+
+    };
+}
+
+compound_accessors! {
+    caar "(car (car value))" => car, car;
+    cadr "(car (cdr value))" => cdr, car;
+    cdar "(cdr (car value))" => car, cdr;
+    cddr "(cdr (cdr value))" => cdr, cdr;
+    caaar "(car (car (car value)))" => car, car, car;
+    caadr "(car (car (cdr value)))" => cdr, car, car;
+    cadar "(car (cdr (car value)))" => car, cdr, car;
+    caddr "(car (cdr (cdr value)))" => cdr, cdr, car;
+    cdaar "(cdr (car (car value)))" => car, car, cdr;
+    cdadr "(cdr (car (cdr value)))" => cdr, car, cdr;
+    cddar "(cdr (cdr (car value)))" => car, cdr, cdr;
+    cdddr "(cdr (cdr (cdr value)))" => cdr, cdr, cdr;
+    caaaar "(car (car (car (car value))))" => car, car, car, car;
+    caaadr "(car (car (car (cdr value))))" => cdr, car, car, car;
+    caadar "(car (car (cdr (car value))))" => car, cdr, car, car;
+    caaddr "(car (car (cdr (cdr value))))" => cdr, cdr, car, car;
+    cadaar "(car (cdr (car (car value))))" => car, car, cdr, car;
+    cadadr "(car (cdr (car (cdr value))))" => cdr, car, cdr, car;
+    caddar "(car (cdr (cdr (car value))))" => car, cdr, cdr, car;
+    cadddr "(car (cdr (cdr (cdr value))))" => cdr, cdr, cdr, car;
+    cdaaar "(cdr (car (car (car value))))" => car, car, car, cdr;
+    cdaadr "(cdr (car (car (cdr value))))" => cdr, car, car, cdr;
+    cdadar "(cdr (car (cdr (car value))))" => car, cdr, car, cdr;
+    cdaddr "(cdr (car (cdr (cdr value))))" => cdr, cdr, car, cdr;
+    cddaar "(cdr (cdr (car (car value))))" => car, car, cdr, cdr;
+    cddadr "(cdr (cdr (car (cdr value))))" => cdr, car, cdr, cdr;
+    cdddar "(cdr (cdr (cdr (car value))))" => car, cdr, cdr, cdr;
+    cddddr "(cdr (cdr (cdr (cdr value))))" => cdr, cdr, cdr, cdr;
+}
+
 pub fn list<'c, T: ListIterator<'c, Value<'c>>>(list: T) -> Value<'c> {
     // dbg!(&list);
-    let mut cell = Cell::nil();
+    let mut cell = ListBuilder::new();
     for item in list.into_iter() {
         // dbg!(&item);
         cell.push_value(item);
     }
-    Value::List(cell)
+    Value::List(cell.build())
 }
 
 pub fn setcar<'c>(cell: &Cell<'c>, sym: &Symbol, value: &Value) {}
-pub fn setcdr<'c>(cell: &Cell<'c>, value: &Value) {}
+/// replaces the tail of `cell` with the list inside `value`, see
+/// [`Cell::setcdr`].
+///
+/// # Safety
+///
+/// `value` must neither move nor be dropped before `cell` is, unless
+/// it is not a list.
+pub unsafe fn setcdr<'c>(cell: &mut Cell<'c>, value: &Value<'c>) {
+    match value {
+        Value::List(tail) | Value::QuotedList(tail) => unsafe { cell.setcdr(tail) },
+        Value::Nil | Value::EmptyList | Value::EmptyQuotedList => unsafe {
+            cell.setcdr(&Cell::nil())
+        },
+        value => panic!("cannot set cdr to non-list {:#?}", value),
+    }
+}
+
+/// destructively appends `tail` to `list` by linking the last cell of
+/// `list` to `tail` itself, such that both lists share structure.
+///
+/// # Safety
+///
+/// `tail` must neither move nor be dropped before `list` is, see
+/// [`Cell::setcdr`].
+pub unsafe fn nconc<'c>(list: &mut Cell<'c>, tail: &Cell<'c>) {
+    if tail.is_nil() {
+        return;
+    }
+    if list.is_empty() {
+        list.add(tail);
+        return;
+    }
+    if let Some(last) = list.cells_mut().pop() {
+        unsafe { last.setcdr(tail) };
+    }
+    list.update_lengths();
+}
+
+/// destructively inserts a copy of the values of `items` right after
+/// the cell at `position` of `list`, relinking the remainder of `list`
+/// after the last inserted cell.
+///
+/// Returns false if `list` has no cell at `position`.
+pub fn splice_after<'c>(list: &mut Cell<'c>, position: usize, items: &Cell<'c>) -> bool {
+    let cell = match list
+        .cells_mut()
+        .into_iter()
+        .filter(|cell| cell.head.is_not_null())
+        .nth(position)
+    {
+        Some(cell) => cell,
+        None => return false,
+    };
+    if items.is_empty() {
+        return true;
+    }
+    let mut chain = Cell::from_iter(items.values());
+    if let Some(last) = chain.cells_mut().pop() {
+        last.tail = unsafe { cell.tail.propagate() };
+    }
+    cell.tail = UniquePointer::from(chain);
+    list.update_lengths();
+    true
+}
+
+/// destructively removes every value of `list` that is
+/// [`Value::equal`] to `value`, returning the number of values
+/// removed.
+pub fn delete<'c>(list: &mut Cell<'c>, value: &Value<'c>) -> usize {
+    list.retain(|_, item| !item.equal(value))
+}
+
+/// returns the tail of `list` starting at the first value that is
+/// [`Value::equal`] to `value`, not unlike lisp's `member`.
+pub fn member<'c>(list: &Cell<'c>, value: &Value<'c>) -> Option<&'c Cell<'c>> {
+    find_cell(list, |item| item.equal(value)).map(|(_, cell)| cell)
+}
+
+/// returns the tail of `list` starting at the very value `value`
+/// as told by [`Value::eq_ptr`], not unlike lisp's `memq`.
+pub fn memq<'c>(list: &Cell<'c>, value: &Value<'c>) -> Option<&'c Cell<'c>> {
+    find_cell(list, |item| item.eq_ptr(value)).map(|(_, cell)| cell)
+}
+
+/// returns the position in `list` of the first value that is
+/// [`Value::equal`] to `value`.
+pub fn position<'c>(list: &Cell<'c>, value: &Value<'c>) -> Option<usize> {
+    find_cell(list, |item| item.equal(value)).map(|(index, _)| index)
+}
+
+/// returns the position in `list` of the very value `value` as told
+/// by [`Value::eq_ptr`].
+pub fn positionq<'c>(list: &Cell<'c>, value: &Value<'c>) -> Option<usize> {
+    find_cell(list, |item| item.eq_ptr(value)).map(|(index, _)| index)
+}
+
+/// returns the first cell of `list` whose value matches `predicate`
+/// along with its position, visiting each cell of circular lists
+/// only once.
+fn find_cell<'c>(
+    list: &Cell<'c>,
+    mut predicate: impl FnMut(&Value<'c>) -> bool,
+) -> Option<(usize, &'c Cell<'c>)> {
+    list.cells()
+        .into_iter()
+        .filter(|cell| cell.head.is_not_null())
+        .enumerate()
+        .find(|(_, cell)| predicate(cell.head.inner_ref()))
+}
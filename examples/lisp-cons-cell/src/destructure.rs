@@ -0,0 +1,80 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{Quotable, Value};
+
+/// `ArityError` tells why a value did not match the pattern of
+/// [`destructure!`](crate::destructure!).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArityError {
+    /// the pattern as written, e.g. `(a b . rest)`
+    pub pattern: &'static str,
+    /// number of elements bound by name
+    pub expected: usize,
+    /// whether the pattern binds the remaining elements
+    pub variadic: bool,
+    /// number of elements of the value, `None` if it is not a list
+    pub found: Option<usize>,
+    /// the value as displayed
+    pub value: String,
+}
+
+impl Display for ArityError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let found = match self.found {
+            Some(found) => found,
+            None => return write!(f, "{} expects a list but got {}", self.pattern, self.value),
+        };
+        let plural = if self.expected == 1 { "" } else { "s" };
+        let at_least = if self.variadic { "at least " } else { "" };
+        write!(
+            f,
+            "{} expects {}{} element{} but {} has {}",
+            self.pattern, at_least, self.expected, plural, self.value, found
+        )
+    }
+}
+
+impl std::error::Error for ArityError {}
+
+/// splits the values of the list `value` into its first `arity`
+/// values and a list of the remaining ones, which must be empty
+/// unless `variadic`, the remaining list being quoted if `value` is.
+///
+/// `nil` and empty lists have no values, any other value but lists
+/// fails to match.
+pub fn destructure<'c>(
+    value: &Value<'c>,
+    pattern: &'static str,
+    arity: usize,
+    variadic: bool,
+) -> Result<(Vec<Value<'c>>, Value<'c>), ArityError> {
+    let mut values = match value {
+        Value::List(cell) | Value::QuotedList(cell) => cell.values(),
+        Value::EmptyList | Value::EmptyQuotedList | Value::Nil => Vec::new(),
+        _ => {
+            return Err(ArityError {
+                pattern,
+                expected: arity,
+                variadic,
+                found: None,
+                value: value.to_string(),
+            });
+        }
+    };
+    if values.len() < arity || (!variadic && values.len() > arity) {
+        return Err(ArityError {
+            pattern,
+            expected: arity,
+            variadic,
+            found: Some(values.len()),
+            value: value.to_string(),
+        });
+    }
+    let rest = values.split_off(arity);
+    let rest = if value.is_quoted() {
+        Value::collect_quoted(rest)
+    } else {
+        rest.into_iter().collect()
+    };
+    Ok((values, rest))
+}
@@ -0,0 +1,110 @@
+use std::collections::BTreeMap;
+
+use unique_pointer::UniquePointer;
+
+use crate::{AsSymbol, Value};
+
+/// `Env` is a frame of symbol bindings linked to the frame that
+/// encloses it.
+///
+/// The parent link is shared rather than owned such that closures
+/// may capture an environment that in turn binds those very closures,
+/// therefore a parent frame must outlive its children and must not
+/// be moved while they exist.
+#[derive(Clone, Debug)]
+pub struct Env<'c> {
+    bindings: BTreeMap<&'c str, Value<'c>>,
+    parent: UniquePointer<Env<'c>>,
+}
+
+impl<'c> Env<'c> {
+    /// creates a top-level environment
+    pub fn new() -> Env<'c> {
+        Env {
+            bindings: BTreeMap::new(),
+            parent: UniquePointer::null(),
+        }
+    }
+
+    /// creates an environment enclosed by `parent`
+    pub fn with_parent(parent: &Env<'c>) -> Env<'c> {
+        Env {
+            bindings: BTreeMap::new(),
+            parent: UniquePointer::read_only(parent),
+        }
+    }
+
+    pub fn parent(&self) -> Option<&'c Env<'c>> {
+        self.parent.as_ref()
+    }
+
+    /// returns the number of frames from `self` to the top-level
+    /// environment
+    pub fn depth(&self) -> usize {
+        match self.parent() {
+            Some(parent) => parent.depth() + 1,
+            None => 0,
+        }
+    }
+
+    /// binds `symbol` to `value` in this frame, shadowing any binding
+    /// of `symbol` in the enclosing frames, and returns the value
+    /// previously bound in this frame.
+    pub fn define<T: AsSymbol<'c>>(&mut self, symbol: T, value: Value<'c>) -> Option<Value<'c>> {
+        self.bindings.insert(symbol.as_symbol().symbol(), value)
+    }
+
+    /// returns the value of the innermost binding of `symbol`
+    pub fn lookup<T: AsSymbol<'c>>(&self, symbol: T) -> Option<&Value<'c>> {
+        let symbol = symbol.as_symbol();
+        let mut env = self;
+        loop {
+            if let Some(value) = env.bindings.get(symbol.symbol()) {
+                return Some(value);
+            }
+            env = env.parent()?;
+        }
+    }
+
+    /// returns the value `symbol` evaluates to, that is, `symbol`
+    /// itself for keywords regardless of any binding, or else the
+    /// value of its innermost binding.
+    pub fn symbol_value<T: AsSymbol<'c>>(&self, symbol: T) -> Option<Value<'c>> {
+        let symbol = symbol.as_symbol();
+        if symbol.is_keyword() {
+            return Some(Value::Symbol(symbol.unquote()));
+        }
+        self.lookup(symbol).cloned()
+    }
+
+    /// replaces the value of the innermost binding of `symbol` and
+    /// returns the value it replaced, unbound symbols are left
+    /// unbound and yield `None`.
+    pub fn set<T: AsSymbol<'c>>(&mut self, symbol: T, value: Value<'c>) -> Option<Value<'c>> {
+        let symbol = symbol.as_symbol();
+        if let Some(current) = self.bindings.get_mut(symbol.symbol()) {
+            return Some(std::mem::replace(current, value));
+        }
+        let mut parent = self.parent.clone();
+        while parent.is_not_null() {
+            let env = parent.inner_mut();
+            if let Some(current) = env.bindings.get_mut(symbol.symbol()) {
+                return Some(std::mem::replace(current, value));
+            }
+            parent = env.parent.clone();
+        }
+        None
+    }
+
+    /// returns true if `symbol` is bound in this frame or in any of
+    /// its enclosing frames
+    pub fn is_bound<T: AsSymbol<'c>>(&self, symbol: T) -> bool {
+        self.lookup(symbol).is_some()
+    }
+}
+
+impl Default for Env<'_> {
+    fn default() -> Self {
+        Env::new()
+    }
+}
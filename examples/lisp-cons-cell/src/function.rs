@@ -0,0 +1,108 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use unique_pointer::UniquePointer;
+
+use crate::{Cell, Env, Value};
+
+/// `Function` is a lambda: a parameter list, a body and the
+/// environment it closes over.
+///
+/// The environment is shared rather than owned such that it may
+/// contain the very function that captured it, which is why
+/// equality, ordering and hashing consider the environment by
+/// address only.
+#[derive(Clone)]
+pub struct Function<'c> {
+    params: Cell<'c>,
+    body: Cell<'c>,
+    env: UniquePointer<Env<'c>>,
+}
+
+impl<'c> Function<'c> {
+    /// creates a function that captures no environment
+    pub fn new(params: Cell<'c>, body: Cell<'c>) -> Function<'c> {
+        Function {
+            params,
+            body,
+            env: UniquePointer::null(),
+        }
+    }
+
+    /// creates a function that captures `env`, which must outlive
+    /// the function.
+    pub fn closure(params: Cell<'c>, body: Cell<'c>, env: &Env<'c>) -> Function<'c> {
+        Function {
+            params,
+            body,
+            env: UniquePointer::read_only(env),
+        }
+    }
+
+    pub fn params(&self) -> &Cell<'c> {
+        &self.params
+    }
+
+    pub fn body(&self) -> &Cell<'c> {
+        &self.body
+    }
+
+    pub fn env(&self) -> Option<&'c Env<'c>> {
+        self.env.as_ref()
+    }
+
+    pub fn arity(&self) -> usize {
+        self.params.len()
+    }
+
+    pub fn is_closure(&self) -> bool {
+        self.env.is_not_null()
+    }
+}
+
+impl PartialEq for Function<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.env.addr() == other.env.addr()
+            && self.params.equal(&other.params)
+            && self.body.equal(&other.body)
+    }
+}
+impl Eq for Function<'_> {}
+impl PartialOrd for Function<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Function<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.params
+            .cmp(&other.params)
+            .then_with(|| self.body.cmp(&other.body))
+            .then_with(|| self.env.addr().cmp(&other.env.addr()))
+    }
+}
+impl Hash for Function<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.params.hash(state);
+        self.body.hash(state);
+        self.env.addr().hash(state);
+    }
+}
+
+impl Display for Function<'_> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "#<function>")
+    }
+}
+impl Debug for Function<'_> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "#<function ({}) ({})>", self.params, self.body)
+    }
+}
+
+impl<'c> From<Function<'c>> for Value<'c> {
+    fn from(function: Function<'c>) -> Value<'c> {
+        Value::Function(function)
+    }
+}
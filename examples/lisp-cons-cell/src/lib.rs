@@ -2,13 +2,27 @@
 pub mod traits;
 pub use traits::{AsNumber, ListValue, Quotable};
 pub mod cons;
-pub use cons::{append, car, cdr, cons, list, makelist, setcar, setcdr};
+pub use cons::{
+    append, caaaar, caaadr, caaar, caadar, caaddr, caadr, caar, cadaar, cadadr, cadar, caddar,
+    cadddr, caddr, cadr, car, cdaaar, cdaadr, cdaar, cdadar, cdaddr, cdadr, cdar, cddaar, cddadr,
+    cddar, cdddar, cddddr, cdddr, cddr, cdr, cons, delete, list, makelist, member, memq, nconc,
+    position, positionq, setcar, setcdr, splice_after,
+};
 pub mod cell;
-pub use cell::{AsCell, Cell, ListIterator};
+pub use cell::{AsCell, Cell, IntoValues, ListBuilder, ListIterator, PairIterator};
+pub mod plist;
+pub use plist::{plist_get, plist_pairs, plist_put, plist_remove, PlistIterator};
+pub mod destructure;
+pub use destructure::ArityError;
+pub mod env;
+pub use env::Env;
+pub mod function;
+pub use function::Function;
 pub mod value;
 pub use value::{
-    AsFloat, AsInteger, AsUnsignedInteger, AsValue, Float, Integer, UnsignedInteger, Value,
-    ValueIterator,
+    escape_string, read_string_literal, unescape_string, AsFloat, AsInteger, AsUnsignedInteger,
+    AsValue, ByteFormat, Float, FormatOptions, Integer, Number, UnsignedInteger, Value,
+    ValueIterator, ValueRefIterator,
 };
 pub mod symbol;
 pub use symbol::{AsSymbol, Symbol};
@@ -26,6 +26,58 @@ macro_rules! append {
     }};
 }
 
+/// binds the values of a list to names, `(a b)` matching lists of
+/// exactly two values and `(a b . rest)` lists of at least two values
+/// whose remaining ones are bound as a list to `rest`, returning
+/// early with an [`ArityError`](crate::destructure::ArityError)
+/// through `?` otherwise.
+///
+/// ```
+/// use cons_cell::destructure::ArityError;
+/// use cons_cell::{destructure, list, Value};
+///
+/// fn first_two(value: Value) -> Result<(Value, Value, Value), ArityError> {
+///     destructure!(value => (a b . rest));
+///     Ok((a, b, rest))
+/// }
+///
+/// let (a, b, rest) = first_two(list!(Value::from(1u8), Value::from(2u8), Value::from(3u8)))?;
+/// assert_eq!(a, Value::from(1u8));
+/// assert_eq!(b, Value::from(2u8));
+/// assert_eq!(rest, list!(Value::from(3u8)));
+///
+/// let error = first_two(list!(Value::from(1u8))).unwrap_err();
+/// assert_eq!(error.to_string(), "(a b . rest) expects at least 2 elements but (0x01) has 1");
+/// # Ok::<(), ArityError>(())
+/// ```
+#[macro_export]
+macro_rules! destructure {
+    ($list:expr => ($( $name:ident )* . $rest:ident)) => {
+        $crate::destructure!(
+            @bind $list,
+            concat!("(", $( stringify!($name), " ", )* ". ", stringify!($rest), ")"),
+            true,
+            $rest,
+            $( $name )*
+        );
+    };
+    ($list:expr => ($( $name:ident )*)) => {
+        $crate::destructure!(@bind $list, stringify!(($( $name )*)), false, _, $( $name )*);
+    };
+    (@bind $list:expr, $pattern:expr, $variadic:literal, $rest:pat, $( $name:ident )*) => {
+        let (values, $rest) = $crate::destructure::destructure(
+            &$list,
+            $pattern,
+            <[&str]>::len(&[$( stringify!($name) ),*]),
+            $variadic,
+        )?;
+        let mut values = values.into_iter();
+        $(
+            let $name = values.next().expect("destructure checks the arity");
+        )*
+    };
+}
+
 #[macro_export]
 macro_rules! location {
     () => {{
@@ -0,0 +1,73 @@
+use unique_pointer::UniquePointer;
+
+use crate::{Cell, PairIterator, Value};
+
+/// returns the value of `key` in the property list `plist`, that is,
+/// a flat list of alternating keys and values.
+///
+/// Keys are compared with [`Value::eql`].
+pub fn plist_get<'c>(plist: &Cell<'c>, key: &Value<'c>) -> Option<Value<'c>> {
+    plist_pairs(plist)
+        .find(|(name, _)| name.eql(key))
+        .map(|(_, value)| value.clone())
+}
+
+/// sets the value of `key` in the property list `plist`, appending
+/// `key` and `value` to `plist` when `key` is not present.
+pub fn plist_put<'c>(plist: &mut Cell<'c>, key: Value<'c>, value: Value<'c>) {
+    let mut cells = plist
+        .cells_mut()
+        .into_iter()
+        .filter(|cell| cell.head.is_not_null());
+    while let Some(name) = cells.next() {
+        let Some(cell) = cells.next() else {
+            break;
+        };
+        if name.head.inner_ref().eql(&key) {
+            cell.head = UniquePointer::from(value);
+            return;
+        }
+    }
+    plist.add(&Cell::from_iter([key, value]));
+}
+
+/// removes `key` and its value from the property list `plist`,
+/// returning false if `key` is not present.
+pub fn plist_remove<'c>(plist: &mut Cell<'c>, key: &Value<'c>) -> bool {
+    let position = plist_pairs(plist).position(|(name, _)| name.eql(key));
+    match position {
+        Some(position) => {
+            let position = position * 2;
+            plist.retain(|index, _| index != position && index != position + 1);
+            true
+        }
+        None => false,
+    }
+}
+
+/// returns an iterator over the `(key, value)` pairs of the property
+/// list `plist`.
+pub fn plist_pairs<'c>(plist: &Cell<'c>) -> PlistIterator<'c> {
+    PlistIterator::new(plist)
+}
+
+pub struct PlistIterator<'c> {
+    pairs: PairIterator<'c>,
+}
+
+impl<'c> PlistIterator<'c> {
+    pub fn new(plist: &Cell<'c>) -> PlistIterator<'c> {
+        PlistIterator {
+            pairs: plist.iter_pairs(),
+        }
+    }
+}
+impl<'c> Iterator for PlistIterator<'c> {
+    type Item = (&'c Value<'c>, &'c Value<'c>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, _) = self.pairs.next()?;
+        let (value, _) = self.pairs.next()?;
+        Some((key, value))
+    }
+}
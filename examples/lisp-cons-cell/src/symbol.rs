@@ -30,10 +30,60 @@ impl<'c> Symbol<'c> {
         }
     }
 
+    /// creates a keyword symbol, printed as `:name`, which
+    /// evaluates to itself
+    pub fn keyword<T: ToString>(name: T) -> Symbol<'c> {
+        Symbol::new(format!(":{}", name.to_string()))
+    }
+
+    /// creates a symbol named `name` within `namespace`, printed as
+    /// `namespace:name`
+    pub fn namespaced<T: ToString, N: ToString>(namespace: N, name: T) -> Symbol<'c> {
+        Symbol::new(format!("{}:{}", namespace.to_string(), name.to_string()))
+    }
+
+    /// returns the printed representation of the symbol, including
+    /// its keyword or namespace prefix
     pub fn symbol(&self) -> &'c str {
         self.sym
     }
 
+    /// returns the name of the symbol without its keyword or
+    /// namespace prefix
+    pub fn name(&self) -> &'c str {
+        self.split().1
+    }
+
+    /// returns the namespace prefix of `namespace:name` or
+    /// `namespace::name` symbols
+    pub fn namespace(&self) -> Option<&'c str> {
+        self.split().0
+    }
+
+    /// returns true if the symbol is printed as `:name`
+    pub fn is_keyword(&self) -> bool {
+        self.sym.len() > 1 && self.sym.starts_with(':')
+    }
+
+    /// splits the printed representation of the symbol into its
+    /// namespace and its name
+    fn split(&self) -> (Option<&'c str>, &'c str) {
+        if self.is_keyword() {
+            return (None, &self.sym[1..]);
+        }
+        match self.sym.split_once(':') {
+            Some((namespace, name)) if !namespace.is_empty() => {
+                let name = name.strip_prefix(':').unwrap_or(name);
+                if name.is_empty() {
+                    (None, self.sym)
+                } else {
+                    (Some(namespace), name)
+                }
+            }
+            _ => (None, self.sym),
+        }
+    }
+
     pub fn quote(&self) -> Symbol<'c> {
         Symbol::quoted(self.symbol(), true)
     }
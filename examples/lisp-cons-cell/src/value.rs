@@ -1,5 +1,6 @@
 #![allow(unused)]
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::convert::{AsMut, AsRef};
 use std::fmt::{Debug, Display, Formatter};
 use std::iter::{Extend, FromIterator, IntoIterator};
@@ -11,11 +12,17 @@ mod macros;
 pub use integer::{AsInteger, Integer};
 pub mod float;
 pub use float::{AsFloat, Float};
+pub mod format;
+pub use format::{ByteFormat, FormatOptions};
+pub mod number;
+pub use number::Number;
+pub mod string;
+pub use string::{escape_string, read_string_literal, unescape_string};
 pub mod unsigned_integer;
 use crate::{dbg, try_result};
 pub use unsigned_integer::{AsUnsignedInteger, UnsignedInteger};
 
-use crate::{AsCell, AsNumber, AsSymbol, Cell, ListIterator, Quotable, Symbol};
+use crate::{AsCell, AsNumber, AsSymbol, Cell, Function, ListIterator, Quotable, Symbol};
 
 pub trait ValueListIterator<'c>: IntoIterator<Item = Value<'c>> + Quotable {}
 // impl <'c, T: IntoIterator<Item = Value<'c>> + Quotable> ValueListIterator<'c> for T {}
@@ -40,6 +47,7 @@ pub enum Value<'c> {
     QuotedList(Cell<'c>),
     EmptyList,
     EmptyQuotedList,
+    Function(Function<'c>),
 }
 impl<'c> Value<'c> {
     pub fn nil() -> Value<'c> {
@@ -58,6 +66,11 @@ impl<'c> Value<'c> {
         Value::QuotedSymbol(sym.as_symbol().quote())
     }
 
+    /// returns the keyword symbol `:name`
+    pub fn keyword<T: ToString>(name: T) -> Value<'c> {
+        Value::Symbol(Symbol::keyword(name))
+    }
+
     pub fn string<T: ToString>(value: T) -> Value<'c> {
         Value::String(value.to_string().leak())
     }
@@ -128,6 +141,18 @@ impl<'c> Value<'c> {
         Value::EmptyQuotedList
     }
 
+    /// collects the values into a [`Value::QuotedList`], or
+    /// [`Value::EmptyQuotedList`] if there are none, as opposed to
+    /// [`collect`](Iterator::collect) which produces unquoted lists.
+    pub fn collect_quoted<I: IntoIterator<Item = Value<'c>>>(iter: I) -> Value<'c> {
+        Value::EmptyQuotedList.extended(iter)
+    }
+
+    fn extended<I: IntoIterator<Item = Value<'c>>>(mut self, iter: I) -> Value<'c> {
+        self.extend(iter);
+        self
+    }
+
     pub fn quote(&self) -> Value<'c> {
         let value = match self {
             Value::Symbol(h) => Value::QuotedSymbol(h.clone().unquote()),
@@ -139,6 +164,45 @@ impl<'c> Value<'c> {
         value.clone()
     }
 
+    /// formats the value the way [`Display`] does, printing numbers
+    /// as told by `options`.
+    pub fn format(&self, options: &FormatOptions) -> String {
+        match self {
+            Value::T => "t".to_string(),
+            Value::Nil => "nil".to_string(),
+            Value::Byte(h) => options.format_byte(*h),
+            Value::Float(h) => options.format_float(**h),
+            Value::Integer(h) => options.format_integer(**h),
+            Value::String(h) => format!("\"{}\"", escape_string(h)),
+            Value::Symbol(h) => format!("{}", h),
+            Value::QuotedSymbol(h) => format!("'{}", h),
+            Value::UnsignedInteger(h) => options.format_integer(i64::from(**h)),
+            Value::List(h) => {
+                if h.is_nil() {
+                    format!("()")
+                } else {
+                    format!("({})", h.format(options))
+                }
+            }
+            Value::QuotedList(h) => {
+                if h.is_nil() {
+                    format!("'()")
+                } else {
+                    format!("'({})", h.format(options))
+                }
+            }
+            Value::EmptyList => format!("()"),
+            Value::EmptyQuotedList => format!("'()"),
+            Value::Function(h) => format!("{}", h),
+        }
+    }
+
+    /// returns an iterator borrowing the values of lists, or `self`
+    /// if it is not a list, without cloning them
+    pub fn iter(&self) -> ValueRefIterator<'_, 'c> {
+        ValueRefIterator::from_value(self)
+    }
+
     pub fn values(&self) -> Vec<Value<'c>> {
         match self {
             Value::List(cell) | Value::QuotedList(cell) => cell.values(),
@@ -205,6 +269,16 @@ impl<'c> Value<'c> {
         }
     }
 
+    pub fn is_number(&self) -> bool {
+        self.to_number().is_some()
+    }
+
+    /// returns the [`Number`] held by `self` or `None` if `self` is
+    /// not numeric.
+    pub fn to_number(&self) -> Option<Number> {
+        Number::from_value(self)
+    }
+
     pub fn is_string(&self) -> bool {
         match self {
             Value::String(_) => true,
@@ -220,6 +294,17 @@ impl<'c> Value<'c> {
         }
     }
 
+    pub fn is_keyword(&self) -> bool {
+        match self {
+            Value::Symbol(symbol) | Value::QuotedSymbol(symbol) => symbol.is_keyword(),
+            _ => false,
+        }
+    }
+
+    pub fn is_function(&self) -> bool {
+        matches!(self, Value::Function(_))
+    }
+
     pub fn is_list(&self) -> bool {
         match self {
             Value::List(_) => true,
@@ -227,6 +312,63 @@ impl<'c> Value<'c> {
             _ => false,
         }
     }
+
+    /// returns true if `self` and `other` are the very same object,
+    /// not unlike lisp's `eq`.
+    ///
+    /// `nil`, `t` and empty lists are singletons and therefore always
+    /// `eq_ptr` to themselves.
+    pub fn eq_ptr(&self, other: &Value<'c>) -> bool {
+        match (self, other) {
+            (Value::Nil, Value::Nil)
+            | (Value::T, Value::T)
+            | (Value::EmptyList, Value::EmptyList)
+            | (Value::EmptyQuotedList, Value::EmptyQuotedList) => true,
+            (Value::List(lhs), Value::List(rhs))
+            | (Value::QuotedList(lhs), Value::QuotedList(rhs)) => lhs.eq_ptr(rhs),
+            _ => std::ptr::eq(self, other),
+        }
+    }
+
+    /// returns true if `self` and `other` are [`Value::eq_ptr`] or
+    /// are numbers of the same type and value or symbols of the same
+    /// name, not unlike lisp's `eql`.
+    pub fn eql(&self, other: &Value<'c>) -> bool {
+        if self.eq_ptr(other) {
+            return true;
+        }
+        match (self, other) {
+            (Value::Byte(lhs), Value::Byte(rhs)) => lhs == rhs,
+            (Value::UnsignedInteger(lhs), Value::UnsignedInteger(rhs)) => lhs == rhs,
+            (Value::Integer(lhs), Value::Integer(rhs)) => lhs == rhs,
+            (Value::Float(lhs), Value::Float(rhs)) => lhs == rhs,
+            (Value::Symbol(lhs), Value::Symbol(rhs))
+            | (Value::QuotedSymbol(lhs), Value::QuotedSymbol(rhs)) => lhs == rhs,
+            _ => false,
+        }
+    }
+
+    /// returns true if `self` and `other` are [`Value::eql`] or are
+    /// strings with the same contents or lists whose values are
+    /// `equal`, not unlike lisp's `equal`.
+    pub fn equal(&self, other: &Value<'c>) -> bool {
+        self.equal_within(other, &mut BTreeSet::new())
+    }
+
+    /// compares lists through [`Cell::equal_within`] such that lists
+    /// nested within themselves do not recurse forever.
+    pub(crate) fn equal_within(
+        &self,
+        other: &Value<'c>,
+        visited: &mut BTreeSet<(usize, usize)>,
+    ) -> bool {
+        match (self, other) {
+            (Value::String(lhs), Value::String(rhs)) => lhs == rhs,
+            (Value::List(lhs), Value::List(rhs))
+            | (Value::QuotedList(lhs), Value::QuotedList(rhs)) => lhs.equal_within(rhs, visited),
+            _ => self.eql(other),
+        }
+    }
 }
 
 impl<'c> AsValue<'c> for Value<'c> {
@@ -251,37 +393,7 @@ impl<'c> Drop for Value<'c> {
 // }
 impl Display for Value<'_> {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Value::T => "t".to_string(),
-                Value::Nil => "nil".to_string(),
-                Value::Byte(h) => format!("0x{:02x}", h),
-                Value::Float(h) => format!("{}", h),
-                Value::Integer(h) => format!("{}", h),
-                Value::String(h) => format!("{:#?}", h),
-                Value::Symbol(h) => format!("{}", h),
-                Value::QuotedSymbol(h) => format!("'{}", h),
-                Value::UnsignedInteger(h) => format!("{}", h),
-                Value::List(h) => {
-                    if h.is_nil() {
-                        format!("()")
-                    } else {
-                        format!("({})", h)
-                    }
-                }
-                Value::QuotedList(h) => {
-                    if h.is_nil() {
-                        format!("'()")
-                    } else {
-                        format!("'({})", h)
-                    }
-                }
-                Value::EmptyList => format!("()"),
-                Value::EmptyQuotedList => format!("'()"),
-            }
-        )
+        write!(f, "{}", self.format(&FormatOptions::default()))
     }
 }
 impl<'c> From<()> for Value<'c> {
@@ -328,20 +440,12 @@ impl<'c> From<&'c str> for Value<'c> {
 }
 impl<'c> From<u64> for Value<'c> {
     fn from(value: u64) -> Value<'c> {
-        if value < u8::MAX.into() {
-            Value::Byte(value as u8)
-        } else {
-            Value::UnsignedInteger(value.into())
-        }
+        Value::from(Number::from(value))
     }
 }
 impl<'c> From<i32> for Value<'c> {
     fn from(value: i32) -> Value<'c> {
-        if let Ok(value) = TryInto::<u64>::try_into(value) {
-            Value::UnsignedInteger(value.into())
-        } else {
-            Value::Integer(value.into())
-        }
+        Value::from(Number::from(value))
     }
 }
 
@@ -459,20 +563,12 @@ impl<'c> AsValue<'c> for &'c str {
 }
 impl<'c> AsValue<'c> for u64 {
     fn as_value(&self) -> Value<'c> {
-        if *self < u8::MAX.into() {
-            Value::Byte(*self as u8)
-        } else {
-            Value::UnsignedInteger(self.as_unsigned_integer())
-        }
+        Value::from(Number::from(*self))
     }
 }
 impl<'c> AsValue<'c> for i32 {
     fn as_value(&self) -> Value<'c> {
-        if let Ok(value) = TryInto::<u64>::try_into(*self) {
-            Value::UnsignedInteger(value.as_unsigned_integer())
-        } else {
-            Value::Integer(self.as_integer())
-        }
+        Value::from(Number::from(*self))
     }
 }
 
@@ -505,19 +601,11 @@ impl<'c> AsCell<'c> for Value<'c> {
         match self {
             Value::Symbol(h) => Cell::quoted(Some(h.unquote()), false),
             Value::QuotedSymbol(h) => Cell::quoted(Some(h.quote()), true),
-            Value::List(h) => {
-                let mut cell = Cell::nil();
-                for item in h.clone().into_iter() {
-                    cell.add(&Cell::new(item));
-                }
-                cell
-            }
+            Value::List(h) => h.copy_structure(false),
             Value::QuotedList(h) => {
-                let mut cell = Cell::nil();
-                for item in h.clone().into_iter() {
-                    cell.add(&Cell::new(item));
-                }
-                cell.quote()
+                let mut cell = h.copy_structure(false);
+                cell.set_quoted(true);
+                cell
             }
             _ => Cell::new(self.clone()),
         }
@@ -598,6 +686,61 @@ impl<'c> Quotable for ValueIterator<'c> {
 }
 impl<'c> ValueListIterator<'c> for ValueIterator<'c> {}
 
+/// `ValueRefIterator` borrows the values of a list one cell at a
+/// time by following the tail of each cell, neither cloning cells
+/// nor values.
+///
+/// Unlike [`ValueIterator`] it yields the values as they are stored,
+/// hence the values of a quoted list are not quoted in turn.
+#[derive(Debug, Clone)]
+pub struct ValueRefIterator<'a, 'c> {
+    cell: Option<&'a Cell<'c>>,
+    value: Option<&'a Value<'c>>,
+}
+
+impl<'a, 'c> ValueRefIterator<'a, 'c> {
+    /// iterates over the values of `cell`
+    pub fn new(cell: &'a Cell<'c>) -> ValueRefIterator<'a, 'c> {
+        ValueRefIterator {
+            cell: Some(cell),
+            value: None,
+        }
+    }
+
+    /// iterates over the values of list values or yields `value`
+    /// itself if it is not a list
+    pub fn from_value(value: &'a Value<'c>) -> ValueRefIterator<'a, 'c> {
+        match value {
+            Value::List(cell) | Value::QuotedList(cell) => ValueRefIterator::new(cell),
+            Value::EmptyList | Value::EmptyQuotedList => ValueRefIterator {
+                cell: None,
+                value: None,
+            },
+            value => ValueRefIterator {
+                cell: None,
+                value: Some(value),
+            },
+        }
+    }
+}
+
+impl<'a, 'c> Iterator for ValueRefIterator<'a, 'c> {
+    type Item = &'a Value<'c>;
+
+    fn next(&mut self) -> Option<&'a Value<'c>> {
+        if let Some(value) = self.value.take() {
+            return Some(value);
+        }
+        while let Some(cell) = self.cell {
+            self.cell = cell.tail();
+            if let Some(value) = cell.head.as_ref() {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
 impl<'c> IntoIterator for &Value<'c> {
     type IntoIter = ValueIterator<'c>;
     type Item = Value<'c>;
@@ -627,49 +770,36 @@ impl<'c> IntoIterator for Value<'c> {
     }
 }
 
+/// collects the values into a [`Value::List`], or
+/// [`Value::EmptyList`] if there are none.
 impl<'c> FromIterator<Value<'c>> for Value<'c> {
     fn from_iter<I: IntoIterator<Item = Value<'c>>>(iter: I) -> Value<'c> {
-        let mut cell = Cell::nil();
-        for value in iter {
-            cell.push_value(value);
-        }
-        Value::list(cell)
+        Value::EmptyList.extended(iter)
     }
 }
+
+/// appends the values to lists, preserving whether they are quoted,
+/// whereas empty lists and `nil` become a [`Value::List`] or a
+/// [`Value::QuotedList`] of the values, according to their own
+/// quoting, and other values are replaced by a [`Value::List`] of
+/// the values.
 impl<'c> Extend<Value<'c>> for Value<'c> {
     fn extend<T: IntoIterator<Item = Value<'c>>>(&mut self, iter: T) {
-        if let Value::List(cell) = self {
+        if let Value::List(cell) | Value::QuotedList(cell) = self {
             for value in iter {
                 cell.push_value(value);
             }
-        } else if let Value::QuotedList(cell) = self {
-            for value in iter {
-                cell.push_value(value);
+            return;
+        }
+        let cell = Cell::from_iter(iter);
+        if cell.is_nil() {
+            if !self.is_empty() {
+                *self = Value::EmptyList;
             }
+        } else if self.is_quoted() && self.is_empty() {
+            *self = Value::quoted_list(cell);
         } else {
-            match self.clone() {
-                Value::EmptyList => {
-                    let mut cell = Cell::nil();
-                    for value in iter {
-                        cell.push_value(value)
-                    }
-                    *self = Value::List(cell)
-                }
-                Value::EmptyQuotedList => {
-                    let mut cell = Cell::nil();
-                    for value in iter {
-                        cell.push_value(value)
-                    }
-                    *self = Value::QuotedList(cell)
-                }
-                value => {
-                    let mut cell = Cell::nil();
-                    for value in iter {
-                        cell.push_value(value)
-                    }
-                    *self = Value::List(cell)
-                }
-            }
+            *self = Value::list(cell);
         }
     }
 }
@@ -775,9 +905,9 @@ impl<'c> AsSymbol<'c> for Value<'c> {
 
 impl<'c> AsFloat for Value<'c> {
     fn as_float(&self) -> Float {
-        match self {
-            Value::Float(float) => *float,
-            value => {
+        match self.to_number() {
+            Some(number) => Float::from(number.as_f64()),
+            None => {
                 panic!("cannot convert {:#?} to float", self)
             }
         }
@@ -786,9 +916,9 @@ impl<'c> AsFloat for Value<'c> {
 
 impl<'c> AsInteger for Value<'c> {
     fn as_integer(&self) -> Integer {
-        match self {
-            Value::Integer(integer) => *integer,
-            value => {
+        match self.to_number().and_then(|number| number.as_i64()) {
+            Some(integer) => Integer::from(integer),
+            None => {
                 panic!("cannot convert {:#?} to integer", self)
             }
         }
@@ -797,9 +927,9 @@ impl<'c> AsInteger for Value<'c> {
 
 impl<'c> AsUnsignedInteger for Value<'c> {
     fn as_unsigned_integer(&self) -> UnsignedInteger {
-        match self {
-            Value::UnsignedInteger(unsigned_integer) => *unsigned_integer,
-            value => {
+        match self.to_number().and_then(|number| number.as_u32()) {
+            Some(unsigned_integer) => UnsignedInteger::from(unsigned_integer),
+            None => {
                 panic!("cannot convert {:#?} to unsigned integer", self)
             }
         }
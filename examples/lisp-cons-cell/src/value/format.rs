@@ -0,0 +1,80 @@
+/// `FormatOptions` tells [`Value::format`](crate::Value::format) and
+/// [`Number::format`](crate::Number::format) how to print numbers,
+/// the [`Default`] options printing them the way [`Display`](std::fmt::Display) does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// radix of integers, between 2 and 36, such that integers in
+    /// radix 16 print as `#x1f`, in radix 8 as `#o37`, in radix 2 as
+    /// `#b11111` and in any other radix but 10 as `#36rv`
+    pub radix: u32,
+    /// number of decimal places of floats, `None` printing as many as
+    /// needed to represent them exactly
+    pub precision: Option<usize>,
+    pub bytes: ByteFormat,
+}
+
+/// `ByteFormat` tells whether bytes print as `0x1f` or as `31`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ByteFormat {
+    #[default]
+    Hex,
+    Decimal,
+}
+
+impl Default for FormatOptions {
+    fn default() -> FormatOptions {
+        FormatOptions {
+            radix: 10,
+            precision: None,
+            bytes: ByteFormat::Hex,
+        }
+    }
+}
+
+impl FormatOptions {
+    pub(crate) fn format_byte(&self, value: u8) -> String {
+        match self.bytes {
+            ByteFormat::Hex => format!("0x{:02x}", value),
+            ByteFormat::Decimal => format!("{}", value),
+        }
+    }
+
+    /// formats `value` in [`radix`](Self::radix), falling back to
+    /// radix 10 when it lies outside of `2..=36`
+    pub(crate) fn format_integer(&self, value: i64) -> String {
+        let radix = self.radix;
+        if radix == 10 || !(2..=36).contains(&radix) {
+            return format!("{}", value);
+        }
+        let prefix = match radix {
+            16 => "#x".to_string(),
+            8 => "#o".to_string(),
+            2 => "#b".to_string(),
+            radix => format!("#{}r", radix),
+        };
+        let mut magnitude = value.unsigned_abs();
+        let mut digits = Vec::new();
+        loop {
+            let digit = (magnitude % radix as u64) as u32;
+            digits.push(char::from_digit(digit, radix).unwrap_or('?'));
+            magnitude /= radix as u64;
+            if magnitude == 0 {
+                break;
+            }
+        }
+        let sign = if value < 0 { "-" } else { "" };
+        format!(
+            "{}{}{}",
+            prefix,
+            sign,
+            digits.iter().rev().collect::<String>()
+        )
+    }
+
+    pub(crate) fn format_float(&self, value: f64) -> String {
+        match self.precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => format!("{}", value),
+        }
+    }
+}
@@ -3,16 +3,8 @@ impl_number_type!(i64, Integer, AsInteger, as_integer);
 
 impl From<i32> for Integer {
     fn from(value: i32) -> Integer {
-        if let Ok(value) = TryInto::<u32>::try_into(value) {
-            Integer {
-                value: value.into(),
-            }
-        } else {
-            panic!(
-                "cannot convert from {:#?} to {}",
-                value,
-                Integer::type_name()
-            )
+        Integer {
+            value: value.into(),
         }
     }
 }
@@ -0,0 +1,275 @@
+use std::cmp::{Ordering, PartialEq, PartialOrd};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{Float, FormatOptions, Integer, UnsignedInteger, Value};
+
+/// `Number` is the numeric tower of the lisp values, mixed
+/// arithmetic and comparisons promote the operand of lower rank
+/// `Byte` → `UnsignedInteger` → `Integer` → `Float` and integer
+/// operations that overflow promote their result to the next rank.
+#[derive(Clone, Copy, Debug)]
+pub enum Number {
+    Byte(u8),
+    UnsignedInteger(u32),
+    Integer(i64),
+    Float(f64),
+}
+
+impl Number {
+    /// returns the number held by `value` or `None` if `value` is not
+    /// numeric.
+    pub fn from_value(value: &Value<'_>) -> Option<Number> {
+        match value {
+            Value::Byte(value) => Some(Number::Byte(*value)),
+            Value::UnsignedInteger(value) => Some(Number::UnsignedInteger(**value)),
+            Value::Integer(value) => Some(Number::Integer(**value)),
+            Value::Float(value) => Some(Number::Float(**value)),
+            _ => None,
+        }
+    }
+
+    /// formats the number as told by `options`.
+    pub fn format(&self, options: &FormatOptions) -> String {
+        match self {
+            Number::Byte(value) => options.format_byte(*value),
+            Number::UnsignedInteger(value) => options.format_integer(i64::from(*value)),
+            Number::Integer(value) => options.format_integer(*value),
+            Number::Float(value) => options.format_float(*value),
+        }
+    }
+
+    /// returns the position of `self` in the numeric tower.
+    pub fn rank(&self) -> u8 {
+        match self {
+            Number::Byte(_) => 0,
+            Number::UnsignedInteger(_) => 1,
+            Number::Integer(_) => 2,
+            Number::Float(_) => 3,
+        }
+    }
+
+    /// returns `self` converted to the given `rank`, numbers already
+    /// at or above `rank` are returned unchanged.
+    pub fn promote(self, rank: u8) -> Number {
+        if self.rank() >= rank {
+            return self;
+        }
+        match rank {
+            1 => Number::UnsignedInteger(self.as_u32().unwrap()),
+            2 => Number::Integer(self.as_i64().unwrap()),
+            _ => Number::Float(self.as_f64()),
+        }
+    }
+
+    /// returns `lhs` and `rhs` promoted to the highest rank among them.
+    pub fn coerce(lhs: Number, rhs: Number) -> (Number, Number) {
+        let rank = lhs.rank().max(rhs.rank());
+        (lhs.promote(rank), rhs.promote(rank))
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            Number::Byte(value) => value.into(),
+            Number::UnsignedInteger(value) => value.into(),
+            Number::Integer(value) => value as f64,
+            Number::Float(value) => value,
+        }
+    }
+
+    /// returns `self` as `i64` or `None` if it does not fit or is a
+    /// float with a fractional part.
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            Number::Byte(value) => Some(value.into()),
+            Number::UnsignedInteger(value) => Some(value.into()),
+            Number::Integer(value) => Some(value),
+            Number::Float(value) => {
+                if value.fract() == 0.0 && value >= i64::MIN as f64 && value < i64::MAX as f64 {
+                    Some(value as i64)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// returns `self` as `u32` or `None` if it does not fit.
+    pub fn as_u32(&self) -> Option<u32> {
+        self.as_i64().and_then(|value| u32::try_from(value).ok())
+    }
+
+    /// returns `self` as `u8` or `None` if it does not fit.
+    pub fn as_u8(&self) -> Option<u8> {
+        self.as_i64().and_then(|value| u8::try_from(value).ok())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.as_f64() == 0.0
+    }
+
+    fn apply(
+        self,
+        other: Number,
+        checked_u8: fn(u8, u8) -> Option<u8>,
+        checked_u32: fn(u32, u32) -> Option<u32>,
+        checked_i64: fn(i64, i64) -> Option<i64>,
+        float: fn(f64, f64) -> f64,
+    ) -> Number {
+        match Number::coerce(self, other) {
+            (Number::Byte(lhs), Number::Byte(rhs)) => match checked_u8(lhs, rhs) {
+                Some(value) => Number::Byte(value),
+                None => Number::UnsignedInteger(lhs.into()).apply(
+                    Number::UnsignedInteger(rhs.into()),
+                    checked_u8,
+                    checked_u32,
+                    checked_i64,
+                    float,
+                ),
+            },
+            (Number::UnsignedInteger(lhs), Number::UnsignedInteger(rhs)) => {
+                match checked_u32(lhs, rhs) {
+                    Some(value) => Number::UnsignedInteger(value),
+                    None => Number::Integer(lhs.into()).apply(
+                        Number::Integer(rhs.into()),
+                        checked_u8,
+                        checked_u32,
+                        checked_i64,
+                        float,
+                    ),
+                }
+            }
+            (Number::Integer(lhs), Number::Integer(rhs)) => match checked_i64(lhs, rhs) {
+                Some(value) => Number::Integer(value),
+                None => Number::Float(float(lhs as f64, rhs as f64)),
+            },
+            (lhs, rhs) => Number::Float(float(lhs.as_f64(), rhs.as_f64())),
+        }
+    }
+}
+
+impl Add for Number {
+    type Output = Number;
+
+    fn add(self, other: Number) -> Number {
+        self.apply(
+            other,
+            u8::checked_add,
+            u32::checked_add,
+            i64::checked_add,
+            f64::add,
+        )
+    }
+}
+impl Sub for Number {
+    type Output = Number;
+
+    fn sub(self, other: Number) -> Number {
+        self.apply(
+            other,
+            u8::checked_sub,
+            u32::checked_sub,
+            i64::checked_sub,
+            f64::sub,
+        )
+    }
+}
+impl Mul for Number {
+    type Output = Number;
+
+    fn mul(self, other: Number) -> Number {
+        self.apply(
+            other,
+            u8::checked_mul,
+            u32::checked_mul,
+            i64::checked_mul,
+            f64::mul,
+        )
+    }
+}
+impl Div for Number {
+    type Output = Number;
+
+    fn div(self, other: Number) -> Number {
+        if other.is_zero() && self.rank().max(other.rank()) < 3 {
+            panic!("cannot divide {} by zero", self)
+        }
+        self.apply(
+            other,
+            u8::checked_div,
+            u32::checked_div,
+            i64::checked_div,
+            f64::div,
+        )
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Number) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Number) -> Option<Ordering> {
+        match Number::coerce(*self, *other) {
+            (Number::Byte(lhs), Number::Byte(rhs)) => lhs.partial_cmp(&rhs),
+            (Number::UnsignedInteger(lhs), Number::UnsignedInteger(rhs)) => lhs.partial_cmp(&rhs),
+            (Number::Integer(lhs), Number::Integer(rhs)) => lhs.partial_cmp(&rhs),
+            (lhs, rhs) => lhs.as_f64().partial_cmp(&rhs.as_f64()),
+        }
+    }
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.format(&FormatOptions::default()))
+    }
+}
+
+impl From<u8> for Number {
+    fn from(value: u8) -> Number {
+        Number::Byte(value)
+    }
+}
+impl From<u32> for Number {
+    fn from(value: u32) -> Number {
+        Number::UnsignedInteger(value)
+    }
+}
+impl From<u64> for Number {
+    fn from(value: u64) -> Number {
+        if let Ok(value) = u32::try_from(value) {
+            Number::UnsignedInteger(value)
+        } else if let Ok(value) = i64::try_from(value) {
+            Number::Integer(value)
+        } else {
+            Number::Float(value as f64)
+        }
+    }
+}
+impl From<i32> for Number {
+    fn from(value: i32) -> Number {
+        Number::Integer(value.into())
+    }
+}
+impl From<i64> for Number {
+    fn from(value: i64) -> Number {
+        Number::Integer(value)
+    }
+}
+impl From<f64> for Number {
+    fn from(value: f64) -> Number {
+        Number::Float(value)
+    }
+}
+
+impl<'c> From<Number> for Value<'c> {
+    fn from(number: Number) -> Value<'c> {
+        match number {
+            Number::Byte(value) => Value::Byte(value),
+            Number::UnsignedInteger(value) => Value::UnsignedInteger(UnsignedInteger::from(value)),
+            Number::Integer(value) => Value::Integer(Integer::from(value)),
+            Number::Float(value) => Value::Float(Float::from(value)),
+        }
+    }
+}
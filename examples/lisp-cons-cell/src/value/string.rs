@@ -0,0 +1,58 @@
+/// returns `string` with `"`, `\`, newlines, tabs and carriage
+/// returns escaped the way the lisp printer writes them
+pub fn escape_string(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len());
+    for c in string.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// returns `string` with the escapes written by [`escape_string`]
+/// resolved, unknown escapes resolve to the escaped character itself
+pub fn unescape_string(string: &str) -> String {
+    let mut unescaped = String::with_capacity(string.len());
+    let mut chars = string.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('r') => unescaped.push('\r'),
+            Some(c) => unescaped.push(c),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// reads the string literal at the start of `source` returning its
+/// unescaped contents and the number of bytes consumed including
+/// both quotes, or `None` when `source` does not start with a
+/// terminated string literal
+pub fn read_string_literal(source: &str) -> Option<(String, usize)> {
+    if !source.starts_with('"') {
+        return None;
+    }
+    let mut escaping = false;
+    for (index, c) in source.char_indices().skip(1) {
+        if escaping {
+            escaping = false;
+        } else if c == '\\' {
+            escaping = true;
+        } else if c == '"' {
+            return Some((unescape_string(&source[1..index]), index + 1));
+        }
+    }
+    None
+}
@@ -1,5 +1,5 @@
 #![allow(unused)]
-use cons_cell::{Cell, Value};
+use cons_cell::{nconc, AsCell, Cell, Value};
 use k9::assert_equal;
 
 #[test]
@@ -36,3 +36,78 @@ fn test_cell_from_value() {
     let head = cell.head();
     assert_equal!(head, Some(Value::Byte(0xF1u8)));
 }
+
+#[test]
+fn test_value_as_cell_round_trip_preserves_quoting() {
+    let cell = Cell::from_slice(&[
+        Value::symbol("a"),
+        Value::quoted_symbol("b"),
+        Value::quoted_list(Cell::from_slice(&[Value::symbol("c"), Value::integer(1)])),
+    ]);
+    let value = Value::List(cell.clone());
+    let round_trip = value.as_cell();
+    assert_equal!(round_trip, cell);
+    assert_equal!(round_trip.to_string(), "a 'b '(c 1)");
+    assert_equal!(Value::List(round_trip), value);
+
+    let quoted = Value::quoted_list(cell.clone());
+    assert_equal!(quoted.to_string(), "'(a 'b '(c 1))");
+    assert_equal!(Value::from(quoted.as_cell()), quoted);
+}
+
+#[test]
+fn test_value_as_cell_preserves_shared_tails() {
+    let tail = Cell::from_slice(&[Value::integer(2), Value::integer(3)]);
+    let mut list = Cell::from(Value::integer(1));
+    unsafe { list.setcdr(&tail) };
+
+    let copy = Value::List(list).as_cell();
+    assert_equal!(copy.to_string(), "1 2 3");
+    assert_equal!(copy.len(), 3);
+    assert_equal!(std::ptr::eq(copy.tail().unwrap(), &tail), true);
+}
+
+#[test]
+fn test_cell_deep_clone() {
+    let tail = Cell::from_slice(&[Value::integer(2), Value::list(Cell::from("x"))]);
+    let mut list = Cell::from(Value::quoted_symbol("a"));
+    unsafe { list.setcdr(&tail) };
+
+    let copy = list.deep_clone();
+    assert_equal!(copy, list);
+    assert_equal!(copy.to_string(), "'a 2 (x)");
+    assert_equal!(copy.len(), 3);
+    assert_equal!(std::ptr::eq(copy.tail().unwrap(), &tail), false);
+
+    let nested = |cell: &Cell| match cell.iter().last() {
+        Some(Value::List(nested)) => std::ptr::from_ref(nested).addr(),
+        _ => 0,
+    };
+    assert_equal!(nested(&copy) == 0, false);
+    assert_equal!(nested(&copy) == nested(&list), false);
+    assert_equal!(Cell::nil().deep_clone(), Cell::nil());
+}
+
+#[test]
+fn test_cell_copies_preserve_cycles() {
+    // the last cell links back to the second one, which lives on the
+    // heap and therefore does not move along with the list
+    let circular = || {
+        let mut list = Cell::from_iter([1, 2, 3].map(Value::integer));
+        let second = list.tail().unwrap();
+        unsafe { nconc(&mut list, second) };
+        list
+    };
+    let list = circular();
+    assert_equal!(list.to_string(), "1 2 3 . #circular#");
+
+    for copy in [list.deep_clone(), Value::List(circular()).as_cell()] {
+        assert_equal!(copy.to_string(), "1 2 3 . #circular#");
+        assert_equal!(copy.is_circular(), true);
+        assert_equal!(copy == list, true);
+        assert_equal!(
+            std::ptr::eq(copy.tail().unwrap(), list.tail().unwrap()),
+            false
+        );
+    }
+}
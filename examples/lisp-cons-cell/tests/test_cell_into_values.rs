@@ -0,0 +1,72 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+
+use cons_cell::{Cell, Value};
+use k9::assert_equal;
+
+thread_local! {
+    static LIVE: std::cell::Cell<isize> = const { std::cell::Cell::new(0) };
+}
+
+/// counts the bytes allocated and not yet deallocated by the current
+/// thread, such that tests running in parallel do not interfere
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = LIVE.try_with(|live| live.set(live.get() + layout.size() as isize));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = LIVE.try_with(|live| live.set(live.get() - layout.size() as isize));
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+fn live() -> isize {
+    LIVE.with(|live| live.get())
+}
+
+#[test]
+fn test_cell_into_values() {
+    let list = Cell::from_slice(&[Value::symbol("a"), Value::integer(1), Value::from("s")]);
+    assert_equal!(
+        list.into_values().collect::<Vec<_>>(),
+        vec![Value::symbol("a"), Value::integer(1), Value::from("s")]
+    );
+    assert_equal!(Cell::nil().into_values().count(), 0);
+}
+
+#[test]
+fn test_cell_into_values_deallocates_cells_as_consumed() {
+    let before = live();
+    let list = Cell::from_iter((0..1000).map(Value::integer));
+    let full = live();
+    let cell_size = std::mem::size_of::<Cell>() as isize;
+    assert_equal!(full - before >= 999 * cell_size, true);
+
+    let mut values = list.into_values();
+    for expected in 0..500 {
+        assert_equal!(values.next(), Some(Value::integer(expected)));
+    }
+    assert_equal!(full - live() >= 499 * cell_size, true);
+    assert_equal!(values.count(), 500);
+    assert_equal!(full - live() >= 999 * cell_size, true);
+}
+
+#[test]
+fn test_cell_into_values_leaves_shared_tails_allocated() {
+    let tail = Cell::from_slice(&[Value::integer(2), Value::integer(3)]);
+    let mut list = Cell::from(Value::integer(1));
+    unsafe { list.setcdr(&tail) };
+
+    assert_equal!(
+        list.into_values().collect::<Vec<_>>(),
+        vec![Value::integer(1), Value::integer(2), Value::integer(3)]
+    );
+    assert_equal!(tail.values(), vec![Value::integer(2), Value::integer(3)]);
+    assert_equal!(tail.len(), 2);
+}
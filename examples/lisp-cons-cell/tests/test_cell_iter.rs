@@ -14,6 +14,46 @@ fn test_cell_into_iterator() {
     assert_equal!(strings, vec!["a", "b", "c"]);
 }
 
+#[test]
+fn test_cell_iter_pairs() {
+    let mut cell = Cell::from("a");
+    cell.add(&Cell::from("b"));
+    cell.add(&Cell::from("c"));
+
+    let pairs = cell
+        .iter_pairs()
+        .map(|(car, cdr)| (car.clone(), cdr.values()))
+        .collect::<Vec<(Value, Vec<Value>)>>();
+    assert_equal!(
+        pairs,
+        vec![
+            (
+                Value::symbol("a"),
+                vec![Value::symbol("b"), Value::symbol("c")]
+            ),
+            (Value::symbol("b"), vec![Value::symbol("c")]),
+            (Value::symbol("c"), vec![]),
+        ]
+    );
+    let (_, last) = cell.iter_pairs().last().unwrap();
+    assert_equal!(last.is_nil(), true);
+}
+
+#[test]
+fn test_cell_iter_pairs_empty() {
+    assert_equal!(Cell::nil().iter_pairs().count(), 0);
+
+    let mut cell = Cell::nil();
+    cell.add(&Cell::from("a"));
+    cell.add(&Cell::from("b"));
+    assert_equal!(
+        cell.iter_pairs()
+            .map(|(car, _)| car.clone())
+            .collect::<Vec<Value>>(),
+        vec![Value::symbol("a"), Value::symbol("b")]
+    );
+}
+
 #[test]
 fn test_cell_from_iterator_quoted_list() {
     let list = Value::list({
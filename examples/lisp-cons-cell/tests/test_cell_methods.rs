@@ -1,5 +1,6 @@
 #![allow(unused)]
-use cons_cell::{Cell, Value};
+use cons_cell::{Cell, Quotable, Value};
+use unique_pointer::UniquePointer;
 use k9::assert_equal;
 
 #[test]
@@ -133,3 +134,406 @@ fn test_add_when_tail_is_null() {
     assert_equal!(tail.values(), vec![Value::from("tail")]);
     assert_equal!(tail.len(), 1);
 }
+
+#[test]
+fn test_reverse() {
+    let mut head = Cell::from("a");
+    head.add(&Cell::from("b"));
+    head.add(&Cell::from("c"));
+
+    let reversed = head.reverse();
+    assert_equal!(
+        reversed.values(),
+        vec![Value::symbol("c"), Value::symbol("b"), Value::symbol("a")]
+    );
+    assert_equal!(
+        head.values(),
+        vec![Value::symbol("a"), Value::symbol("b"), Value::symbol("c")]
+    );
+    assert_equal!(Cell::nil().reverse(), Cell::nil());
+}
+
+#[test]
+fn test_nreverse() {
+    let mut head = Cell::from("a");
+    head.add(&Cell::from("b"));
+    head.add(&Cell::from("c"));
+    head.add(&Cell::from("d"));
+
+    head.nreverse();
+    assert_equal!(
+        head.values(),
+        vec![
+            Value::symbol("d"),
+            Value::symbol("c"),
+            Value::symbol("b"),
+            Value::symbol("a")
+        ]
+    );
+    assert_equal!(head.len(), 4);
+
+    let mut single = Cell::from("x");
+    single.nreverse();
+    assert_equal!(single.values(), vec![Value::symbol("x")]);
+
+    let mut nil = Cell::nil();
+    nil.nreverse();
+    assert_equal!(nil.is_nil(), true);
+}
+
+#[test]
+fn test_nreverse_shared_tail() {
+    let mut head = Cell::from("a");
+    head.add(&Cell::from("b"));
+    head.add(&Cell::from("c"));
+    let copy = head.clone();
+    let tail = head.tail().unwrap();
+
+    head.nreverse();
+    assert_equal!(
+        head.values(),
+        vec![Value::symbol("c"), Value::symbol("b"), Value::symbol("a")]
+    );
+    assert_equal!(tail.values(), vec![Value::symbol("b"), Value::symbol("a")]);
+    assert_equal!(
+        copy.values(),
+        vec![Value::symbol("a"), Value::symbol("b"), Value::symbol("c")]
+    );
+}
+
+#[test]
+fn test_nreverse_relinks_tails() {
+    let mut head = Cell::from("a");
+    head.add(&Cell::from("b"));
+    head.add(&Cell::from("c"));
+    head.add(&Cell::from("d"));
+    let copy = head.clone();
+    let addrs = |head: &Cell| {
+        let mut addrs = Vec::new();
+        let mut cell = head.tail();
+        while let Some(tail) = cell {
+            addrs.push(UniquePointer::provenance_of_const_ptr(tail));
+            cell = tail.tail();
+        }
+        addrs
+    };
+    let refs = |head: &Cell| {
+        let mut refs = vec![head.refs()];
+        let mut cell = head.tail();
+        while let Some(tail) = cell {
+            refs.push(tail.refs());
+            cell = tail.tail();
+        }
+        refs
+    };
+    let (b, c, d) = match addrs(&head)[..] {
+        [b, c, d] => (b, c, d),
+        _ => unreachable!(),
+    };
+    let before = refs(&head);
+
+    head.nreverse();
+    assert_equal!(
+        head.values(),
+        vec![
+            Value::symbol("d"),
+            Value::symbol("c"),
+            Value::symbol("b"),
+            Value::symbol("a")
+        ]
+    );
+    // the cells in between are relinked rather than rewritten, the
+    // first and last ones swap heads
+    assert_equal!(addrs(&head), vec![c, b, d]);
+    assert_equal!(
+        refs(&head),
+        vec![before[0], before[2], before[1], before[3]]
+    );
+    assert_equal!(head.tail().map(Cell::len), Some(3));
+    assert_equal!(
+        copy.values(),
+        vec![
+            Value::symbol("a"),
+            Value::symbol("b"),
+            Value::symbol("c"),
+            Value::symbol("d")
+        ]
+    );
+}
+
+#[test]
+fn test_len_is_maintained() {
+    let mut head = Cell::nil();
+    assert_equal!(head.len(), 0);
+    assert_equal!(head.is_empty(), true);
+
+    head.add(&Cell::from("a"));
+    assert_equal!(head.len(), 1);
+    assert_equal!(head.is_empty(), false);
+
+    head.add(&Cell::from("b"));
+    head.add(&Cell::from("c"));
+    assert_equal!(head.len(), 3);
+    assert_equal!(head.tail().unwrap().len(), 2);
+    assert_equal!(head.clone().len(), 3);
+
+    let mut tail = Cell::from("x");
+    tail.add(&Cell::from("y"));
+    head.add(&tail);
+    assert_equal!(head.len(), 5);
+    assert_equal!(head.values().len(), 5);
+
+    head.nreverse();
+    assert_equal!(head.len(), 5);
+    assert_equal!(head.head(), Some(Value::symbol("y")));
+
+    assert_equal!(head.pop(), true);
+    assert_equal!(head.len(), 1);
+    assert_equal!(head.pop(), true);
+    assert_equal!(head.len(), 0);
+    assert_equal!(head.is_empty(), true);
+}
+
+#[test]
+fn test_setcdr() {
+    let mut head = Cell::from("a");
+    head.add(&Cell::from("b"));
+
+    let mut tail = Cell::from("x");
+    tail.add(&Cell::from("y"));
+    tail.add(&Cell::from("z"));
+
+    unsafe { head.setcdr(&tail) };
+    assert_equal!(head.len(), 4);
+    assert_equal!(
+        head.values(),
+        vec![
+            Value::symbol("a"),
+            Value::symbol("x"),
+            Value::symbol("y"),
+            Value::symbol("z")
+        ]
+    );
+
+    unsafe { head.setcdr(&Cell::nil()) };
+    assert_equal!(head.len(), 1);
+    assert_equal!(head.values(), vec![Value::symbol("a")]);
+}
+
+#[test]
+fn test_circular_list_display() {
+    let mut a = Cell::from("a");
+    let mut b = Cell::from("b");
+    unsafe { a.setcdr(&b) };
+    assert_equal!(a.is_circular(), false);
+    assert_equal!(a.to_string(), "a b");
+
+    unsafe { b.setcdr(&a) };
+    assert_equal!(a.is_circular(), true);
+    assert_equal!(b.is_circular(), true);
+    assert_equal!(a.to_string(), "a b . #circular#");
+    assert_equal!(b.to_string(), "b a . #circular#");
+    assert_equal!(format!("{:?}", a).ends_with(" . #circular#"), true);
+    assert_equal!(a.values(), vec![Value::symbol("a"), Value::symbol("b")]);
+
+    // whichever cell of the cycle dropped first would leave the other
+    // one linking to freed stack memory, see `Cell::setcdr`
+    std::mem::forget(a);
+    std::mem::forget(b);
+}
+
+#[test]
+fn test_circular_list_self_reference() {
+    let mut cell = Cell::from(Value::from("self"));
+    let this: &Cell = UniquePointer::read_only(&cell).extend_lifetime();
+    unsafe { cell.setcdr(this) };
+
+    assert_equal!(cell.is_circular(), true);
+    assert_equal!(cell.to_string(), r#""self" . #circular#"#);
+    assert_equal!(cell.values(), vec![Value::from("self")]);
+}
+
+#[test]
+fn test_from_slice() {
+    let values = [
+        Value::symbol("a"),
+        Value::integer(1),
+        Value::quoted_symbol("b"),
+    ];
+    let cell = Cell::from_slice(&values);
+    assert_equal!(cell.len(), 3);
+    assert_equal!(cell.values(), values.to_vec());
+    assert_equal!(cell.to_string(), "a 1 'b");
+    assert_equal!(Cell::from_slice(&[]), Cell::nil());
+}
+
+#[test]
+fn test_dedup() {
+    let mut cell = Cell::from_slice(&[
+        Value::symbol("a"),
+        Value::symbol("a"),
+        Value::integer(1),
+        Value::from("s"),
+        Value::from("s"),
+        Value::symbol("a"),
+        Value::symbol("a"),
+    ]);
+    assert_equal!(cell.dedup(), 3);
+    assert_equal!(cell.len(), 4);
+    assert_equal!(
+        cell.values(),
+        vec![
+            Value::symbol("a"),
+            Value::integer(1),
+            Value::from("s"),
+            Value::symbol("a")
+        ]
+    );
+    assert_equal!(cell.dedup(), 0);
+
+    let mut cell = Cell::from_slice(&[Value::integer(7), Value::integer(7)]);
+    assert_equal!(cell.dedup(), 1);
+    assert_equal!(cell.values(), vec![Value::integer(7)]);
+
+    let mut nil = Cell::nil();
+    assert_equal!(nil.dedup(), 0);
+    assert_equal!(nil, Cell::nil());
+}
+
+#[test]
+fn test_dedup_by() {
+    let mut cell = Cell::from_iter([1, 2, 4, 5, 7, 9, 10].map(Value::integer));
+    let parity = |value: &Value| value.to_string().parse::<i64>().unwrap_or_default() % 2;
+    assert_equal!(
+        cell.dedup_by(|value, previous| parity(value) == parity(previous)),
+        3
+    );
+    assert_equal!(
+        cell.values(),
+        vec![1, 2, 5, 10]
+            .into_iter()
+            .map(Value::integer)
+            .collect::<Vec<_>>()
+    );
+    assert_equal!(cell.len(), 4);
+}
+
+#[test]
+fn test_sort_by() {
+    let mut cell = Cell::from_iter([5, 3, 9, 1, 4, 1, 8].map(Value::integer));
+    cell.sort_by(|a, b| a.cmp(b));
+    assert_equal!(
+        cell.values(),
+        vec![1, 1, 3, 4, 5, 8, 9]
+            .into_iter()
+            .map(Value::integer)
+            .collect::<Vec<_>>()
+    );
+    assert_equal!(cell.len(), 7);
+    assert_equal!(cell.to_string(), "1 1 3 4 5 8 9");
+
+    cell.sort_by(|a, b| b.cmp(a));
+    assert_equal!(cell.to_string(), "9 8 5 4 3 1 1");
+
+    let mut nil = Cell::nil();
+    nil.sort_by(|a, b| a.cmp(b));
+    assert_equal!(nil, Cell::nil());
+}
+
+#[test]
+fn test_sort_by_is_stable() {
+    let mut cell = Cell::from_slice(&[
+        Value::from("bb"),
+        Value::from("a"),
+        Value::from("cc"),
+        Value::from("d"),
+        Value::from("eee"),
+    ]);
+    let len = |value: &Value| value.to_string().len();
+    cell.sort_by(|a, b| len(a).cmp(&len(b)));
+    assert_equal!(
+        cell.values(),
+        vec![
+            Value::from("a"),
+            Value::from("d"),
+            Value::from("bb"),
+            Value::from("cc"),
+            Value::from("eee")
+        ]
+    );
+}
+
+#[test]
+fn test_zip_and_unzip() {
+    let keys = Cell::from_slice(&[Value::symbol("a"), Value::symbol("b"), Value::symbol("c")]);
+    let values = Cell::from_iter([1, 2].map(Value::integer));
+
+    let zipped = keys.zip(&values);
+    assert_equal!(zipped.len(), 2);
+    assert_equal!(Value::list(zipped.clone()).to_string(), "((a 1) (b 2))");
+
+    let (cars, cadrs) = zipped.unzip();
+    assert_equal!(cars.values(), vec![Value::symbol("a"), Value::symbol("b")]);
+    assert_equal!(cadrs, values);
+
+    let (cars, cadrs) =
+        Cell::from_slice(&[Value::integer(1), Value::list(Cell::from("x"))]).unzip();
+    assert_equal!(cars.values(), vec![Value::Nil, Value::symbol("x")]);
+    assert_equal!(cadrs.values(), vec![Value::Nil, Value::Nil]);
+
+    assert_equal!(keys.zip(&Cell::nil()), Cell::nil());
+    assert_equal!(Cell::nil().unzip(), (Cell::nil(), Cell::nil()));
+}
+
+#[test]
+fn test_pairs() {
+    let list = Cell::from_iter([1, 2, 3].map(Value::integer));
+    assert_equal!(Value::list(list.pairs()).to_string(), "((1 2) (2 3))");
+    assert_equal!(Cell::from(Value::integer(1)).pairs(), Cell::nil());
+    assert_equal!(Cell::nil().pairs(), Cell::nil());
+}
+
+#[test]
+fn test_flatten() {
+    let list = Cell::from_slice(&[
+        Value::symbol("a"),
+        Value::list(Cell::from_slice(&[
+            Value::integer(1),
+            Value::list(Cell::from_slice(&[Value::integer(2), Value::integer(3)])),
+        ])),
+        Value::EmptyList,
+        Value::symbol("b"),
+    ]);
+    assert_equal!(list.to_string(), "a (1 (2 3)) () b");
+    assert_equal!(list.flatten(0).to_string(), "a (1 (2 3)) () b");
+    assert_equal!(list.flatten(1).to_string(), "a 1 (2 3) b");
+    assert_equal!(list.flatten(2).to_string(), "a 1 2 3 b");
+    assert_equal!(list.flatten(usize::MAX).len(), 5);
+    assert_equal!(list.len(), 4);
+    assert_equal!(Cell::nil().flatten(1), Cell::nil());
+}
+
+#[test]
+fn test_flatten_quoted() {
+    let list = Cell::from_slice(&[
+        Value::symbol("a"),
+        Value::quoted_list(Cell::from_slice(&[
+            Value::symbol("b"),
+            Value::list(Cell::from_slice(&[Value::symbol("c")])),
+        ])),
+    ]);
+    assert_equal!(list.to_string(), "a '(b (c))");
+    assert_equal!(list.flatten(1).to_string(), "a 'b '(c)");
+    assert_equal!(list.flatten(2).to_string(), "a 'b 'c");
+    assert_equal!(
+        list.flatten(2).values(),
+        vec![
+            Value::symbol("a"),
+            Value::quoted_symbol("b"),
+            Value::quoted_symbol("c")
+        ]
+    );
+
+    let quoted = list.clone().quote();
+    assert_equal!(quoted.flatten(2).is_quoted(), true);
+}
@@ -1,5 +1,6 @@
 #![allow(unused)]
 use cons_cell::{Cell, Value};
+use unique_pointer::UniquePointer;
 use k9::assert_equal;
 
 #[test]
@@ -15,3 +16,33 @@ fn test_iterator() {
         vec![r#""head""#, "10", "x"]
     );
 }
+
+#[test]
+fn test_debug_graph() {
+    let mut head = Cell::new(Value::from("head"));
+    head.add(&mut Cell::new(Value::integer(10)));
+    head.add(&mut Cell::new(Value::symbol("x")));
+    let head = UniquePointer::from(head);
+
+    let graph = format!("{:?}", head.debug_graph(1));
+    assert_equal!(graph.contains("] \"head\" {tail: "), true);
+    assert_equal!(graph.ends_with("] 10 {...}}"), true);
+
+    let graph = format!("{:#?}", head.debug_graph(3));
+    assert_equal!(
+        graph
+            .lines()
+            .map(|line| line.split_once(']').map(|(_, rest)| rest).unwrap_or(line))
+            .collect::<Vec<&str>>(),
+        vec![
+            " \"head\" {",
+            " 10 {",
+            " x {",
+            "            tail: NULL",
+            "        }",
+            "    }",
+            "}",
+        ]
+    );
+    assert_equal!(graph.lines().nth(1).unwrap().starts_with("    tail: "), true);
+}
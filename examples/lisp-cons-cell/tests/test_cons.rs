@@ -1,6 +1,8 @@
 #![allow(unused)]
 use cons_cell::{
-    append, assert_debug_equal, assert_display_equal, car, cdr, cons, list, Cell, Value,
+    append, assert_debug_equal, assert_display_equal, caaaar, caadr, caar, cadddr, caddr, cadr,
+    car, cdar, cddddr, cdddr, cddr, cdr, cons, delete, list, member, memq, nconc, position,
+    positionq, setcdr, splice_after, Cell, Value,
 };
 use k9::assert_equal;
 
@@ -63,3 +65,135 @@ fn test_append() {
         ]
     );
 }
+
+#[test]
+fn test_setcdr() {
+    let mut cell = Cell::from("a");
+    let tail = list([Value::symbol("b"), Value::symbol("c")]);
+    unsafe { setcdr(&mut cell, &tail) };
+    assert_equal!(cell.len(), 3);
+    assert_display_equal!(Value::list(cell.clone()), "(a b c)");
+
+    unsafe { setcdr(&mut cell, &Value::Nil) };
+    assert_equal!(cell.len(), 1);
+}
+
+#[test]
+fn test_nconc() {
+    // `tail` is declared first such that it is dropped after `list`
+    let mut tail = Cell::from("c");
+    tail.add(&Cell::from("d"));
+    let mut list = Cell::from("a");
+    list.add(&Cell::from("b"));
+
+    unsafe { nconc(&mut list, &tail) };
+    assert_equal!(list.len(), 4);
+    assert_display_equal!(list, "a b c d");
+    assert_display_equal!(tail, "c d");
+
+    let mut empty = Cell::nil();
+    unsafe { nconc(&mut empty, &tail) };
+    assert_display_equal!(empty, "c d");
+    assert_equal!(empty.len(), 2);
+
+    unsafe { nconc(&mut empty, &Cell::nil()) };
+    assert_equal!(empty.len(), 2);
+}
+
+#[test]
+fn test_splice_after() {
+    let mut list = Cell::from("a");
+    list.add(&Cell::from("d"));
+    let mut items = Cell::from("b");
+    items.add(&Cell::from("c"));
+
+    assert_equal!(splice_after(&mut list, 0, &items), true);
+    assert_display_equal!(list, "a b c d");
+    assert_equal!(list.len(), 4);
+    assert_display_equal!(items, "b c");
+
+    assert_equal!(splice_after(&mut list, 3, &Cell::from("e")), true);
+    assert_display_equal!(list, "a b c d e");
+    assert_equal!(list.len(), 5);
+
+    assert_equal!(splice_after(&mut list, 5, &Cell::from("f")), false);
+    assert_display_equal!(list, "a b c d e");
+}
+
+#[test]
+fn test_delete() {
+    let mut list = Cell::from_iter([
+        Value::symbol("x"),
+        Value::symbol("a"),
+        Value::symbol("x"),
+        Value::symbol("b"),
+        Value::symbol("x"),
+    ]);
+    assert_equal!(delete(&mut list, &Value::symbol("x")), 3);
+    assert_display_equal!(list, "a b");
+    assert_equal!(list.len(), 2);
+
+    assert_equal!(delete(&mut list, &Value::symbol("z")), 0);
+    assert_display_equal!(list, "a b");
+
+    let mut list = Cell::from_iter([Value::from("s"), Value::from("s")]);
+    assert_equal!(delete(&mut list, &Value::from("s")), 2);
+    assert_equal!(list.is_empty(), true);
+    assert_equal!(list.is_nil(), true);
+}
+
+#[test]
+fn test_member_and_position() {
+    let list = Cell::from_iter([
+        Value::symbol("a"),
+        Value::from("s"),
+        Value::integer(1),
+        Value::from("s"),
+    ]);
+    assert_display_equal!(member(&list, &Value::from("s")).unwrap(), r#""s" 1 "s""#);
+    assert_display_equal!(member(&list, &Value::integer(1)).unwrap(), r#"1 "s""#);
+    assert_equal!(member(&list, &Value::symbol("z")), None);
+    assert_equal!(position(&list, &Value::symbol("a")), Some(0));
+    assert_equal!(position(&list, &Value::from("s")), Some(1));
+    assert_equal!(position(&list, &Value::symbol("z")), None);
+    assert_equal!(position(&Cell::nil(), &Value::Nil), None);
+}
+
+#[test]
+fn test_memq_and_positionq() {
+    let list = Cell::from_iter([Value::from("s"), Value::symbol("a"), Value::from("s")]);
+    let (last, _) = list.iter_pairs().last().unwrap();
+
+    assert_equal!(memq(&list, &Value::from("s")), None);
+    assert_equal!(positionq(&list, &Value::from("s")), None);
+    assert_display_equal!(memq(&list, last).unwrap(), r#""s""#);
+    assert_equal!(positionq(&list, last), Some(2));
+    assert_equal!(position(&list, last), Some(0));
+}
+
+#[test]
+fn test_compound_accessors() {
+    // ((a b) c d (e f) g)
+    let value = list([
+        list([Value::symbol("a"), Value::symbol("b")]),
+        Value::symbol("c"),
+        Value::symbol("d"),
+        list([Value::symbol("e"), Value::symbol("f")]),
+        Value::symbol("g"),
+    ]);
+    assert_equal!(caar(&value), Value::symbol("a"));
+    assert_equal!(cadr(&value), Value::symbol("c"));
+    assert_display_equal!(cdar(&value), "(b)");
+    assert_display_equal!(cddr(&value), "(d (e f) g)");
+    assert_equal!(caddr(&value), Value::symbol("d"));
+    assert_display_equal!(cdddr(&value), "((e f) g)");
+    assert_display_equal!(cadddr(&value), "(e f)");
+    assert_display_equal!(cddddr(&value), "(g)");
+    assert_equal!(cadr(&value), car(&cdr(&value)));
+    assert_equal!(cadddr(&value), car(&cdr(&cdr(&cdr(&value)))));
+
+    assert_equal!(caaaar(&value), Value::Nil);
+    assert_equal!(caadr(&value), Value::Nil);
+    assert_equal!(cadr(&Value::symbol("a")), Value::Nil);
+    assert_equal!(cddr(&Value::Nil), Value::Nil);
+}
@@ -0,0 +1,94 @@
+use cons_cell::{assert_display_equal, destructure, list, ArityError, Value};
+use k9::assert_equal;
+
+#[test]
+fn test_destructure_exact() -> Result<(), ArityError> {
+    destructure!(list!(Value::symbol("x"), Value::from(1u8)) => (name value));
+    assert_equal!(name, Value::symbol("x"));
+    assert_equal!(value, Value::from(1u8));
+    Ok(())
+}
+
+#[test]
+fn test_destructure_rest() -> Result<(), ArityError> {
+    let value = list!(
+        Value::symbol("lambda"),
+        list!(Value::symbol("x")),
+        Value::symbol("x"),
+        Value::symbol("y")
+    );
+    destructure!(value => (keyword params . body));
+    assert_equal!(keyword, Value::symbol("lambda"));
+    assert_display_equal!(params, "(x)");
+    assert_display_equal!(body, "(x y)");
+
+    destructure!(value => (a b c d . rest));
+    assert_equal!(d, Value::symbol("y"));
+    assert_equal!(rest, Value::EmptyList);
+
+    destructure!(value => (. all));
+    assert_equal!(all, value);
+    Ok(())
+}
+
+#[test]
+fn test_destructure_quoted_rest() -> Result<(), ArityError> {
+    let value = Value::collect_quoted([Value::from(1u8), Value::from(2u8)]);
+    destructure!(value => (head . tail));
+    assert_equal!(head, Value::from(1u8));
+    assert_display_equal!(tail, "'(0x02)");
+    Ok(())
+}
+
+#[test]
+fn test_destructure_empty() -> Result<(), ArityError> {
+    destructure!(Value::EmptyList => ());
+    destructure!(Value::Nil => (. rest));
+    assert_equal!(rest, Value::EmptyList);
+    Ok(())
+}
+
+fn bind_pair(value: Value) -> Result<(Value, Value), ArityError> {
+    destructure!(value => (a b));
+    Ok((a, b))
+}
+
+fn bind_at_least_one(value: Value) -> Result<(Value, Value), ArityError> {
+    destructure!(value => (a . rest));
+    Ok((a, rest))
+}
+
+#[test]
+fn test_destructure_too_many() {
+    let error = bind_pair(list!(Value::from(1u8), Value::from(2u8), Value::from(3u8))).unwrap_err();
+    assert_equal!(
+        error,
+        ArityError {
+            pattern: "(a b)",
+            expected: 2,
+            variadic: false,
+            found: Some(3),
+            value: "(0x01 0x02 0x03)".to_string(),
+        }
+    );
+    assert_display_equal!(error, "(a b) expects 2 elements but (0x01 0x02 0x03) has 3");
+}
+
+#[test]
+fn test_destructure_too_few() {
+    assert_display_equal!(
+        bind_pair(list!(Value::from(1u8))).unwrap_err(),
+        "(a b) expects 2 elements but (0x01) has 1"
+    );
+    assert_display_equal!(
+        bind_at_least_one(Value::EmptyList).unwrap_err(),
+        "(a . rest) expects at least 1 element but () has 0"
+    );
+}
+
+#[test]
+fn test_destructure_not_a_list() {
+    let error = bind_pair(Value::from(1u8)).unwrap_err();
+    assert_equal!(error.found, None);
+    assert_display_equal!(error, "(a b) expects a list but got 0x01");
+}
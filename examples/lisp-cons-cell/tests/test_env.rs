@@ -0,0 +1,64 @@
+use cons_cell::{Env, Value};
+use k9::assert_equal;
+
+#[test]
+fn test_env_define_and_lookup() {
+    let mut env = Env::new();
+    assert_equal!(env.lookup("x"), None);
+    assert_equal!(env.define("x", Value::integer(1i64)), None);
+    assert_equal!(env.lookup("x"), Some(&Value::integer(1i64)));
+    assert_equal!(
+        env.define("x", Value::integer(2i64)),
+        Some(Value::integer(1i64))
+    );
+    assert_equal!(env.lookup("x"), Some(&Value::integer(2i64)));
+    assert_equal!(env.depth(), 0);
+}
+
+#[test]
+fn test_env_lookup_walks_parents() {
+    let mut global = Env::new();
+    global.define("x", Value::integer(1i64));
+    let mut local = Env::with_parent(&global);
+    local.define("y", Value::integer(2i64));
+
+    assert_equal!(local.depth(), 1);
+    assert_equal!(local.lookup("x"), Some(&Value::integer(1i64)));
+    assert_equal!(local.lookup("y"), Some(&Value::integer(2i64)));
+    assert_equal!(global.is_bound("y"), false);
+}
+
+#[test]
+fn test_env_define_shadows_parent() {
+    let mut global = Env::new();
+    global.define("x", Value::integer(1i64));
+    let mut local = Env::with_parent(&global);
+    local.define("x", Value::string("local"));
+
+    assert_equal!(local.lookup("x"), Some(&Value::string("local")));
+    assert_equal!(global.lookup("x"), Some(&Value::integer(1i64)));
+}
+
+#[test]
+fn test_env_set_updates_innermost_binding() {
+    let mut global = Env::new();
+    global.define("x", Value::integer(1i64));
+    global.define("y", Value::integer(1i64));
+    let mut local = Env::with_parent(&global);
+    local.define("x", Value::integer(2i64));
+
+    assert_equal!(
+        local.set("x", Value::integer(3i64)),
+        Some(Value::integer(2i64))
+    );
+    assert_equal!(global.lookup("x"), Some(&Value::integer(1i64)));
+
+    assert_equal!(
+        local.set("y", Value::integer(4i64)),
+        Some(Value::integer(1i64))
+    );
+    assert_equal!(global.lookup("y"), Some(&Value::integer(4i64)));
+
+    assert_equal!(local.set("z", Value::integer(5i64)), None);
+    assert_equal!(local.is_bound("z"), false);
+}
@@ -0,0 +1,156 @@
+use cons_cell::{list, Cell, Value};
+use k9::assert_equal;
+
+#[test]
+fn test_value_eq_ptr() {
+    let value = Value::from("string");
+    assert_equal!(value.eq_ptr(&value), true);
+    assert_equal!(value.eq_ptr(&Value::from("string")), false);
+    assert_equal!(Value::Nil.eq_ptr(&Value::Nil), true);
+    assert_equal!(Value::T.eq_ptr(&Value::T), true);
+    assert_equal!(Value::EmptyList.eq_ptr(&Value::EmptyList), true);
+    assert_equal!(Value::integer(1).eq_ptr(&Value::integer(1)), false);
+
+    let list = list([Value::symbol("a")]);
+    assert_equal!(list.eq_ptr(&list), true);
+    assert_equal!(list.eq_ptr(&list.clone()), false);
+}
+
+#[test]
+fn test_value_eql() {
+    assert_equal!(Value::integer(1).eql(&Value::integer(1)), true);
+    assert_equal!(Value::float(1.5).eql(&Value::float(1.5)), true);
+    assert_equal!(Value::byte(7u8).eql(&Value::byte(7u8)), true);
+    assert_equal!(Value::symbol("a").eql(&Value::symbol("a")), true);
+    assert_equal!(Value::symbol("a").eql(&Value::quoted_symbol("a")), false);
+    assert_equal!(Value::byte(7u8).eql(&Value::unsigned_integer(7u32)), false);
+    assert_equal!(Value::from("string").eql(&Value::from("string")), false);
+
+    let list = list([Value::symbol("a")]);
+    assert_equal!(list.eql(&list), true);
+    assert_equal!(list.eql(&list.clone()), false);
+}
+
+#[test]
+fn test_value_equal() {
+    assert_equal!(Value::from("string").equal(&Value::from("string")), true);
+    assert_equal!(Value::from("string").equal(&Value::from("other")), false);
+    assert_equal!(Value::integer(1).equal(&Value::integer(1)), true);
+
+    let lhs = list([Value::symbol("a"), list([Value::from("b"), Value::integer(3)])]);
+    let rhs = list([Value::symbol("a"), list([Value::from("b"), Value::integer(3)])]);
+    assert_equal!(lhs.equal(&rhs), true);
+    assert_equal!(lhs.eql(&rhs), false);
+    assert_equal!(lhs.equal(&list([Value::symbol("a")])), false);
+}
+
+#[test]
+fn test_cell_eq_ptr_eql_equal() {
+    let mut cell = Cell::from("a");
+    cell.add(&Cell::from("b"));
+    let copy = cell.clone();
+
+    assert_equal!(cell.eq_ptr(&cell), true);
+    assert_equal!(cell.eq_ptr(&copy), false);
+    assert_equal!(cell.eql(&copy), false);
+    assert_equal!(cell.equal(&copy), true);
+    assert_equal!(cell == copy, true);
+    assert_equal!(Cell::nil().eq_ptr(&Cell::nil()), true);
+    assert_equal!(Cell::nil().equal(&Cell::nil()), true);
+    assert_equal!(cell.equal(&Cell::from("a")), false);
+}
+
+#[test]
+fn test_cell_equality_compares_every_value() {
+    let integers = |values: &[i64]| Cell::from_iter(values.iter().copied().map(Value::integer));
+
+    assert_equal!(integers(&[1, 2]) == integers(&[1, 2]), true);
+    assert_equal!(integers(&[1, 2]) == integers(&[3, 4]), false);
+    assert_equal!(integers(&[1, 2]) == integers(&[1, 4]), false);
+    assert_equal!(integers(&[1, 2]) == integers(&[2, 1]), false);
+    assert_equal!(integers(&[1, 2]) == integers(&[1]), false);
+    assert_equal!(integers(&[1]) == integers(&[1, 2]), false);
+    assert_equal!(integers(&[1, 2]) == integers(&[1, 2, 3]), false);
+    assert_equal!(integers(&[1]) == Cell::nil(), false);
+    assert_equal!(Cell::nil() == integers(&[1]), false);
+    assert_equal!(Cell::nil() == Cell::nil(), true);
+    assert_equal!(
+        integers(&[1]) == Cell::from_slice(&[Value::float(1.0)]),
+        false
+    );
+    assert_equal!(
+        integers(&[1]) == Cell::from_slice(&[Value::symbol("1")]),
+        false
+    );
+}
+
+#[test]
+fn test_cell_equality_compares_nested_lists() {
+    let nested = |last: i64| {
+        Cell::from_slice(&[
+            Value::symbol("a"),
+            list([Value::from("b"), list([Value::integer(last)])]),
+        ])
+    };
+    assert_equal!(nested(1) == nested(1), true);
+    assert_equal!(nested(1) == nested(2), false);
+    assert_equal!(
+        Cell::from_slice(&[list([Value::symbol("a")])])
+            == Cell::from_slice(&[Value::quoted_list(Cell::from("a"))]),
+        false
+    );
+}
+
+#[test]
+fn test_cell_equality_compares_shared_tails() {
+    let tail = Cell::from_slice(&[Value::integer(2), Value::integer(3)]);
+    let mut shared = Cell::from(Value::integer(1));
+    unsafe { shared.setcdr(&tail) };
+
+    let copied = Cell::from_iter([1, 2, 3].map(Value::integer));
+    assert_equal!(shared == copied, true);
+    assert_equal!(copied == shared, true);
+    assert_equal!(shared == tail, false);
+}
+
+#[test]
+fn test_cell_equality_of_circular_lists() {
+    let mut a = Cell::from(Value::integer(1));
+    let mut b = Cell::from(Value::integer(2));
+    unsafe { a.setcdr(&b) };
+    unsafe { b.setcdr(&a) };
+
+    let mut c = Cell::from(Value::integer(1));
+    let mut d = Cell::from(Value::integer(2));
+    unsafe { c.setcdr(&d) };
+    unsafe { d.setcdr(&c) };
+
+    assert_equal!(a == a, true);
+    assert_equal!(a == c, true);
+    assert_equal!(a == d, false);
+    assert_equal!(a == Cell::from_iter([1, 2].map(Value::integer)), false);
+    assert_equal!(Cell::from_iter([1, 2, 1].map(Value::integer)) == a, false);
+
+    // dropping any cell of either cycle would leave the other one
+    // linking to freed stack memory, see `Cell::setcdr`
+    std::mem::forget((a, b));
+    std::mem::forget((c, d));
+}
+
+#[test]
+fn test_cell_equal_values_hash_equally() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let hash = |cell: &Cell| {
+        let mut hasher = DefaultHasher::new();
+        cell.hash(&mut hasher);
+        hasher.finish()
+    };
+    let mut cell = Cell::from("a");
+    cell.add(&Cell::from("b"));
+    let copy = cell.clone();
+    assert_equal!(cell == copy, true);
+    assert_equal!(hash(&cell), hash(&copy));
+    assert_equal!(hash(&cell), hash(&Cell::from_slice(&cell.values())));
+}
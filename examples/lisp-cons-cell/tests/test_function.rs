@@ -0,0 +1,61 @@
+use cons_cell::{assert_display_equal, list, Cell, Env, Function, Value};
+use k9::assert_equal;
+
+fn params<'c>() -> Cell<'c> {
+    Cell::from_iter([Value::symbol("x"), Value::symbol("y")])
+}
+
+fn body<'c>() -> Cell<'c> {
+    Cell::from_iter([Value::symbol("+"), Value::symbol("x"), Value::symbol("y")])
+}
+
+#[test]
+fn test_function_display() {
+    let function = Value::from(Function::new(params(), body()));
+    assert_display_equal!(&function, "#<function>");
+    assert_equal!(
+        format!("{:?}", function),
+        "Function(#<function (x y) (+ x y)>)"
+    );
+    assert_equal!(function.is_function(), true);
+    assert_equal!(Value::symbol("f").is_function(), false);
+}
+
+#[test]
+fn test_function_accessors() {
+    let function = Function::new(params(), body());
+    assert_equal!(function.arity(), 2);
+    assert_equal!(function.params(), &params());
+    assert_equal!(function.body(), &body());
+    assert_equal!(function.env().is_none(), true);
+    assert_equal!(function.is_closure(), false);
+}
+
+#[test]
+fn test_function_closure_shares_env() {
+    let mut env = Env::new();
+    let function = Function::closure(params(), body(), &env);
+    assert_equal!(function.is_closure(), true);
+    assert_equal!(function.env().unwrap().is_bound("z"), false);
+
+    env.define("z", Value::integer(1i64));
+    assert_equal!(
+        function.env().unwrap().lookup("z"),
+        Some(&Value::integer(1i64))
+    );
+}
+
+#[test]
+fn test_function_in_its_own_env() {
+    let mut env = Env::new();
+    let function = Value::from(Function::closure(params(), body(), &env));
+    env.define("f", function.clone());
+
+    assert_display_equal!(env.lookup("f").unwrap(), "#<function>");
+    assert_display_equal!(list([function.clone()]), "(#<function>)");
+    assert_equal!(&function, &function.clone());
+
+    let other_env = Env::new();
+    let other = Value::from(Function::closure(params(), body(), &other_env));
+    assert_equal!(function == other, false);
+}
@@ -0,0 +1,81 @@
+use std::time::Instant;
+
+use cons_cell::{assert_display_equal, Cell, ListBuilder, Value};
+use k9::assert_equal;
+
+#[test]
+fn test_list_builder_push_value() {
+    let mut builder = ListBuilder::new();
+    assert_equal!(builder.is_empty(), true);
+    builder.push_value(Value::symbol("a"));
+    builder.push_value(Value::symbol("b"));
+    builder.push_value(Value::quoted_symbol("c"));
+    assert_equal!(builder.len(), 3);
+
+    let cell = builder.build();
+    assert_equal!(
+        cell.values(),
+        vec![
+            Value::symbol("a"),
+            Value::symbol("b"),
+            Value::quoted_symbol("c")
+        ]
+    );
+    assert_equal!(cell.len(), 3);
+    assert_display_equal!(Value::list(cell), "(a b 'c)");
+}
+
+#[test]
+fn test_list_builder_append() {
+    let mut list = Cell::from("x");
+    list.add(&Cell::from("y"));
+
+    let mut builder = ListBuilder::new();
+    builder.push_value(Value::symbol("a"));
+    builder.append(&list);
+    builder.extend([Value::integer(1)]);
+
+    assert_equal!(
+        builder.build().values(),
+        vec![
+            Value::symbol("a"),
+            Value::symbol("x"),
+            Value::symbol("y"),
+            Value::integer(1)
+        ]
+    );
+    assert_equal!(list.values(), vec![Value::symbol("x"), Value::symbol("y")]);
+}
+
+#[test]
+fn test_list_builder_empty() {
+    assert_equal!(ListBuilder::new().build(), Cell::nil());
+}
+
+#[test]
+fn test_list_builder_is_faster_than_cell_add() {
+    let count = 250u32;
+
+    let start = Instant::now();
+    let mut cell = Cell::nil();
+    for n in 0..count {
+        cell.push_value(Value::unsigned_integer(n));
+    }
+    let cell_add = start.elapsed();
+
+    let start = Instant::now();
+    let mut builder = ListBuilder::new();
+    for n in 0..count {
+        builder.push_value(Value::unsigned_integer(n));
+    }
+    let built = builder.build();
+    let list_builder = start.elapsed();
+
+    eprintln!(
+        "{} values: Cell::push_value {:?} ListBuilder::push_value {:?}",
+        count, cell_add, list_builder
+    );
+    assert_equal!(built.len(), count as usize);
+    assert_equal!(built, cell);
+    assert!(list_builder < cell_add);
+}
@@ -0,0 +1,137 @@
+use cons_cell::{
+    AsFloat, AsInteger, AsUnsignedInteger, ByteFormat, Cell, FormatOptions, Number, Value,
+};
+use k9::assert_equal;
+
+#[test]
+fn test_number_from_value() {
+    assert_equal!(Value::byte(7u8).to_number(), Some(Number::Byte(7)));
+    assert_equal!(Value::float(1.5).to_number(), Some(Number::Float(1.5)));
+    assert_equal!(Value::symbol("x").to_number(), None);
+    assert_equal!(Value::string("1").is_number(), false);
+}
+
+#[test]
+fn test_number_promotes_mixed_arithmetic() {
+    assert_equal!(
+        format!("{:?}", Number::Byte(2) + Number::UnsignedInteger(3)),
+        "UnsignedInteger(5)"
+    );
+    assert_equal!(
+        format!("{:?}", Number::UnsignedInteger(2) * Number::Integer(-3)),
+        "Integer(-6)"
+    );
+    assert_equal!(
+        format!("{:?}", Number::Integer(1) + Number::Float(0.5)),
+        "Float(1.5)"
+    );
+    assert_equal!(
+        format!("{:?}", Number::Integer(7) / Number::Byte(2)),
+        "Integer(3)"
+    );
+}
+
+#[test]
+fn test_number_promotes_on_overflow() {
+    assert_equal!(
+        format!("{:?}", Number::Byte(200) + Number::Byte(100)),
+        "UnsignedInteger(300)"
+    );
+    assert_equal!(
+        format!("{:?}", Number::Byte(3) - Number::Byte(5)),
+        "Integer(-2)"
+    );
+    assert_equal!(
+        format!("{:?}", Number::Integer(i64::MAX) + Number::Integer(1)),
+        format!("Float({:?})", i64::MAX as f64 + 1.0)
+    );
+}
+
+#[test]
+#[should_panic(expected = "cannot divide 1 by zero")]
+fn test_number_integer_division_by_zero() {
+    let _ = Number::Integer(1) / Number::Byte(0);
+}
+
+#[test]
+fn test_number_comparisons() {
+    assert_equal!(Number::Byte(3) == Number::Float(3.0), true);
+    assert_equal!(Number::Integer(-1) < Number::Byte(0), true);
+    assert_equal!(Number::UnsignedInteger(10) > Number::Float(9.5), true);
+}
+
+#[test]
+fn test_value_as_numbers_coerce() {
+    assert_equal!(*Value::byte(7u8).as_integer(), 7i64);
+    assert_equal!(*Value::integer(7i64).as_float(), 7.0);
+    assert_equal!(*Value::float(8.0).as_unsigned_integer(), 8u32);
+}
+
+#[test]
+#[should_panic(expected = "cannot convert")]
+fn test_value_as_integer_rejects_fractions() {
+    Value::float(8.5).as_integer();
+}
+
+#[test]
+fn test_value_from_integers() {
+    assert_equal!(Value::from(0xF1u64), Value::unsigned_integer(0xF1u32));
+    assert_equal!(Value::from(u64::MAX >> 1), Value::integer(i64::MAX));
+    assert_equal!(Value::from(-8i32), Value::integer(-8i64));
+    assert_equal!(Value::from(8i32), Value::integer(8i64));
+    assert_equal!(
+        Cell::from(200u32).head(),
+        Some(Value::unsigned_integer(200u32))
+    );
+}
+
+#[test]
+fn test_number_format_options() {
+    let options = FormatOptions::default();
+    assert_equal!(Number::Byte(31).format(&options), "0x1f");
+    assert_equal!(Number::Integer(-31).format(&options), "-31");
+    assert_equal!(Number::Float(1.5).format(&options), "1.5");
+    assert_equal!(Number::Byte(31).to_string(), "0x1f");
+
+    let options = FormatOptions {
+        radix: 16,
+        precision: Some(2),
+        bytes: ByteFormat::Decimal,
+    };
+    assert_equal!(Number::Byte(31).format(&options), "31");
+    assert_equal!(Number::UnsignedInteger(255).format(&options), "#xff");
+    assert_equal!(Number::Integer(-31).format(&options), "#x-1f");
+    assert_equal!(Number::Integer(0).format(&options), "#x0");
+    assert_equal!(Number::Float(1.0 / 3.0).format(&options), "0.33");
+
+    let radix = |radix: u32, value: i64| {
+        Number::Integer(value).format(&FormatOptions {
+            radix,
+            ..FormatOptions::default()
+        })
+    };
+    assert_equal!(radix(2, 5), "#b101");
+    assert_equal!(radix(8, 31), "#o37");
+    assert_equal!(radix(36, 35), "#36rz");
+    assert_equal!(radix(16, i64::MIN), "#x-8000000000000000");
+    assert_equal!(radix(1, 31), "31");
+    assert_equal!(radix(37, 31), "31");
+}
+
+#[test]
+fn test_value_format_options() {
+    let list = Value::list(Cell::from_slice(&[
+        Value::byte(10u8),
+        Value::integer(255),
+        Value::quoted_list(Cell::from_slice(&[Value::float(2.0), Value::symbol("x")])),
+    ]));
+    assert_equal!(list.to_string(), "(0x0a 255 '(2 x))");
+    assert_equal!(list.format(&FormatOptions::default()), list.to_string());
+
+    let options = FormatOptions {
+        radix: 2,
+        precision: Some(1),
+        bytes: ByteFormat::Decimal,
+    };
+    assert_equal!(list.format(&options), "(10 #b11111111 '(2.0 x))");
+}
@@ -0,0 +1,71 @@
+use cons_cell::{
+    assert_display_equal, plist_get, plist_pairs, plist_put, plist_remove, Cell, Value,
+};
+use k9::assert_equal;
+
+fn plist<'c>() -> Cell<'c> {
+    Cell::from_iter([
+        Value::symbol("name"),
+        Value::from("cell"),
+        Value::symbol("size"),
+        Value::integer(2),
+    ])
+}
+
+#[test]
+fn test_plist_get() {
+    let plist = plist();
+    assert_equal!(
+        plist_get(&plist, &Value::symbol("name")),
+        Some(Value::from("cell"))
+    );
+    assert_equal!(
+        plist_get(&plist, &Value::symbol("size")),
+        Some(Value::integer(2))
+    );
+    assert_equal!(plist_get(&plist, &Value::symbol("cell")), None);
+    assert_equal!(plist_get(&Cell::nil(), &Value::symbol("name")), None);
+}
+
+#[test]
+fn test_plist_put() {
+    let mut plist = plist();
+    plist_put(&mut plist, Value::symbol("size"), Value::integer(3));
+    assert_display_equal!(plist, r#"name "cell" size 3"#);
+    assert_equal!(plist.len(), 4);
+
+    plist_put(&mut plist, Value::symbol("color"), Value::symbol("red"));
+    assert_display_equal!(plist, r#"name "cell" size 3 color red"#);
+    assert_equal!(plist.len(), 6);
+
+    let mut empty = Cell::nil();
+    plist_put(&mut empty, Value::symbol("key"), Value::T);
+    assert_equal!(plist_get(&empty, &Value::symbol("key")), Some(Value::T));
+    assert_equal!(empty.len(), 2);
+}
+
+#[test]
+fn test_plist_remove() {
+    let mut plist = plist();
+    assert_equal!(plist_remove(&mut plist, &Value::symbol("name")), true);
+    assert_display_equal!(plist, "size 2");
+    assert_equal!(plist.len(), 2);
+    assert_equal!(plist_remove(&mut plist, &Value::symbol("name")), false);
+    assert_equal!(plist_remove(&mut plist, &Value::symbol("size")), true);
+    assert_equal!(plist.is_empty(), true);
+}
+
+#[test]
+fn test_plist_pairs() {
+    let plist = plist();
+    let pairs = plist_pairs(&plist)
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<Vec<(Value, Value)>>();
+    assert_equal!(
+        pairs,
+        vec![
+            (Value::symbol("name"), Value::from("cell")),
+            (Value::symbol("size"), Value::integer(2)),
+        ]
+    );
+}
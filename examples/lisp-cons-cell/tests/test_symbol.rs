@@ -0,0 +1,78 @@
+use cons_cell::{assert_display_equal, Cell, Env, Symbol, Value};
+use k9::assert_equal;
+
+#[test]
+fn test_symbol_plain() {
+    let symbol = Symbol::new("name");
+    assert_equal!(symbol.symbol(), "name");
+    assert_equal!(symbol.name(), "name");
+    assert_equal!(symbol.namespace(), None);
+    assert_equal!(symbol.is_keyword(), false);
+    assert_equal!(Symbol::new(":").is_keyword(), false);
+    assert_equal!(Symbol::new(":").name(), ":");
+    assert_equal!(Symbol::new("name:").namespace(), None);
+}
+
+#[test]
+fn test_symbol_keyword() {
+    let keyword = Symbol::keyword("test");
+    assert_equal!(keyword, Symbol::new(":test"));
+    assert_equal!(keyword.symbol(), ":test");
+    assert_equal!(keyword.name(), "test");
+    assert_equal!(keyword.namespace(), None);
+    assert_equal!(keyword.is_keyword(), true);
+    assert_display_equal!(keyword, ":test");
+    assert_equal!(keyword == Symbol::new("test"), false);
+
+    let value = Value::keyword("test");
+    assert_equal!(value, Value::symbol(":test"));
+    assert_equal!(value.is_keyword(), true);
+    assert_equal!(Value::symbol("test").is_keyword(), false);
+    assert_display_equal!(
+        Value::list(Cell::from_slice(&[
+            Value::symbol("f"),
+            value,
+            Value::integer(1)
+        ])),
+        "(f :test 1)"
+    );
+}
+
+#[test]
+fn test_symbol_namespace() {
+    let symbol = Symbol::namespaced("pkg", "name");
+    assert_equal!(symbol, Symbol::new("pkg:name"));
+    assert_equal!(symbol.namespace(), Some("pkg"));
+    assert_equal!(symbol.name(), "name");
+    assert_equal!(symbol.is_keyword(), false);
+    assert_display_equal!(symbol, "pkg:name");
+    assert_equal!(symbol == Symbol::new("name"), false);
+
+    let internal = Symbol::new("pkg::name");
+    assert_equal!(internal.namespace(), Some("pkg"));
+    assert_equal!(internal.name(), "name");
+    assert_display_equal!(Value::quoted_symbol(internal), "'pkg::name");
+}
+
+#[test]
+fn test_env_keywords_evaluate_to_themselves() {
+    let mut env = Env::new();
+    env.define("test", Value::integer(1));
+    env.define("pkg:test", Value::integer(2));
+    env.define(":test", Value::integer(3));
+
+    assert_equal!(env.symbol_value("test"), Some(Value::integer(1)));
+    assert_equal!(
+        env.symbol_value(Symbol::namespaced("pkg", "test")),
+        Some(Value::integer(2))
+    );
+    assert_equal!(
+        env.symbol_value(Symbol::keyword("test")),
+        Some(Value::keyword("test"))
+    );
+    assert_equal!(env.symbol_value("undefined"), None);
+    assert_equal!(
+        env.symbol_value(":undefined"),
+        Some(Value::keyword("undefined"))
+    );
+}
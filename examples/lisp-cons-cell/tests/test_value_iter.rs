@@ -148,3 +148,73 @@ fn test_value_list_extend_cell() {
         })
     );
 }
+
+#[test]
+fn test_value_collect_preserves_quoting() {
+    let values = || [Value::symbol("a"), Value::integer(1)];
+
+    let value = values().into_iter().collect::<Value>();
+    assert_equal!(value, Value::list(Cell::from_slice(&values())));
+    assert_equal!(value.to_string(), "(a 1)");
+    assert_equal!(
+        Vec::<Value>::new().into_iter().collect::<Value>(),
+        Value::EmptyList
+    );
+
+    let value = Value::collect_quoted(values());
+    assert_equal!(value, Value::quoted_list(Cell::from_slice(&values())));
+    assert_equal!(value.to_string(), "'(a 1)");
+    assert_equal!(Value::collect_quoted([]), Value::EmptyQuotedList);
+}
+
+#[test]
+fn test_value_extend_preserves_quoting() {
+    let values = || [Value::symbol("b"), Value::integer(2)];
+    let extended = |mut value: Value<'static>, values: &[Value<'static>]| {
+        value.extend(values.iter().cloned());
+        value
+    };
+
+    let list = Value::list(Cell::from("a"));
+    assert_equal!(extended(list.clone(), &values()).to_string(), "(a b 2)");
+    assert_equal!(extended(list.clone(), &[]), list);
+
+    let quoted = Value::quoted_list(Cell::from("a"));
+    assert_equal!(extended(quoted.clone(), &values()).to_string(), "'(a b 2)");
+    assert_equal!(
+        matches!(extended(quoted.clone(), &values()), Value::QuotedList(_)),
+        true
+    );
+    assert_equal!(extended(quoted.clone(), &[]), quoted);
+
+    assert_equal!(
+        extended(Value::EmptyList, &values()),
+        Value::list(Cell::from_slice(&values()))
+    );
+    assert_equal!(extended(Value::EmptyList, &[]), Value::EmptyList);
+
+    assert_equal!(
+        extended(Value::EmptyQuotedList, &values()),
+        Value::quoted_list(Cell::from_slice(&values()))
+    );
+    assert_equal!(
+        extended(Value::EmptyQuotedList, &values()).to_string(),
+        "'(b 2)"
+    );
+    assert_equal!(
+        extended(Value::EmptyQuotedList, &[]),
+        Value::EmptyQuotedList
+    );
+
+    assert_equal!(
+        extended(Value::Nil, &values()),
+        Value::list(Cell::from_slice(&values()))
+    );
+    assert_equal!(extended(Value::Nil, &[]), Value::Nil);
+
+    assert_equal!(
+        extended(Value::integer(1), &values()),
+        Value::list(Cell::from_slice(&values()))
+    );
+    assert_equal!(extended(Value::integer(1), &[]), Value::EmptyList);
+}
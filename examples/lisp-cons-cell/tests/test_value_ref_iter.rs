@@ -0,0 +1,68 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+
+use cons_cell::{Cell, Value};
+use k9::assert_equal;
+
+thread_local! {
+    static ALLOCATIONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// counts the allocations made by the current thread, such that tests
+/// running in parallel do not interfere
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+fn allocations<T>(body: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(|count| count.get());
+    let result = body();
+    (result, ALLOCATIONS.with(|count| count.get()) - before)
+}
+
+#[test]
+fn test_value_iter_borrows_values() {
+    let list = Value::list(Cell::from_slice(&[
+        Value::symbol("a"),
+        Value::integer(1),
+        Value::from("s"),
+    ]));
+    assert_equal!(
+        list.iter().collect::<Vec<_>>(),
+        vec![&Value::symbol("a"), &Value::integer(1), &Value::from("s")]
+    );
+    assert_equal!(list.iter().cloned().collect::<Vec<_>>(), list.values());
+    assert_equal!(
+        Value::integer(1).iter().collect::<Vec<_>>(),
+        vec![&Value::integer(1)]
+    );
+    assert_equal!(Value::EmptyList.iter().count(), 0);
+    assert_equal!(Cell::nil().iter().count(), 0);
+
+    let quoted = Value::quoted_list(Cell::from_slice(&[Value::symbol("a")]));
+    assert_equal!(quoted.iter().collect::<Vec<_>>(), vec![&Value::symbol("a")]);
+}
+
+#[test]
+fn test_value_iter_does_not_allocate() {
+    let list = Value::list(Cell::from_iter((0..1000).map(Value::integer)));
+
+    let (count, borrowed) = allocations(|| list.iter().count());
+    assert_equal!(count, 1000);
+    assert_equal!(borrowed, 0);
+
+    let (count, cloned) = allocations(|| (&list).into_iter().count());
+    assert_equal!(count, 1000);
+    assert_equal!(cloned >= 1000, true);
+}
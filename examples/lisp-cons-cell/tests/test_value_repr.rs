@@ -1,4 +1,5 @@
-use cons_cell::{assert_display_equal, Cell, Value};
+use cons_cell::{assert_display_equal, read_string_literal, unescape_string, Cell, Value};
+use k9::assert_equal;
 
 #[test]
 fn test_nil() {
@@ -137,3 +138,37 @@ fn test_value_symbol_quoted_twice() {
     let value = value.quote();
     assert_display_equal!(&value, "'a");
 }
+#[test]
+fn test_string_escapes() {
+    assert_display_equal!(Value::string("say \"hi\"\\\n"), r#""say \"hi\"\\\n""#);
+    assert_display_equal!(Value::string("tab\there"), r#""tab\there""#);
+}
+#[test]
+fn test_string_unescape() {
+    assert_equal!(unescape_string(r#"say \"hi\"\\\n"#), "say \"hi\"\\\n");
+    assert_equal!(unescape_string(r#"\q\"#), "q\\");
+}
+#[test]
+fn test_read_string_literal() {
+    assert_equal!(
+        read_string_literal(r#""a \"b\" c" rest"#),
+        Some(("a \"b\" c".to_string(), 11))
+    );
+    assert_equal!(read_string_literal(r#""unterminated \""#), None);
+    assert_equal!(read_string_literal("symbol"), None);
+}
+#[test]
+fn test_string_round_trip() {
+    for text in [
+        "",
+        "plain",
+        "\"quoted\"",
+        "back\\slash",
+        "multi\nline\r\n\ttab",
+    ] {
+        let printed = Value::string(text).to_string();
+        let (read, consumed) = read_string_literal(&printed).unwrap();
+        assert_equal!(read, text);
+        assert_equal!(consumed, printed.len());
+    }
+}
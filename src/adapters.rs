@@ -0,0 +1,144 @@
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use crate::{PointerFamily, PointerLike, UniquePointer};
+
+/// [BoxPointer](Self) adapts [`Box`] to [`PointerLike`](crate::PointerLike).
+///
+/// Boxes cannot be shared, therefore
+/// [`clone_shared`](crate::PointerLike::clone_shared) copies the pointee.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoxPointer<T> {
+    inner: Option<Box<T>>,
+}
+
+impl<T> BoxPointer<T> {
+    /// creates a [BoxPointer](Self) holding `data`
+    pub fn new(data: T) -> BoxPointer<T> {
+        BoxPointer {
+            inner: Some(Box::new(data)),
+        }
+    }
+
+    /// returns the underlying [`Box`], if any
+    pub fn into_box(self) -> Option<Box<T>> {
+        self.inner
+    }
+}
+
+impl<T: Clone> PointerLike<T> for BoxPointer<T> {
+    type Ref<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type RefMut<'a>
+        = &'a mut T
+    where
+        Self: 'a;
+
+    fn null() -> BoxPointer<T> {
+        BoxPointer { inner: None }
+    }
+
+    fn write(&mut self, data: T) {
+        self.inner = Some(Box::new(data));
+    }
+
+    fn as_ref(&self) -> Option<&T> {
+        self.inner.as_deref()
+    }
+
+    fn as_mut(&mut self) -> Option<&mut T> {
+        self.inner.as_deref_mut()
+    }
+
+    fn clone_shared(&self) -> BoxPointer<T> {
+        self.clone()
+    }
+}
+
+/// [RcPointer](Self) adapts [`Rc`] to [`PointerLike`](crate::PointerLike).
+///
+/// [`as_mut`](crate::PointerLike::as_mut) only lends the pointee
+/// while no other [RcPointer](Self) shares it, as per [`Rc::get_mut`].
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RcPointer<T> {
+    inner: Option<Rc<T>>,
+}
+
+impl<T> RcPointer<T> {
+    /// creates a [RcPointer](Self) holding `data`
+    pub fn new(data: T) -> RcPointer<T> {
+        RcPointer {
+            inner: Some(Rc::new(data)),
+        }
+    }
+
+    /// returns the number of [RcPointer](Self) sharing the pointee
+    pub fn refs(&self) -> usize {
+        self.inner.as_ref().map(Rc::strong_count).unwrap_or(0)
+    }
+}
+
+impl<T> Clone for RcPointer<T> {
+    fn clone(&self) -> RcPointer<T> {
+        RcPointer {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> PointerLike<T> for RcPointer<T> {
+    type Ref<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type RefMut<'a>
+        = &'a mut T
+    where
+        Self: 'a;
+
+    fn null() -> RcPointer<T> {
+        RcPointer { inner: None }
+    }
+
+    fn write(&mut self, data: T) {
+        self.inner = Some(Rc::new(data));
+    }
+
+    fn as_ref(&self) -> Option<&T> {
+        self.inner.as_deref()
+    }
+
+    fn as_mut(&mut self) -> Option<&mut T> {
+        self.inner.as_mut().and_then(Rc::get_mut)
+    }
+
+    fn clone_shared(&self) -> RcPointer<T> {
+        self.clone()
+    }
+}
+
+/// [UniquePointerFamily](Self) is the [`PointerFamily`](crate::PointerFamily)
+/// of [`UniquePointer`](crate::UniquePointer).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UniquePointerFamily;
+impl PointerFamily for UniquePointerFamily {
+    type Pointer<T: Debug + Clone> = UniquePointer<T>;
+}
+
+/// [BoxPointerFamily](Self) is the [`PointerFamily`](crate::PointerFamily)
+/// of [`BoxPointer`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BoxPointerFamily;
+impl PointerFamily for BoxPointerFamily {
+    type Pointer<T: Debug + Clone> = BoxPointer<T>;
+}
+
+/// [RcPointerFamily](Self) is the [`PointerFamily`](crate::PointerFamily)
+/// of [`RcPointer`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RcPointerFamily;
+impl PointerFamily for RcPointerFamily {
+    type Pointer<T: Debug + Clone> = RcPointer<T>;
+}
@@ -0,0 +1,165 @@
+//! The `alias_check` module implements the opt-in aliasing-violation
+//! detector enabled by the `alias-check` feature in debug builds.
+//!
+//! References handed out by [`UniquePointer::inner_ref`](crate::UniquePointer::inner_ref),
+//! [`UniquePointer::inner_mut`](crate::UniquePointer::inner_mut) and
+//! the methods built on top of them, such as
+//! [`UniquePointer::extend_lifetime_mut`](crate::UniquePointer::extend_lifetime_mut),
+//! carry no guard, therefore a borrow is considered outstanding until
+//! the same `UniquePointer` borrows again or is dropped.
+//!
+//! Whenever a `UniquePointer` borrows an address which another
+//! `UniquePointer` holds an outstanding borrow of, and at least one
+//! of them is mutable, an [`AliasViolation`] naming both creation
+//! sites is printed to stderr and recorded for [`violations`].
+//!
+#![cfg_attr(not(feature = "no-panic"), doc = "```")]
+#![cfg_attr(feature = "no-panic", doc = "```ignore")]
+//! use unique_pointer::{alias_check, UniquePointer};
+//!
+//! let mut data = UniquePointer::from(1u8);
+//! let mut copy = data.clone();
+//!
+//! let a = data.inner_mut();
+//! let b = copy.inner_mut();
+//! *b += *a;
+//!
+//! assert_eq!(alias_check::violations().len(), if cfg!(debug_assertions) { 1 } else { 0 });
+//! alias_check::clear();
+//! ```
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::panic::Location;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_HANDLE: AtomicUsize = AtomicUsize::new(1);
+
+thread_local! {
+    static BORROWS: RefCell<BTreeMap<usize, Vec<Borrow>>> = const { RefCell::new(BTreeMap::new()) };
+    /// the addresses borrowed by each handle, such that releasing a
+    /// handle only visits the addresses it borrowed
+    static BORROWED: RefCell<BTreeMap<usize, BTreeSet<usize>>> = const { RefCell::new(BTreeMap::new()) };
+    static VIOLATIONS: RefCell<Vec<AliasViolation>> = const { RefCell::new(Vec::new()) };
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BorrowKind {
+    Shared,
+    Mutable,
+}
+
+impl Display for BorrowKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            BorrowKind::Shared => write!(f, "shared"),
+            BorrowKind::Mutable => write!(f, "mutable"),
+        }
+    }
+}
+
+/// [Borrow](Self) is an outstanding borrow of an address by a
+/// `UniquePointer`.
+#[derive(Clone, Copy, Debug)]
+pub struct Borrow {
+    pub handle: usize,
+    pub kind: BorrowKind,
+    pub location: &'static Location<'static>,
+}
+
+/// [AliasViolation](Self) describes two overlapping borrows of the
+/// same address where at least one of them is mutable.
+#[derive(Clone, Copy, Debug)]
+pub struct AliasViolation {
+    pub addr: usize,
+    pub first: Borrow,
+    pub second: Borrow,
+}
+
+impl Display for AliasViolation {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "aliasing violation at {:016x}: {} borrow at {} overlaps {} borrow at {}",
+            self.addr, self.second.kind, self.second.location, self.first.kind, self.first.location,
+        )
+    }
+}
+
+/// returns the violations recorded in the current thread
+pub fn violations() -> Vec<AliasViolation> {
+    VIOLATIONS.with(|violations| violations.borrow().clone())
+}
+
+/// forgets all outstanding borrows and recorded violations of the
+/// current thread
+pub fn clear() {
+    BORROWS.with(|borrows| borrows.borrow_mut().clear());
+    BORROWED.with(|borrowed| borrowed.borrow_mut().clear());
+    VIOLATIONS.with(|violations| violations.borrow_mut().clear());
+}
+
+pub(crate) fn next_handle() -> usize {
+    NEXT_HANDLE.fetch_add(1, Ordering::Relaxed)
+}
+
+pub(crate) fn record(
+    addr: usize,
+    handle: usize,
+    kind: BorrowKind,
+    location: &'static Location<'static>,
+) {
+    if !cfg!(debug_assertions) || addr == 0 {
+        return;
+    }
+    let borrow = Borrow {
+        handle,
+        kind,
+        location,
+    };
+    BORROWS.with(|borrows| {
+        let mut borrows = borrows.borrow_mut();
+        let outstanding = borrows.entry(addr).or_default();
+        outstanding.retain(|other| other.handle != handle);
+        for other in outstanding.iter() {
+            if kind == BorrowKind::Mutable || other.kind == BorrowKind::Mutable {
+                let violation = AliasViolation {
+                    addr,
+                    first: *other,
+                    second: borrow,
+                };
+                eprintln!("{}", violation);
+                VIOLATIONS.with(|violations| violations.borrow_mut().push(violation));
+            }
+        }
+        outstanding.push(borrow);
+    });
+    BORROWED.with(|borrowed| {
+        borrowed
+            .borrow_mut()
+            .entry(handle)
+            .or_default()
+            .insert(addr)
+    });
+}
+
+pub(crate) fn release(handle: usize) {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+    let addrs = BORROWED
+        .try_with(|borrowed| borrowed.borrow_mut().remove(&handle))
+        .ok()
+        .flatten();
+    let _ = BORROWS.try_with(|borrows| {
+        let mut borrows = borrows.borrow_mut();
+        for addr in addrs.into_iter().flatten() {
+            if let Some(outstanding) = borrows.get_mut(&addr) {
+                outstanding.retain(|borrow| borrow.handle != handle);
+                if outstanding.is_empty() {
+                    borrows.remove(&addr);
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,106 @@
+//! The `audit` module implements [`audit`], a health check of the
+//! pointers of a user structure.
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+use crate::{GraphNode, UniquePointer};
+
+/// [Finding](Self) is a suspicious pointer reported by [`audit`],
+/// along with the name of the edge it was reached through, `"root"`
+/// for the roots themselves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Finding {
+    /// a written pointer whose reference count is down to zero,
+    /// typically a clone of a pointer whose memory was released
+    /// elsewhere
+    Released { addr: usize, edge: &'static str },
+    /// a pointer whose address does not match its raw pointer
+    ProvenanceMismatch {
+        addr: usize,
+        ptr: usize,
+        edge: &'static str,
+    },
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Finding::Released { addr, edge } => {
+                write!(f, "{}: {:016x} written but without references", edge, addr)
+            }
+            Finding::ProvenanceMismatch { addr, ptr, edge } => write!(
+                f,
+                "{}: {:016x} does not match its raw pointer {:016x}",
+                edge, addr, ptr
+            ),
+        }
+    }
+}
+
+/// walks every node reachable from `roots` through
+/// [`GraphNode::edges`] and returns the suspicious pointers found
+/// along the way, which is empty for a healthy structure.
+///
+/// Nodes are only visited through pointers without findings, such
+/// that the walk never dereferences memory deemed suspicious.
+///
+#[cfg_attr(not(feature = "no-panic"), doc = "```")]
+#[cfg_attr(feature = "no-panic", doc = "```ignore")]
+/// use std::fmt::Formatter;
+/// use unique_pointer::audit::{audit, Finding};
+/// use unique_pointer::{GraphNode, UniquePointer};
+///
+/// #[derive(Debug)]
+/// struct Node {
+///     next: UniquePointer<Node>,
+/// }
+///
+/// impl GraphNode for Node {
+///     fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+///         vec![("next", &self.next)]
+///     }
+///
+///     fn label(&self, f: &mut Formatter) -> std::fmt::Result {
+///         write!(f, "node")
+///     }
+/// }
+///
+/// let mut tail = UniquePointer::from(Node { next: UniquePointer::null() });
+/// let head = UniquePointer::from(Node { next: tail.clone() });
+/// assert_eq!(audit(&[head.clone()]), vec![]);
+///
+/// // releasing `tail` once too often leaves `head.next` without references
+/// tail.drop_in_place();
+/// tail.drop_in_place();
+/// assert_eq!(
+///     audit(&[head.clone()]),
+///     vec![Finding::Released { addr: head.next.addr(), edge: "next" }]
+/// );
+/// ```
+pub fn audit<T: GraphNode>(roots: &[UniquePointer<T>]) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut visited = BTreeSet::new();
+    let mut pending = roots.iter().map(|root| ("root", root)).collect::<Vec<_>>();
+    while let Some((edge, pointer)) = pending.pop() {
+        if pointer.is_null() {
+            continue;
+        }
+        let (addr, ptr) = pointer.addr_and_ptr();
+        let ptr = ptr.addr();
+        if addr != ptr {
+            findings.push(Finding::ProvenanceMismatch { addr, ptr, edge });
+            continue;
+        }
+        if pointer.is_written() && pointer.refs() == 0 {
+            findings.push(Finding::Released { addr, edge });
+            continue;
+        }
+        if !visited.insert(addr) {
+            continue;
+        }
+        if let Some(node) = pointer.as_ref() {
+            pending.extend(node.edges().into_iter().rev());
+        }
+    }
+    findings
+}
@@ -0,0 +1,132 @@
+//! The `clone_stats` module implements the opt-in copy tracker
+//! enabled by the `clone-stats` feature.
+//!
+//! Every [`Clone::clone`] and [`UniquePointer::propagate`](crate::UniquePointer::propagate)
+//! of a non-null `UniquePointer` is recorded against the address it
+//! points to along with its call site, such that surprising reference
+//! counts can be traced back to the code that created the copies.
+//! Records are reset whenever [`UniquePointer::alloc`](crate::UniquePointer::alloc)
+//! hands out an address anew.
+//!
+//! ```
+//! use unique_pointer::UniquePointer;
+//!
+//! let data = UniquePointer::from(1u8);
+//! let copies = (0..2).map(|_| data.clone()).collect::<Vec<_>>();
+//! let propagated = unsafe { data.propagate() };
+//!
+//! let stats = data.clone_stats();
+//! assert_eq!(stats.clones, 2);
+//! assert_eq!(stats.propagations, 1);
+//! assert_eq!(stats.sites().len(), 2);
+//! assert_eq!(stats.sites()[0].1, 2);
+//! ```
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::panic::Location;
+
+thread_local! {
+    static STATS: RefCell<BTreeMap<usize, CloneStats>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CopyKind {
+    Clone,
+    Propagate,
+}
+
+impl Display for CopyKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            CopyKind::Clone => write!(f, "clone"),
+            CopyKind::Propagate => write!(f, "propagate"),
+        }
+    }
+}
+
+/// [CopyOrigin](Self) is the call site of a copy of a
+/// `UniquePointer`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CopyOrigin {
+    pub kind: CopyKind,
+    pub location: &'static Location<'static>,
+}
+
+/// [CloneStats](Self) holds the copies made of the `UniquePointer`s
+/// pointing to an address.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CloneStats {
+    pub clones: usize,
+    pub propagations: usize,
+    pub origins: Vec<CopyOrigin>,
+}
+
+impl CloneStats {
+    /// returns the total number of copies
+    pub fn copies(&self) -> usize {
+        self.clones + self.propagations
+    }
+
+    /// returns the distinct call sites paired with the number of
+    /// copies made from each, most prolific first
+    pub fn sites(&self) -> Vec<(CopyOrigin, usize)> {
+        let mut sites = BTreeMap::<(&'static str, u32, u32, CopyKind), (CopyOrigin, usize)>::new();
+        for origin in &self.origins {
+            let key = (
+                origin.location.file(),
+                origin.location.line(),
+                origin.location.column(),
+                origin.kind,
+            );
+            sites.entry(key).or_insert((*origin, 0)).1 += 1;
+        }
+        let mut sites = sites.into_values().collect::<Vec<_>>();
+        sites.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        sites
+    }
+}
+
+impl Display for CloneStats {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} clones, {} propagations",
+            self.clones, self.propagations
+        )?;
+        for (origin, count) in self.sites() {
+            write!(f, "\n  {}x {} at {}", count, origin.kind, origin.location)?;
+        }
+        Ok(())
+    }
+}
+
+/// returns the copies recorded in the current thread of the
+/// `UniquePointer`s pointing to `addr`
+pub fn stats(addr: usize) -> CloneStats {
+    STATS.with(|stats| stats.borrow().get(&addr).cloned().unwrap_or_default())
+}
+
+/// forgets all copies recorded in the current thread
+pub fn clear() {
+    STATS.with(|stats| stats.borrow_mut().clear());
+}
+
+pub(crate) fn record(addr: usize, kind: CopyKind, location: &'static Location<'static>) {
+    if addr == 0 {
+        return;
+    }
+    STATS.with(|stats| {
+        let mut stats = stats.borrow_mut();
+        let entry = stats.entry(addr).or_default();
+        match kind {
+            CopyKind::Clone => entry.clones += 1,
+            CopyKind::Propagate => entry.propagations += 1,
+        }
+        entry.origins.push(CopyOrigin { kind, location });
+    });
+}
+
+pub(crate) fn reset(addr: usize) {
+    let _ = STATS.try_with(|stats| stats.borrow_mut().remove(&addr));
+}
@@ -0,0 +1,39 @@
+//! Classic data structures built exclusively on top of the crate's
+//! own primitives.
+//!
+//! [`UniquePointer`](crate::UniquePointer) does not drop its pointee,
+//! therefore every structure implements [`Drop`] to reclaim its nodes
+//! along with the values they hold.
+pub mod binary_heap;
+#[doc(inline)]
+pub use binary_heap::BinaryHeap;
+pub mod hash_map;
+#[doc(inline)]
+pub use hash_map::HashMap;
+pub mod union_find;
+#[doc(inline)]
+pub use union_find::UnionFind;
+pub mod btree_map;
+#[doc(inline)]
+pub use btree_map::BTreeMapLike;
+pub mod splay_tree;
+#[doc(inline)]
+pub use splay_tree::SplayTree;
+pub mod threaded_tree;
+#[doc(inline)]
+pub use threaded_tree::ThreadedTree;
+pub mod xor_list;
+#[doc(inline)]
+pub use xor_list::XorList;
+pub mod stack;
+#[doc(inline)]
+pub use stack::Stack;
+pub mod queue;
+#[doc(inline)]
+pub use queue::Queue;
+pub mod array_list;
+#[doc(inline)]
+pub use array_list::ArrayList;
+pub mod interner;
+#[doc(inline)]
+pub use interner::Interner;
@@ -0,0 +1,364 @@
+use std::fmt::{Debug, Formatter};
+use std::mem::MaybeUninit;
+
+use crate::Pointee;
+
+/// [ArrayList](Self) is a double-ended list of up to `N` values
+/// stored inline in a ring buffer, hence without any heap
+/// allocation, exposing the API of [`XorList`](crate::collections::XorList)
+/// except that pushing onto a full list hands the value back.
+///
+/// Unlike a pointer-linked list its values are contiguous in memory,
+/// at the cost of a capacity fixed at compile time.
+///
+/// ```
+/// use unique_pointer::collections::ArrayList;
+///
+/// let mut list = ArrayList::<u8, 3>::new();
+/// assert_eq!(list.push_back(2), Ok(()));
+/// assert_eq!(list.push_back(3), Ok(()));
+/// assert_eq!(list.push_front(1), Ok(()));
+/// assert_eq!(list.push_back(4), Err(4));
+/// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+/// list.reverse();
+/// assert_eq!(list.pop_front(), Some(3));
+/// assert_eq!(list.pop_back(), Some(1));
+/// assert_eq!(list.len(), 1);
+/// ```
+pub struct ArrayList<T: Pointee, const N: usize> {
+    values: [MaybeUninit<T>; N],
+    head: usize,
+    len: usize,
+}
+
+impl<T: Pointee, const N: usize> ArrayList<T, N> {
+    pub fn new() -> ArrayList<T, N> {
+        ArrayList {
+            values: [const { MaybeUninit::uninit() }; N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.checked_sub(1)?)
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.checked_sub(1)?)
+    }
+
+    /// returns the value at `index` from the front
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        Some(unsafe { self.values[self.slot(index)].assume_init_ref() })
+    }
+
+    /// returns the value at `index` from the front
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let slot = self.slot(index);
+        Some(unsafe { self.values[slot].assume_init_mut() })
+    }
+
+    /// places `value` in front of the list or returns it if the list
+    /// is full
+    pub fn push_front(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.head = self.slot(N - 1);
+        self.values[self.head].write(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// places `value` at the back of the list or returns it if the
+    /// list is full
+    pub fn push_back(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        let slot = self.slot(self.len);
+        self.values[slot].write(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let value = unsafe { self.values[self.head].assume_init_read() };
+        self.head = self.slot(1);
+        self.len -= 1;
+        Some(value)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.len -= 1;
+        Some(unsafe { self.values[self.slot(self.len)].assume_init_read() })
+    }
+
+    /// reverses the list by swapping its values pairwise from both
+    /// ends
+    pub fn reverse(&mut self) {
+        for index in 0..self.len / 2 {
+            let (front, back) = (self.slot(index), self.slot(self.len - 1 - index));
+            self.values.swap(front, back);
+        }
+    }
+
+    /// returns the values as two contiguous slices, from the front to
+    /// the end of the buffer and from the start of the buffer to the
+    /// back
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front, back) = self.ranges();
+        unsafe {
+            (
+                self.values[front].assume_init_ref(),
+                self.values[back].assume_init_ref(),
+            )
+        }
+    }
+
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front, back) = self.ranges();
+        let (before, after) = self.values.split_at_mut(front.start);
+        unsafe {
+            (
+                after[..front.len()].assume_init_mut(),
+                before[back].assume_init_mut(),
+            )
+        }
+    }
+
+    /// returns a double-ended iterator from the front to the back
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter {
+            front: front.iter(),
+            back: back.iter(),
+        }
+    }
+
+    /// returns a double-ended iterator from the front to the back
+    /// with mutable references to the values
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        IterMut {
+            front: front.iter_mut(),
+            back: back.iter_mut(),
+        }
+    }
+
+    /// moves the values out from the front to the back, leaving the
+    /// list empty
+    pub fn drain(&mut self) -> IntoIter<T, N> {
+        std::mem::take(self).into_iter()
+    }
+
+    /// returns the position in the buffer of the value at `index`
+    /// from the front, wrapping around its end
+    fn slot(&self, index: usize) -> usize {
+        let slot = self.head + index;
+        if slot >= N {
+            slot - N
+        } else {
+            slot
+        }
+    }
+
+    /// returns the ranges of the buffer holding the values before and
+    /// after wrapping around its end
+    fn ranges(&self) -> (std::ops::Range<usize>, std::ops::Range<usize>) {
+        let end = self.head + self.len;
+        if end > N {
+            (self.head..N, 0..end - N)
+        } else {
+            (self.head..end, 0..0)
+        }
+    }
+}
+
+impl<T: Pointee, const N: usize> Drop for ArrayList<T, N> {
+    fn drop(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        unsafe {
+            std::ptr::drop_in_place(front);
+            std::ptr::drop_in_place(back);
+        }
+    }
+}
+
+impl<T: Pointee, const N: usize> Default for ArrayList<T, N> {
+    fn default() -> ArrayList<T, N> {
+        ArrayList::new()
+    }
+}
+
+/// panics if the iterator yields more than `N` values
+impl<T: Pointee, const N: usize> FromIterator<T> for ArrayList<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> ArrayList<T, N> {
+        let mut list = ArrayList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+/// panics if the list cannot hold every value yielded by the
+/// iterator
+impl<T: Pointee, const N: usize> Extend<T> for ArrayList<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            if self.push_back(value).is_err() {
+                panic!("ArrayList capacity of {} exceeded", N);
+            }
+        }
+    }
+}
+
+impl<T: Pointee, const N: usize> IntoIterator for ArrayList<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    /// moves the values out of the list from the front to the back
+    fn into_iter(self) -> IntoIter<T, N> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T: Pointee, const N: usize> IntoIterator for &'a ArrayList<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T: Pointee, const N: usize> IntoIterator for &'a mut ArrayList<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T: Pointee + Debug, const N: usize> Debug for ArrayList<T, N> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// `Iter` walks the values of an [`ArrayList`] from either end.
+pub struct Iter<'a, T: Pointee> {
+    front: std::slice::Iter<'a, T>,
+    back: std::slice::Iter<'a, T>,
+}
+
+impl<'a, T: Pointee> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: Pointee> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T: Pointee> ExactSizeIterator for Iter<'_, T> {}
+
+/// `IterMut` walks the values of an [`ArrayList`] from either end.
+pub struct IterMut<'a, T: Pointee> {
+    front: std::slice::IterMut<'a, T>,
+    back: std::slice::IterMut<'a, T>,
+}
+
+impl<'a, T: Pointee> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: Pointee> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T: Pointee> ExactSizeIterator for IterMut<'_, T> {}
+
+/// `IntoIter` moves the values out of an [`ArrayList`] from either
+/// end.
+pub struct IntoIter<T: Pointee, const N: usize> {
+    list: ArrayList<T, N>,
+}
+
+impl<T: Pointee, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T: Pointee, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T: Pointee, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
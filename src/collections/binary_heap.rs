@@ -0,0 +1,183 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::{Pointee, UniquePointer};
+
+/// [BinaryHeap](Self) is a max-heap stored in a
+/// [`UniquePointer<Vec<T>>`](crate::UniquePointer) in which the
+/// children of the element at index `i` live at indexes `2i + 1` and
+/// `2i + 2`.
+///
+/// ```
+/// use unique_pointer::collections::BinaryHeap;
+///
+/// let mut heap = BinaryHeap::new();
+/// heap.push(3);
+/// heap.push(7);
+/// heap.push(1);
+///
+/// assert_eq!(heap.peek(), Some(&7));
+/// assert_eq!(heap.pop(), Some(7));
+/// assert_eq!(heap.into_sorted_vec(), vec![1, 3]);
+/// ```
+pub struct BinaryHeap<T: Pointee + Ord> {
+    data: UniquePointer<Vec<T>>,
+}
+
+impl<T: Pointee + Ord> BinaryHeap<T> {
+    pub fn new() -> BinaryHeap<T> {
+        BinaryHeap {
+            data: UniquePointer::null(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.as_slice().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// returns the greatest element of the heap
+    pub fn peek(&self) -> Option<&T> {
+        self.data.as_slice().first()
+    }
+
+    pub fn push(&mut self, item: T) {
+        if !self.data.is_written() {
+            self.data.write(Vec::new());
+        }
+        if let Some(data) = self.data.as_mut() {
+            data.push(item);
+        }
+        let last = self.len() - 1;
+        self.sift_up(last);
+    }
+
+    /// removes and returns the greatest element of the heap
+    pub fn pop(&mut self) -> Option<T> {
+        let item = self.data.as_mut()?.pop()?;
+        if self.is_empty() {
+            return Some(item);
+        }
+        let top = std::mem::replace(&mut self.data.as_mut_slice()[0], item);
+        self.sift_down(0, self.len());
+        Some(top)
+    }
+
+    /// returns the elements of the heap in ascending order
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut end = self.len();
+        while end > 1 {
+            end -= 1;
+            self.data.as_mut_slice().swap(0, end);
+            self.sift_down(0, end);
+        }
+        self.into_vec()
+    }
+
+    /// returns the elements of the heap in heap order
+    pub fn into_vec(mut self) -> Vec<T> {
+        self.data.take().unwrap_or_default()
+    }
+
+    /// returns an iterator over the elements in heap order, there is
+    /// no mutable counterpart as it would break the heap property
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.as_slice().iter()
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        let data = self.data.as_mut_slice();
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if data[index] <= data[parent] {
+                break;
+            }
+            data.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    /// restores the heap property of `self.data[..end]` below `index`
+    fn sift_down(&mut self, mut index: usize, end: usize) {
+        let data = self.data.as_mut_slice();
+        loop {
+            let mut greatest = index;
+            for child in [2 * index + 1, 2 * index + 2] {
+                if child < end && data[child] > data[greatest] {
+                    greatest = child;
+                }
+            }
+            if greatest == index {
+                break;
+            }
+            data.swap(index, greatest);
+            index = greatest;
+        }
+    }
+}
+
+impl<T: Pointee + Ord> Drop for BinaryHeap<T> {
+    fn drop(&mut self) {
+        self.data.take();
+    }
+}
+
+impl<T: Pointee + Ord> Default for BinaryHeap<T> {
+    fn default() -> BinaryHeap<T> {
+        BinaryHeap::new()
+    }
+}
+
+impl<T: Pointee + Ord> From<Vec<T>> for BinaryHeap<T> {
+    fn from(data: Vec<T>) -> BinaryHeap<T> {
+        let mut heap = BinaryHeap {
+            data: UniquePointer::from(data),
+        };
+        let len = heap.len();
+        for index in (0..len / 2).rev() {
+            heap.sift_down(index, len);
+        }
+        heap
+    }
+}
+
+impl<T: Pointee + Ord> FromIterator<T> for BinaryHeap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> BinaryHeap<T> {
+        BinaryHeap::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<T: Pointee + Ord> Extend<T> for BinaryHeap<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T: Pointee + Ord> IntoIterator for BinaryHeap<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    /// moves the elements out of the heap in heap order
+    fn into_iter(self) -> std::vec::IntoIter<T> {
+        self.into_vec().into_iter()
+    }
+}
+
+impl<'a, T: Pointee + Ord> IntoIterator for &'a BinaryHeap<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> std::slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T: Pointee + Ord + Debug> Debug for BinaryHeap<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
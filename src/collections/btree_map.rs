@@ -0,0 +1,723 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+
+use crate::{Pointee, UniquePointer};
+
+/// the minimum degree of the tree: nodes other than the root hold
+/// between `MIN_DEGREE - 1` and `2 * MIN_DEGREE - 1` keys
+const MIN_DEGREE: usize = 3;
+
+#[derive(Debug)]
+struct Node<K: Pointee, V: Pointee> {
+    keys: Vec<K>,
+    values: Vec<V>,
+    children: Vec<UniquePointer<Node<K, V>>>,
+}
+
+impl<K: Pointee + Ord, V: Pointee> Node<K, V> {
+    fn new() -> Node<K, V> {
+        Node {
+            keys: Vec::new(),
+            values: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    fn is_full(&self) -> bool {
+        self.keys.len() == 2 * MIN_DEGREE - 1
+    }
+
+    fn child(&self, index: usize) -> &Node<K, V> {
+        self.children[index]
+            .as_ref()
+            .expect("B-tree nodes are never NULL")
+    }
+
+    fn child_mut<'c>(&mut self, index: usize) -> &'c mut Node<K, V> {
+        self.children[index]
+            .as_mut()
+            .expect("B-tree nodes are never NULL")
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        match self.keys.binary_search(key) {
+            Ok(index) => Some(&self.values[index]),
+            Err(_) if self.is_leaf() => None,
+            Err(index) => self.child(index).get(key),
+        }
+    }
+
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        match self.keys.binary_search(key) {
+            Ok(index) => Some(&mut self.values[index]),
+            Err(_) if self.is_leaf() => None,
+            Err(index) => self.child_mut(index).get_mut(key),
+        }
+    }
+
+    /// splits the full child at `index` around its median key, which
+    /// moves up into `self`
+    fn split_child(&mut self, index: usize) {
+        let child = self.child_mut(index);
+        let mut sibling = Node::new();
+        sibling.keys = child.keys.split_off(MIN_DEGREE);
+        sibling.values = child.values.split_off(MIN_DEGREE);
+        if !child.is_leaf() {
+            sibling.children = child.children.split_off(MIN_DEGREE);
+        }
+        let (key, value) = match (child.keys.pop(), child.values.pop()) {
+            (Some(key), Some(value)) => (key, value),
+            _ => unreachable!("full nodes hold 2 * MIN_DEGREE - 1 keys"),
+        };
+        self.keys.insert(index, key);
+        self.values.insert(index, value);
+        self.children
+            .insert(index + 1, UniquePointer::from(sibling));
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        match self.keys.binary_search(key) {
+            Ok(index) if self.is_leaf() => {
+                self.keys.remove(index);
+                Some(self.values.remove(index))
+            }
+            Ok(index) => {
+                if self.child(index).keys.len() >= MIN_DEGREE {
+                    let (key, value) = self.child_mut(index).remove_max();
+                    self.keys[index] = key;
+                    Some(std::mem::replace(&mut self.values[index], value))
+                } else if self.child(index + 1).keys.len() >= MIN_DEGREE {
+                    let (key, value) = self.child_mut(index + 1).remove_min();
+                    self.keys[index] = key;
+                    Some(std::mem::replace(&mut self.values[index], value))
+                } else {
+                    self.merge(index);
+                    self.child_mut(index).remove(key)
+                }
+            }
+            Err(_) if self.is_leaf() => None,
+            Err(index) => {
+                let index = self.fill(index);
+                self.child_mut(index).remove(key)
+            }
+        }
+    }
+
+    fn remove_max(&mut self) -> (K, V) {
+        if self.is_leaf() {
+            return match (self.keys.pop(), self.values.pop()) {
+                (Some(key), Some(value)) => (key, value),
+                _ => unreachable!("non-root nodes are never empty"),
+            };
+        }
+        let index = self.fill(self.children.len() - 1);
+        self.child_mut(index).remove_max()
+    }
+
+    fn remove_min(&mut self) -> (K, V) {
+        if self.is_leaf() {
+            return (self.keys.remove(0), self.values.remove(0));
+        }
+        let index = self.fill(0);
+        self.child_mut(index).remove_min()
+    }
+
+    /// ensures the child at `index` holds at least `MIN_DEGREE` keys
+    /// by borrowing a key from a sibling or merging it into one and
+    /// returns the index the child ends up at
+    fn fill(&mut self, index: usize) -> usize {
+        if self.child(index).keys.len() >= MIN_DEGREE {
+            return index;
+        }
+        if index > 0 && self.child(index - 1).keys.len() >= MIN_DEGREE {
+            let (child, sibling) = (self.child_mut(index), self.child_mut(index - 1));
+            if let (Some(key), Some(value)) = (sibling.keys.pop(), sibling.values.pop()) {
+                child
+                    .keys
+                    .insert(0, std::mem::replace(&mut self.keys[index - 1], key));
+                child
+                    .values
+                    .insert(0, std::mem::replace(&mut self.values[index - 1], value));
+            }
+            if let Some(grandchild) = sibling.children.pop() {
+                child.children.insert(0, grandchild);
+            }
+            index
+        } else if index < self.keys.len() && self.child(index + 1).keys.len() >= MIN_DEGREE {
+            let (child, sibling) = (self.child_mut(index), self.child_mut(index + 1));
+            child.keys.push(std::mem::replace(
+                &mut self.keys[index],
+                sibling.keys.remove(0),
+            ));
+            child.values.push(std::mem::replace(
+                &mut self.values[index],
+                sibling.values.remove(0),
+            ));
+            if !sibling.is_leaf() {
+                child.children.push(sibling.children.remove(0));
+            }
+            index
+        } else if index < self.keys.len() {
+            self.merge(index);
+            index
+        } else {
+            self.merge(index - 1);
+            index - 1
+        }
+    }
+
+    /// merges the key at `index` and the child to its right into the
+    /// child to its left
+    fn merge(&mut self, index: usize) {
+        let sibling = match self.children.remove(index + 1).take() {
+            Some(sibling) => sibling,
+            None => return,
+        };
+        let child = self.child_mut(index);
+        child.keys.push(self.keys.remove(index));
+        child.values.push(self.values.remove(index));
+        child.keys.extend(sibling.keys);
+        child.values.extend(sibling.values);
+        child.children.extend(sibling.children);
+    }
+
+    /// descends towards `key`, splitting full children on the way
+    /// down such that the leaf reached has room for `key`
+    fn entry<'a>(&'a mut self, key: K, len: &'a mut usize) -> Entry<'a, K, V> {
+        let mut node = self;
+        loop {
+            let mut index = match node.keys.binary_search(&key) {
+                Ok(index) => return Entry::Occupied(OccupiedEntry { node, index }),
+                Err(index) if node.is_leaf() => {
+                    return Entry::Vacant(VacantEntry {
+                        key,
+                        node,
+                        index,
+                        len,
+                    });
+                }
+                Err(index) => index,
+            };
+            if node.child(index).is_full() {
+                node.split_child(index);
+                match key.cmp(&node.keys[index]) {
+                    Ordering::Equal => return Entry::Occupied(OccupiedEntry { node, index }),
+                    Ordering::Greater => index += 1,
+                    Ordering::Less => {}
+                }
+            }
+            node = node.child_mut(index);
+        }
+    }
+
+    fn height(&self) -> usize {
+        if self.is_leaf() {
+            1
+        } else {
+            1 + self.child(0).height()
+        }
+    }
+}
+
+/// moves the node out of `pointer` and drops it along with its
+/// descendants
+fn drop_node<K: Pointee, V: Pointee>(mut pointer: UniquePointer<Node<K, V>>) {
+    if let Some(node) = pointer.take() {
+        for child in node.children {
+            drop_node(child);
+        }
+    }
+}
+
+/// [BTreeMapLike](Self) is an ordered map stored in a B-tree whose
+/// nodes hold arrays of keys and values along with an array of
+/// [`UniquePointer`](crate::UniquePointer)s to their children.
+///
+/// Insertion splits full nodes on the way down and removal borrows
+/// from or merges with siblings on the way down, such that every leaf
+/// remains at the same depth.
+///
+/// ```
+/// use unique_pointer::collections::BTreeMapLike;
+///
+/// let mut map = BTreeMapLike::new();
+/// for key in [5, 1, 4, 2, 3] {
+///     map.insert(key, key * 10);
+/// }
+/// assert_eq!(map.get(&4), Some(&40));
+/// assert_eq!(map.remove(&1), Some(10));
+/// assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+/// ```
+pub struct BTreeMapLike<K: Pointee + Ord, V: Pointee> {
+    root: UniquePointer<Node<K, V>>,
+    len: usize,
+}
+
+impl<K: Pointee + Ord, V: Pointee> BTreeMapLike<K, V> {
+    pub fn new() -> BTreeMapLike<K, V> {
+        BTreeMapLike {
+            root: UniquePointer::from(Node::new()),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// returns the number of levels of the tree
+    pub fn height(&self) -> usize {
+        self.root().height()
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.root().get(key)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.root_mut().get_mut(key)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// inserts `value` under `key` and returns the value previously
+    /// stored under `key`
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+
+    /// returns the entry of `key` for in-place manipulation, found or
+    /// made room for in a single descent of the tree
+    ///
+    /// ```
+    /// use unique_pointer::collections::BTreeMapLike;
+    ///
+    /// let mut counts = BTreeMapLike::new();
+    /// for word in "the cat and the hat".split(' ') {
+    ///     *counts.entry(word).or_insert(0) += 1;
+    /// }
+    /// assert_eq!(counts.get(&"the"), Some(&2));
+    /// assert_eq!(counts.get(&"cat"), Some(&1));
+    /// ```
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        if self.root().is_full() {
+            let mut root = Node::new();
+            root.children
+                .push(std::mem::replace(&mut self.root, UniquePointer::null()));
+            self.root = UniquePointer::from(root);
+            self.root_mut().split_child(0);
+        }
+        let root = self.root.as_mut().expect("B-tree nodes are never NULL");
+        root.entry(key, &mut self.len)
+    }
+
+    /// removes `key` and returns the value stored under it
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let value = self.root_mut().remove(key)?;
+        self.len -= 1;
+        if self.root().keys.is_empty() && !self.root().is_leaf() {
+            let child = self.root_mut().children.remove(0);
+            drop_node(std::mem::replace(&mut self.root, child));
+        }
+        Some(value)
+    }
+
+    /// returns the entry of the smallest key
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.iter().next()
+    }
+
+    /// returns the entry of the greatest key
+    pub fn last(&self) -> Option<(&K, &V)> {
+        let mut node = self.root();
+        while !node.is_leaf() {
+            node = node.child(node.children.len() - 1);
+        }
+        Some((node.keys.last()?, node.values.last()?))
+    }
+
+    /// returns an iterator over the entries in ascending key order
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.descend(self.root());
+        iter
+    }
+
+    /// returns an iterator over the entries in ascending key order
+    /// with mutable references to the values
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        let mut iter = IterMut { stack: Vec::new() };
+        iter.descend(self.root_mut());
+        iter
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.iter_mut().map(|(_, value)| value)
+    }
+
+    /// moves the entries out in ascending key order, leaving the map
+    /// empty, each node being deallocated as the iteration reaches
+    /// it
+    pub fn drain(&mut self) -> IntoIter<K, V> {
+        std::mem::take(self).into_iter()
+    }
+
+    /// empties the map in ascending key order, passing each entry to
+    /// `visitor` right before dropping it
+    pub fn drop_with(&mut self, mut visitor: impl FnMut(&K, &mut V)) {
+        for (key, mut value) in self.drain() {
+            visitor(&key, &mut value);
+        }
+    }
+
+    fn root(&self) -> &Node<K, V> {
+        self.root.as_ref().expect("B-tree nodes are never NULL")
+    }
+
+    fn root_mut(&mut self) -> &mut Node<K, V> {
+        self.root.as_mut().expect("B-tree nodes are never NULL")
+    }
+}
+
+impl<K: Pointee + Ord, V: Pointee> Drop for BTreeMapLike<K, V> {
+    fn drop(&mut self) {
+        drop_node(std::mem::replace(&mut self.root, UniquePointer::null()));
+    }
+}
+
+impl<K: Pointee + Ord, V: Pointee> Default for BTreeMapLike<K, V> {
+    fn default() -> BTreeMapLike<K, V> {
+        BTreeMapLike::new()
+    }
+}
+
+impl<K: Pointee + Ord, V: Pointee> FromIterator<(K, V)> for BTreeMapLike<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> BTreeMapLike<K, V> {
+        let mut map = BTreeMapLike::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Pointee + Ord, V: Pointee> Extend<(K, V)> for BTreeMapLike<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Pointee + Ord, V: Pointee> IntoIterator for BTreeMapLike<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    /// moves the entries out of the map in ascending key order,
+    /// deallocating each node as the iteration reaches it
+    fn into_iter(mut self) -> IntoIter<K, V> {
+        let mut iter = IntoIter { stack: Vec::new() };
+        iter.descend(std::mem::replace(&mut self.root, UniquePointer::null()));
+        iter
+    }
+}
+
+impl<'a, K: Pointee + Ord, V: Pointee> IntoIterator for &'a BTreeMapLike<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K: Pointee + Ord, V: Pointee> IntoIterator for &'a mut BTreeMapLike<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+impl<K: Pointee + Ord + Debug, V: Pointee + Debug> Debug for BTreeMapLike<K, V> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Pointee + Ord, V: Pointee + PartialEq> PartialEq for BTreeMapLike<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+/// [Entry](Self) is a view into a single key of a [`BTreeMapLike`],
+/// returned by [`BTreeMapLike::entry`].
+pub enum Entry<'a, K: Pointee + Ord, V: Pointee> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+impl<'a, K: Pointee + Ord, V: Pointee> Entry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// returns the value of the entry, inserting `value` if vacant
+    pub fn or_insert(self, value: V) -> &'a mut V {
+        self.or_insert_with(|| value)
+    }
+
+    /// returns the value of the entry, inserting the value returned by
+    /// `value` if vacant
+    pub fn or_insert_with(self, value: impl FnOnce() -> V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(value()),
+        }
+    }
+
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// calls `modify` upon the value of an occupied entry
+    pub fn and_modify(mut self, modify: impl FnOnce(&mut V)) -> Entry<'a, K, V> {
+        if let Entry::Occupied(entry) = &mut self {
+            modify(entry.get_mut());
+        }
+        self
+    }
+}
+
+/// [OccupiedEntry](Self) is an [`Entry`] whose key is present.
+pub struct OccupiedEntry<'a, K: Pointee + Ord, V: Pointee> {
+    node: &'a mut Node<K, V>,
+    index: usize,
+}
+
+impl<'a, K: Pointee + Ord, V: Pointee> OccupiedEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &self.node.keys[self.index]
+    }
+
+    pub fn get(&self) -> &V {
+        &self.node.values[self.index]
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.node.values[self.index]
+    }
+
+    /// returns the value with the lifetime of the map
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.node.values[self.index]
+    }
+
+    /// replaces the value and returns the previous one
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+}
+
+/// [VacantEntry](Self) is an [`Entry`] whose key is absent, pointing
+/// at the leaf with room for the key.
+pub struct VacantEntry<'a, K: Pointee + Ord, V: Pointee> {
+    key: K,
+    node: &'a mut Node<K, V>,
+    index: usize,
+    len: &'a mut usize,
+}
+
+impl<'a, K: Pointee + Ord, V: Pointee> VacantEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// inserts `value` under the key of the entry without descending
+    /// the tree again
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.node.keys.insert(self.index, self.key);
+        self.node.values.insert(self.index, value);
+        *self.len += 1;
+        &mut self.node.values[self.index]
+    }
+}
+
+/// `Iter` iterates over the entries of a [`BTreeMapLike`] in
+/// ascending key order.
+pub struct Iter<'a, K: Pointee, V: Pointee> {
+    stack: Vec<(&'a Node<K, V>, usize)>,
+}
+
+impl<'a, K: Pointee + Ord, V: Pointee> Iter<'a, K, V> {
+    /// stacks the path to the leftmost leaf of `node`
+    fn descend(&mut self, mut node: &'a Node<K, V>) {
+        loop {
+            self.stack.push((node, 0));
+            if node.is_leaf() {
+                break;
+            }
+            node = node.children[0]
+                .as_ref()
+                .expect("B-tree nodes are never NULL");
+        }
+    }
+}
+
+impl<'a, K: Pointee + Ord, V: Pointee> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        loop {
+            let (node, index) = self.stack.last_mut()?;
+            let node: &'a Node<K, V> = node;
+            if *index < node.keys.len() {
+                let entry = (&node.keys[*index], &node.values[*index]);
+                *index += 1;
+                if !node.is_leaf() {
+                    let child = node.children[*index]
+                        .as_ref()
+                        .expect("B-tree nodes are never NULL");
+                    self.descend(child);
+                }
+                return Some(entry);
+            }
+            self.stack.pop();
+        }
+    }
+}
+
+/// `IterMut` iterates over the entries of a [`BTreeMapLike`] in
+/// ascending key order, splitting each node it visits into the
+/// iterators of its keys, values and children.
+pub struct IterMut<'a, K: Pointee, V: Pointee> {
+    stack: Vec<IterMutFrame<'a, K, V>>,
+}
+
+/// the keys, values and children of a node yet to be visited
+type IterMutFrame<'a, K, V> = (
+    std::slice::Iter<'a, K>,
+    std::slice::IterMut<'a, V>,
+    std::slice::IterMut<'a, UniquePointer<Node<K, V>>>,
+);
+
+impl<'a, K: Pointee, V: Pointee> IterMut<'a, K, V> {
+    /// stacks the path to the leftmost leaf of `node`
+    fn descend(&mut self, node: &'a mut Node<K, V>) {
+        let mut children = node.children.iter_mut();
+        let leftmost = children.next().and_then(|child| child.as_mut());
+        self.stack
+            .push((node.keys.iter(), node.values.iter_mut(), children));
+        if let Some(child) = leftmost {
+            self.descend(child);
+        }
+    }
+}
+
+impl<'a, K: Pointee, V: Pointee> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a mut V)> {
+        loop {
+            let (keys, values, children) = self.stack.last_mut()?;
+            if let (Some(key), Some(value)) = (keys.next(), values.next()) {
+                if let Some(child) = children.next().and_then(|child| child.as_mut()) {
+                    self.descend(child);
+                }
+                return Some((key, value));
+            }
+            self.stack.pop();
+        }
+    }
+}
+
+/// `IntoIter` moves the entries out of a [`BTreeMapLike`] in
+/// ascending key order.
+pub struct IntoIter<K: Pointee, V: Pointee> {
+    stack: Vec<IntoIterFrame<K, V>>,
+}
+
+/// the keys, values and children of a node yet to be moved out
+type IntoIterFrame<K, V> = (
+    std::vec::IntoIter<K>,
+    std::vec::IntoIter<V>,
+    std::vec::IntoIter<UniquePointer<Node<K, V>>>,
+);
+
+impl<K: Pointee, V: Pointee> IntoIter<K, V> {
+    /// moves the nodes on the path to the leftmost leaf of `pointer`
+    /// onto the stack
+    fn descend(&mut self, mut pointer: UniquePointer<Node<K, V>>) {
+        if let Some(node) = pointer.take() {
+            let mut children = node.children.into_iter();
+            let leftmost = children.next();
+            self.stack
+                .push((node.keys.into_iter(), node.values.into_iter(), children));
+            if let Some(child) = leftmost {
+                self.descend(child);
+            }
+        }
+    }
+}
+
+impl<K: Pointee, V: Pointee> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        loop {
+            let (keys, values, children) = self.stack.last_mut()?;
+            if let (Some(key), Some(value)) = (keys.next(), values.next()) {
+                if let Some(child) = children.next() {
+                    self.descend(child);
+                }
+                return Some((key, value));
+            }
+            self.stack.pop();
+        }
+    }
+}
+
+/// drops the entries that were not iterated over
+impl<K: Pointee, V: Pointee> Drop for IntoIter<K, V> {
+    fn drop(&mut self) {
+        for (_, _, children) in self.stack.drain(..) {
+            for child in children {
+                drop_node(child);
+            }
+        }
+    }
+}
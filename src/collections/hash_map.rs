@@ -0,0 +1,322 @@
+use std::collections::hash_map::RandomState;
+use std::fmt::{Debug, Formatter};
+use std::hash::{BuildHasher, Hash};
+
+use crate::{Pointee, UniquePointer};
+
+const INITIAL_BUCKETS: usize = 8;
+
+#[derive(Debug)]
+struct Entry<K: Pointee, V: Pointee> {
+    key: K,
+    value: V,
+    next: UniquePointer<Entry<K, V>>,
+}
+
+/// [HashMap](Self) is a hash table with separate chaining in which
+/// each bucket is a singly-linked list of entries chained through
+/// [`UniquePointer`](crate::UniquePointer).
+///
+/// The table doubles its buckets once it holds more than 3/4 entries
+/// per bucket, relinking the existing entries into the new buckets
+/// rather than reallocating them.
+///
+/// ```
+/// use unique_pointer::collections::HashMap;
+///
+/// let mut map = HashMap::new();
+/// map.insert("one", 1);
+/// map.insert("two", 2);
+///
+/// assert_eq!(map.get(&"one"), Some(&1));
+/// assert_eq!(map.insert("one", 10), Some(1));
+/// assert_eq!(map.remove(&"two"), Some(2));
+/// assert_eq!(map.len(), 1);
+/// ```
+pub struct HashMap<K: Pointee + Hash + Eq, V: Pointee> {
+    buckets: Vec<UniquePointer<Entry<K, V>>>,
+    len: usize,
+    hasher: RandomState,
+}
+
+impl<K: Pointee + Hash + Eq, V: Pointee> HashMap<K, V> {
+    pub fn new() -> HashMap<K, V> {
+        HashMap::with_buckets(INITIAL_BUCKETS)
+    }
+
+    /// creates a [HashMap](Self) with room for `buckets` chains
+    pub fn with_buckets(buckets: usize) -> HashMap<K, V> {
+        HashMap {
+            buckets: (0..buckets.max(1)).map(|_| UniquePointer::null()).collect(),
+            len: 0,
+            hasher: RandomState::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// returns the number of buckets
+    pub fn buckets(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// inserts `value` under `key` and returns the value previously
+    /// stored under `key`
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(current) = self.get_mut(&key) {
+            return Some(std::mem::replace(current, value));
+        }
+        if (self.len + 1) * 4 > self.buckets.len() * 3 {
+            self.resize(self.buckets.len() * 2);
+        }
+        let index = self.bucket_of(&key);
+        let next = std::mem::replace(&mut self.buckets[index], UniquePointer::null());
+        self.buckets[index] = UniquePointer::from(Entry { key, value, next });
+        self.len += 1;
+        None
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entry(key).map(|entry| &entry.value)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let index = self.bucket_of(key);
+        let mut link = &mut self.buckets[index];
+        while let Some(entry) = link.as_mut() {
+            if entry.key == *key {
+                return Some(&mut entry.value);
+            }
+            link = &mut entry.next;
+        }
+        None
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entry(key).is_some()
+    }
+
+    /// removes `key` and returns the value stored under it
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.bucket_of(key);
+        let mut link = &mut self.buckets[index];
+        loop {
+            let entry = link.as_mut()?;
+            if entry.key == *key {
+                let next = std::mem::replace(&mut entry.next, UniquePointer::null());
+                let mut removed = std::mem::replace(link, next);
+                self.len -= 1;
+                return removed.take().map(|entry| entry.value);
+            }
+            link = &mut entry.next;
+        }
+    }
+
+    /// redistributes the entries among `buckets` chains
+    pub fn resize(&mut self, buckets: usize) {
+        let old = std::mem::replace(
+            &mut self.buckets,
+            (0..buckets.max(1)).map(|_| UniquePointer::null()).collect(),
+        );
+        for mut node in old {
+            while let Some(entry) = node.as_mut() {
+                let next = std::mem::replace(&mut entry.next, UniquePointer::null());
+                let index = self.bucket_of(&entry.key);
+                entry.next = std::mem::replace(&mut self.buckets[index], UniquePointer::null());
+                self.buckets[index] = std::mem::replace(&mut node, next);
+            }
+        }
+    }
+
+    /// returns an iterator over the entries in bucket order, which
+    /// is unspecified and changes as the table resizes
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            buckets: self.buckets.iter(),
+            entry: None,
+        }
+    }
+
+    /// returns an iterator over the entries in bucket order with
+    /// mutable references to the values
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            buckets: self.buckets.iter_mut(),
+            entry: None,
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.iter_mut().map(|(_, value)| value)
+    }
+
+    fn bucket_of(&self, key: &K) -> usize {
+        (self.hasher.hash_one(key) as usize) % self.buckets.len()
+    }
+
+    fn entry(&self, key: &K) -> Option<&Entry<K, V>> {
+        let mut entry = self.buckets[self.bucket_of(key)].as_ref();
+        while let Some(current) = entry {
+            if current.key == *key {
+                return Some(current);
+            }
+            entry = current.next.as_ref();
+        }
+        None
+    }
+}
+
+/// walks the chain of every bucket
+impl<K: Pointee + Hash + Eq, V: Pointee> Drop for HashMap<K, V> {
+    fn drop(&mut self) {
+        for bucket in self.buckets.iter_mut() {
+            let mut node = std::mem::replace(bucket, UniquePointer::null());
+            while let Some(Entry { next, .. }) = node.take() {
+                node = next;
+            }
+        }
+    }
+}
+
+impl<K: Pointee + Hash + Eq, V: Pointee> Default for HashMap<K, V> {
+    fn default() -> HashMap<K, V> {
+        HashMap::new()
+    }
+}
+
+impl<K: Pointee + Hash + Eq, V: Pointee> FromIterator<(K, V)> for HashMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> HashMap<K, V> {
+        let mut map = HashMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Pointee + Hash + Eq, V: Pointee> Extend<(K, V)> for HashMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Pointee + Hash + Eq, V: Pointee> IntoIterator for HashMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    /// moves the entries out of the map in bucket order,
+    /// deallocating each entry as it is yielded
+    fn into_iter(mut self) -> IntoIter<K, V> {
+        IntoIter {
+            buckets: std::mem::take(&mut self.buckets).into_iter(),
+            entry: UniquePointer::null(),
+        }
+    }
+}
+
+impl<'a, K: Pointee + Hash + Eq, V: Pointee> IntoIterator for &'a HashMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K: Pointee + Hash + Eq, V: Pointee> IntoIterator for &'a mut HashMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+impl<K: Pointee + Hash + Eq + Debug, V: Pointee + Debug> Debug for HashMap<K, V> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// `Iter` iterates over the entries of a [`HashMap`] in bucket order.
+pub struct Iter<'a, K: Pointee, V: Pointee> {
+    buckets: std::slice::Iter<'a, UniquePointer<Entry<K, V>>>,
+    entry: Option<&'a Entry<K, V>>,
+}
+
+impl<'a, K: Pointee, V: Pointee> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        loop {
+            if let Some(entry) = self.entry {
+                self.entry = entry.next.as_ref();
+                return Some((&entry.key, &entry.value));
+            }
+            self.entry = self.buckets.next()?.as_ref();
+        }
+    }
+}
+
+/// `IterMut` iterates over the entries of a [`HashMap`] in bucket
+/// order.
+pub struct IterMut<'a, K: Pointee, V: Pointee> {
+    buckets: std::slice::IterMut<'a, UniquePointer<Entry<K, V>>>,
+    entry: Option<&'a mut Entry<K, V>>,
+}
+
+impl<'a, K: Pointee, V: Pointee> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a mut V)> {
+        loop {
+            if let Some(entry) = self.entry.take() {
+                self.entry = entry.next.as_mut();
+                return Some((&entry.key, &mut entry.value));
+            }
+            self.entry = self.buckets.next()?.as_mut();
+        }
+    }
+}
+
+/// `IntoIter` moves the entries out of a [`HashMap`] in bucket
+/// order.
+pub struct IntoIter<K: Pointee, V: Pointee> {
+    buckets: std::vec::IntoIter<UniquePointer<Entry<K, V>>>,
+    entry: UniquePointer<Entry<K, V>>,
+}
+
+impl<K: Pointee, V: Pointee> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        loop {
+            if let Some(Entry { key, value, next }) = self.entry.take() {
+                self.entry = next;
+                return Some((key, value));
+            }
+            self.entry = self.buckets.next()?;
+        }
+    }
+}
+
+/// drops the entries that were not iterated over
+impl<K: Pointee, V: Pointee> Drop for IntoIter<K, V> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::hash_map::RandomState;
+use std::fmt::{Debug, Formatter};
+use std::hash::{BuildHasher, Hash};
+
+use crate::collections::HashMap;
+use crate::{Pointee, UniquePointer};
+
+/// [Interner](Self) is a pool of unique values handing out clones of
+/// the very same [`UniquePointer`] for equal values, such that
+/// repeated values are stored once.
+///
+/// The pool holds a reference to each value, therefore values whose
+/// reference count is down to one are no longer used outside of the
+/// pool and are released by [evict](Self::evict).
+///
+/// ```
+/// use unique_pointer::collections::Interner;
+///
+/// let mut interner = Interner::new();
+/// let a = interner.intern(String::from("symbol"));
+/// let b = interner.intern(String::from("symbol"));
+/// assert_eq!(a.addr(), b.addr());
+/// assert_eq!(interner.len(), 1);
+///
+/// drop(a);
+/// drop(b);
+/// assert_eq!(interner.evict(), 1);
+/// assert!(interner.is_empty());
+/// ```
+pub struct Interner<T: Pointee + Eq + Hash> {
+    values: HashMap<u64, Vec<UniquePointer<T>>>,
+    len: usize,
+    hasher: RandomState,
+}
+
+impl<T: Pointee + Eq + Hash> Interner<T> {
+    pub fn new() -> Interner<T> {
+        Interner {
+            values: HashMap::new(),
+            len: 0,
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// returns the number of distinct values in the pool
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// returns a clone of the pointer to the pooled value equal to
+    /// `value`, pooling `value` first if absent
+    pub fn intern(&mut self, value: T) -> UniquePointer<T> {
+        let hash = self.hasher.hash_one(&value);
+        if let Some(pointer) = self.find(hash, &value) {
+            return pointer;
+        }
+        let pointer = UniquePointer::from(value);
+        match self.values.get_mut(&hash) {
+            Some(values) => values.push(pointer.clone()),
+            None => {
+                self.values.insert(hash, vec![pointer.clone()]);
+            }
+        }
+        self.len += 1;
+        pointer
+    }
+
+    /// returns a clone of the pointer to the pooled value equal to
+    /// `value`
+    pub fn get(&self, value: &T) -> Option<UniquePointer<T>> {
+        self.find(self.hasher.hash_one(value), value)
+    }
+
+    fn find(&self, hash: u64, value: &T) -> Option<UniquePointer<T>> {
+        self.values
+            .get(&hash)?
+            .iter()
+            .find(|pointer| UniquePointer::as_ref(pointer) == Some(value))
+            .cloned()
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.get(value).is_some()
+    }
+
+    /// releases the values only referenced by the pool and returns
+    /// how many were released
+    pub fn evict(&mut self) -> usize {
+        let mut evicted = 0;
+        let mut emptied = Vec::new();
+        for (hash, values) in self.values.iter_mut() {
+            values.retain_mut(|pointer| {
+                if pointer.refs() > 1 {
+                    return true;
+                }
+                pointer.take();
+                evicted += 1;
+                false
+            });
+            if values.is_empty() {
+                emptied.push(*hash);
+            }
+        }
+        for hash in emptied {
+            self.values.remove(&hash);
+        }
+        self.len -= evicted;
+        evicted
+    }
+
+    /// returns an iterator over the pooled values in no particular
+    /// order
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.values
+            .values()
+            .flatten()
+            .filter_map(|pointer| pointer.as_ref())
+    }
+}
+
+/// releases the values only referenced by the pool, the others
+/// remain valid for as long as their clones live
+impl<T: Pointee + Eq + Hash> Drop for Interner<T> {
+    fn drop(&mut self) {
+        self.evict();
+    }
+}
+
+impl<T: Pointee + Eq + Hash> Default for Interner<T> {
+    fn default() -> Interner<T> {
+        Interner::new()
+    }
+}
+
+impl<T: Pointee + Eq + Hash + Debug> Debug for Interner<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,122 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::collections::xor_list::{IntoIter, Iter, IterMut};
+use crate::collections::XorList;
+use crate::Pointee;
+
+/// [Queue](Self) is a first-in first-out facade over [`XorList`].
+///
+/// ```
+/// use unique_pointer::collections::Queue;
+///
+/// let mut queue = Queue::new();
+/// queue.enqueue(1);
+/// queue.enqueue(2);
+/// assert_eq!(queue.front(), Some(&1));
+/// assert_eq!(queue.dequeue(), Some(1));
+/// assert_eq!(queue.dequeue(), Some(2));
+/// assert_eq!(queue.dequeue(), None);
+/// ```
+pub struct Queue<T: Pointee> {
+    list: XorList<T>,
+}
+
+impl<T: Pointee> Queue<T> {
+    pub fn new() -> Queue<T> {
+        Queue {
+            list: XorList::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// places `value` at the back of the queue
+    pub fn enqueue(&mut self, value: T) {
+        self.list.push_back(value)
+    }
+
+    /// removes the value at the front of the queue and returns it
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    /// returns the value to be dequeued next
+    pub fn front(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.list.front_mut()
+    }
+
+    /// returns an iterator over the values from the front to the
+    /// back of the queue
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.list.iter_mut()
+    }
+}
+
+impl<T: Pointee> IntoIterator for Queue<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// moves the values out from the front to the back of the queue
+    fn into_iter(self) -> IntoIter<T> {
+        self.list.into_iter()
+    }
+}
+
+impl<'a, T: Pointee> IntoIterator for &'a Queue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T: Pointee> IntoIterator for &'a mut Queue<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T: Pointee> Default for Queue<T> {
+    fn default() -> Queue<T> {
+        Queue::new()
+    }
+}
+
+impl<T: Pointee> FromIterator<T> for Queue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Queue<T> {
+        Queue {
+            list: XorList::from_iter(iter),
+        }
+    }
+}
+
+impl<T: Pointee> Extend<T> for Queue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.list.extend(iter)
+    }
+}
+
+/// renders the values from the front to the back of the queue
+impl<T: Pointee + Debug> Debug for Queue<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,422 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+
+use crate::{Error, Pointee, UniquePointer};
+
+/// nodes own their children whereas their parent is a read-only
+/// [`UniquePointer`]
+struct Node<T: Pointee> {
+    value: T,
+    parent: UniquePointer<Node<T>>,
+    left: UniquePointer<Node<T>>,
+    right: UniquePointer<Node<T>>,
+}
+
+impl<T: Pointee> Node<T> {
+    fn new(value: T, parent: UniquePointer<Node<T>>) -> Node<T> {
+        Node {
+            value,
+            parent,
+            left: UniquePointer::null(),
+            right: UniquePointer::null(),
+        }
+    }
+
+    fn is_left_child_of(&self, parent: &Node<T>) -> bool {
+        parent
+            .left
+            .as_ref()
+            .is_some_and(|left| std::ptr::eq(left, self))
+    }
+}
+
+/// renders the value only, rendering the links would recurse back
+/// and forth between parents and children
+impl<T: Pointee> Debug for Node<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        #[cfg(not(feature = "allow-no-debug"))]
+        return write!(f, "Node({:?})", self.value);
+        #[cfg(feature = "allow-no-debug")]
+        return write!(f, "Node({:p})", self);
+    }
+}
+
+/// returns a read-only copy of the pointer to `node` or NULL
+fn link<T: Pointee>(node: Option<&Node<T>>) -> UniquePointer<Node<T>> {
+    match node {
+        Some(node) => UniquePointer::read_only(node),
+        None => UniquePointer::null(),
+    }
+}
+
+/// [SplayTree](Self) is a self-adjusting binary search tree which
+/// moves every node it inserts or finds to the root through
+/// rotations, such that recently accessed values are cheap to access
+/// again and any sequence of `m` operations on `n` values takes
+/// `O(m log n)` rotations in total.
+///
+/// ```
+/// use unique_pointer::collections::SplayTree;
+///
+/// let mut tree = SplayTree::new();
+/// for value in [5, 3, 8, 1] {
+///     tree.insert(value);
+/// }
+/// assert_eq!(tree.root(), Some(&1));
+/// assert!(tree.contains(&8));
+/// assert_eq!(tree.root(), Some(&8));
+/// assert_eq!(tree.remove(&3), Some(3));
+/// assert_eq!(tree.iter().collect::<Vec<_>>(), vec![&1, &5, &8]);
+/// ```
+pub struct SplayTree<T: Pointee + Ord> {
+    root: UniquePointer<Node<T>>,
+    len: usize,
+    rotations: usize,
+}
+
+impl<T: Pointee + Ord> SplayTree<T> {
+    pub fn new() -> SplayTree<T> {
+        SplayTree {
+            root: UniquePointer::null(),
+            len: 0,
+            rotations: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// returns the value at the root, which is the value accessed
+    /// last
+    pub fn root(&self) -> Option<&T> {
+        self.root.as_ref().map(|node| &node.value)
+    }
+
+    /// returns the number of rotations performed so far
+    pub fn rotations(&self) -> usize {
+        self.rotations
+    }
+
+    /// inserts `value` unless already present, in either case
+    /// splaying its node, and returns true if it was inserted
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut parent = UniquePointer::null();
+        let mut link = &mut self.root;
+        while let Some(node) = link.as_mut() {
+            parent = UniquePointer::read_only(node);
+            link = match value.cmp(&node.value) {
+                Ordering::Equal => {
+                    self.splay(node);
+                    return false;
+                }
+                Ordering::Less => &mut node.left,
+                Ordering::Greater => &mut node.right,
+            };
+        }
+        *link = UniquePointer::from(Node::new(value, parent));
+        if let Some(node) = link.as_mut() {
+            self.splay(node);
+        }
+        self.len += 1;
+        true
+    }
+
+    /// returns true if `value` is present, splaying its node or the
+    /// last node visited otherwise
+    pub fn contains(&mut self, value: &T) -> bool {
+        let mut node = match self.root.as_mut() {
+            Some(node) => node,
+            None => return false,
+        };
+        loop {
+            let next = match value.cmp(&node.value) {
+                Ordering::Equal => {
+                    self.splay(node);
+                    return true;
+                }
+                Ordering::Less => node.left.as_mut(),
+                Ordering::Greater => node.right.as_mut(),
+            };
+            match next {
+                Some(next) => node = next,
+                None => {
+                    self.splay(node);
+                    return false;
+                }
+            }
+        }
+    }
+
+    /// removes `value` and returns it
+    pub fn remove(&mut self, value: &T) -> Option<T> {
+        if !self.contains(value) {
+            return None;
+        }
+        let mut root = std::mem::replace(&mut self.root, UniquePointer::null());
+        let node = root.as_mut()?;
+        let mut left = std::mem::replace(&mut node.left, UniquePointer::null());
+        let mut right = std::mem::replace(&mut node.right, UniquePointer::null());
+        if let Some(left) = left.as_mut() {
+            left.parent = UniquePointer::null();
+        }
+        if let Some(right) = right.as_mut() {
+            right.parent = UniquePointer::null();
+        }
+
+        self.root = left;
+        let mut max = self.root.as_mut();
+        while let Some(node) = max {
+            if node.right.is_null() {
+                self.splay(node);
+                break;
+            }
+            max = node.right.as_mut();
+        }
+        match self.root.as_mut() {
+            Some(max) => {
+                if let Some(right) = right.as_mut() {
+                    right.parent = UniquePointer::read_only(max);
+                }
+                max.right = right;
+            }
+            None => self.root = right,
+        }
+        self.len -= 1;
+        root.take().map(|node| node.value)
+    }
+
+    /// returns an iterator over the values in ascending order which,
+    /// unlike [`contains`](Self::contains), does not splay
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.descend(self.root.as_ref());
+        iter
+    }
+
+    /// returns the first pair of consecutive values of the in-order
+    /// traversal that are not in strictly ascending order, which only
+    /// happens when the ordering of values changes while in the tree
+    pub fn is_bst(&self) -> Result<(), (&T, &T)> {
+        let mut pairs = self.iter().zip(self.iter().skip(1));
+        match pairs.find(|(previous, value)| previous >= value) {
+            Some(pair) => Err(pair),
+            None => Ok(()),
+        }
+    }
+
+    /// [`is_bst`](Self::is_bst) returning [`Error::Invariant`] at the
+    /// address of the second value of the offending pair, in the
+    /// context of the tree
+    pub fn validate(&self) -> crate::Result<()> {
+        self.is_bst().map_err(|(_, value)| {
+            Error::Invariant {
+                addr: std::ptr::from_ref(value).addr(),
+                invariant: "values in strictly ascending order",
+            }
+            .context::<SplayTree<T>>("validate", std::ptr::from_ref(self).addr())
+        })
+    }
+
+    /// moves the values out in ascending order, leaving the tree
+    /// empty, each node being deallocated as its value is yielded
+    pub fn drain(&mut self) -> IntoIter<T> {
+        std::mem::take(self).into_iter()
+    }
+
+    /// empties the tree in ascending order, passing each value to
+    /// `visitor` right before dropping it
+    pub fn drop_with(&mut self, mut visitor: impl FnMut(&mut T)) {
+        for mut value in self.drain() {
+            visitor(&mut value);
+        }
+    }
+
+    /// moves `node` to the root
+    fn splay(&mut self, node: &mut Node<T>) {
+        while let Some(parent) = node.parent.as_ref() {
+            match parent.parent.as_ref() {
+                None => self.rotate(node),
+                Some(grandparent) => {
+                    if node.is_left_child_of(parent) == parent.is_left_child_of(grandparent) {
+                        if let Some(parent) = node.parent.as_mut() {
+                            self.rotate(parent);
+                        }
+                        self.rotate(node);
+                    } else {
+                        self.rotate(node);
+                        self.rotate(node);
+                    }
+                }
+            }
+        }
+    }
+
+    /// rotates `node` above its parent
+    fn rotate(&mut self, node: &mut Node<T>) {
+        let parent = match node.parent.as_mut() {
+            Some(parent) => parent,
+            None => return,
+        };
+        let is_left = node.is_left_child_of(parent);
+        let owner = match parent.parent.as_mut() {
+            None => &mut self.root,
+            Some(grandparent) if parent.is_left_child_of(grandparent) => &mut grandparent.left,
+            Some(grandparent) => &mut grandparent.right,
+        };
+        node.parent = link(parent.parent.as_ref());
+
+        let mut inner = if is_left {
+            std::mem::replace(&mut node.right, UniquePointer::null())
+        } else {
+            std::mem::replace(&mut node.left, UniquePointer::null())
+        };
+        if let Some(inner) = inner.as_mut() {
+            inner.parent = UniquePointer::read_only(parent);
+        }
+        let node_owner = if is_left {
+            std::mem::replace(&mut parent.left, inner)
+        } else {
+            std::mem::replace(&mut parent.right, inner)
+        };
+        let parent_owner = std::mem::replace(owner, node_owner);
+        if is_left {
+            node.right = parent_owner;
+        } else {
+            node.left = parent_owner;
+        }
+        parent.parent = UniquePointer::read_only(node);
+        self.rotations += 1;
+    }
+}
+
+/// iterative given that splay trees may degenerate into paths
+impl<T: Pointee + Ord> Drop for SplayTree<T> {
+    fn drop(&mut self) {
+        let mut pending = vec![std::mem::replace(&mut self.root, UniquePointer::null())];
+        while let Some(mut pointer) = pending.pop() {
+            if let Some(node) = pointer.take() {
+                pending.push(node.left);
+                pending.push(node.right);
+            }
+        }
+    }
+}
+
+impl<T: Pointee + Ord> Default for SplayTree<T> {
+    fn default() -> SplayTree<T> {
+        SplayTree::new()
+    }
+}
+
+impl<T: Pointee + Ord> FromIterator<T> for SplayTree<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> SplayTree<T> {
+        let mut tree = SplayTree::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<T: Pointee + Ord> Extend<T> for SplayTree<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: Pointee + Ord> IntoIterator for SplayTree<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// moves the values out of the tree in ascending order,
+    /// deallocating each node as its value is yielded
+    fn into_iter(mut self) -> IntoIter<T> {
+        IntoIter {
+            root: std::mem::replace(&mut self.root, UniquePointer::null()),
+        }
+    }
+}
+
+impl<'a, T: Pointee + Ord> IntoIterator for &'a SplayTree<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T: Pointee + Ord + Debug> Debug for SplayTree<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// `Iter` iterates over the values of a [`SplayTree`] in ascending
+/// order.
+pub struct Iter<'a, T: Pointee> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T: Pointee> Iter<'a, T> {
+    fn descend(&mut self, mut node: Option<&'a Node<T>>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left.as_ref();
+        }
+    }
+}
+
+impl<'a, T: Pointee> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.stack.pop()?;
+        self.descend(node.right.as_ref());
+        if let Some(next) = self.stack.last() {
+            next.right.prefetch_read();
+        }
+        Some(&node.value)
+    }
+}
+
+/// `IntoIter` moves the values out of a [`SplayTree`] in ascending
+/// order by rotating the minimum up to the root and unlinking it,
+/// hence it needs no memory besides the remaining nodes.
+pub struct IntoIter<T: Pointee> {
+    root: UniquePointer<Node<T>>,
+}
+
+impl<T: Pointee> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            let node = self.root.as_mut()?;
+            let mut left = std::mem::replace(&mut node.left, UniquePointer::null());
+            match left.as_mut() {
+                Some(child) => {
+                    node.left = std::mem::replace(&mut child.right, UniquePointer::null());
+                    child.right = std::mem::replace(&mut self.root, left);
+                }
+                None => {
+                    let Node { value, right, .. } = self.root.take()?;
+                    self.root = right;
+                    return Some(value);
+                }
+            }
+        }
+    }
+}
+
+/// drops the values that were not iterated over
+impl<T: Pointee> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
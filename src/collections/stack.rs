@@ -0,0 +1,122 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::collections::xor_list::{IntoIter, Iter, IterMut};
+use crate::collections::XorList;
+use crate::Pointee;
+
+/// [Stack](Self) is a last-in first-out facade over [`XorList`].
+///
+/// ```
+/// use unique_pointer::collections::Stack;
+///
+/// let mut stack = Stack::new();
+/// stack.push(1);
+/// stack.push(2);
+/// assert_eq!(stack.peek(), Some(&2));
+/// assert_eq!(stack.pop(), Some(2));
+/// assert_eq!(stack.pop(), Some(1));
+/// assert_eq!(stack.pop(), None);
+/// ```
+pub struct Stack<T: Pointee> {
+    list: XorList<T>,
+}
+
+impl<T: Pointee> Stack<T> {
+    pub fn new() -> Stack<T> {
+        Stack {
+            list: XorList::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// places `value` on top of the stack
+    pub fn push(&mut self, value: T) {
+        self.list.push_back(value)
+    }
+
+    /// removes the value on top of the stack and returns it
+    pub fn pop(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+
+    /// returns the value on top of the stack
+    pub fn peek(&self) -> Option<&T> {
+        self.list.back()
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.list.back_mut()
+    }
+
+    /// returns an iterator over the values from the bottom to the
+    /// top of the stack
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.list.iter_mut()
+    }
+}
+
+impl<T: Pointee> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// moves the values out from the bottom to the top of the stack
+    fn into_iter(self) -> IntoIter<T> {
+        self.list.into_iter()
+    }
+}
+
+impl<'a, T: Pointee> IntoIterator for &'a Stack<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T: Pointee> IntoIterator for &'a mut Stack<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T: Pointee> Default for Stack<T> {
+    fn default() -> Stack<T> {
+        Stack::new()
+    }
+}
+
+impl<T: Pointee> FromIterator<T> for Stack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Stack<T> {
+        Stack {
+            list: XorList::from_iter(iter),
+        }
+    }
+}
+
+impl<T: Pointee> Extend<T> for Stack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.list.extend(iter)
+    }
+}
+
+/// renders the values from the bottom to the top of the stack
+impl<T: Pointee + Debug> Debug for Stack<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
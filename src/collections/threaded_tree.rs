@@ -0,0 +1,406 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+
+use crate::{Error, Pointee, UniquePointer};
+
+/// a link flagged as a thread is a read-only [`UniquePointer`] to the
+/// in-order predecessor or successor, NULL at either end of the
+/// tree, otherwise it owns the child it points to
+struct Node<T: Pointee> {
+    value: T,
+    left: UniquePointer<Node<T>>,
+    right: UniquePointer<Node<T>>,
+    left_thread: bool,
+    right_thread: bool,
+}
+
+impl<T: Pointee> Node<T> {
+    fn new(
+        value: T,
+        predecessor: UniquePointer<Node<T>>,
+        successor: UniquePointer<Node<T>>,
+    ) -> Node<T> {
+        Node {
+            value,
+            left: predecessor,
+            right: successor,
+            left_thread: true,
+            right_thread: true,
+        }
+    }
+
+    fn leftmost(&self) -> &Node<T> {
+        let mut node = self;
+        while !node.left_thread {
+            match node.left.as_ref() {
+                Some(left) => node = left,
+                None => break,
+            }
+        }
+        node
+    }
+
+    fn rightmost(&self) -> &Node<T> {
+        let mut node = self;
+        while !node.right_thread {
+            match node.right.as_ref() {
+                Some(right) => node = right,
+                None => break,
+            }
+        }
+        node
+    }
+
+    fn successor<'a>(&self) -> Option<&'a Node<T>> {
+        if self.right_thread {
+            self.right.as_ref()
+        } else {
+            self.right
+                .as_ref()
+                .map(|right: &'a Node<T>| right.leftmost())
+        }
+    }
+
+    fn predecessor<'a>(&self) -> Option<&'a Node<T>> {
+        if self.left_thread {
+            self.left.as_ref()
+        } else {
+            self.left.as_ref().map(|left: &'a Node<T>| left.rightmost())
+        }
+    }
+}
+
+/// renders the value only, rendering the threads would recurse back
+/// into the tree
+impl<T: Pointee> Debug for Node<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        #[cfg(not(feature = "allow-no-debug"))]
+        return write!(f, "Node({:?})", self.value);
+        #[cfg(feature = "allow-no-debug")]
+        return write!(f, "Node({:p})", self);
+    }
+}
+
+/// [ThreadedTree](Self) is a binary search tree whose otherwise NULL
+/// child links point to the in-order predecessor and successor,
+/// therefore it is traversed in either direction in `O(1)` space
+/// without parent pointers nor a stack.
+///
+/// ```
+/// use unique_pointer::collections::ThreadedTree;
+///
+/// let tree = [5, 3, 8, 1, 4].into_iter().collect::<ThreadedTree<i32>>();
+/// assert_eq!(tree.iter().collect::<Vec<_>>(), vec![&1, &3, &4, &5, &8]);
+/// assert_eq!(tree.iter().rev().collect::<Vec<_>>(), vec![&8, &5, &4, &3, &1]);
+/// assert_eq!(tree.successor(&4), Some(&5));
+/// assert_eq!(tree.predecessor(&1), None);
+/// ```
+pub struct ThreadedTree<T: Pointee + Ord> {
+    root: UniquePointer<Node<T>>,
+    len: usize,
+}
+
+impl<T: Pointee + Ord> ThreadedTree<T> {
+    pub fn new() -> ThreadedTree<T> {
+        ThreadedTree {
+            root: UniquePointer::null(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// inserts `value` unless already present and returns true if it
+    /// was inserted
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut node = match self.root.as_mut() {
+            Some(node) => node,
+            None => {
+                self.root = UniquePointer::from(Node::new(
+                    value,
+                    UniquePointer::null(),
+                    UniquePointer::null(),
+                ));
+                self.len += 1;
+                return true;
+            }
+        };
+        loop {
+            match value.cmp(&node.value) {
+                Ordering::Equal => return false,
+                Ordering::Less if node.left_thread => {
+                    let predecessor = std::mem::replace(&mut node.left, UniquePointer::null());
+                    let successor = UniquePointer::read_only(node);
+                    node.left = UniquePointer::from(Node::new(value, predecessor, successor));
+                    node.left_thread = false;
+                    break;
+                }
+                Ordering::Greater if node.right_thread => {
+                    let successor = std::mem::replace(&mut node.right, UniquePointer::null());
+                    let predecessor = UniquePointer::read_only(node);
+                    node.right = UniquePointer::from(Node::new(value, predecessor, successor));
+                    node.right_thread = false;
+                    break;
+                }
+                Ordering::Less => match node.left.as_mut() {
+                    Some(left) => node = left,
+                    None => return false,
+                },
+                Ordering::Greater => match node.right.as_mut() {
+                    Some(right) => node = right,
+                    None => return false,
+                },
+            }
+        }
+        self.len += 1;
+        true
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.find(value).is_some()
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.root.as_ref().map(|node| &node.leftmost().value)
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.root.as_ref().map(|node| &node.rightmost().value)
+    }
+
+    /// returns the value following `value`, which must be present,
+    /// by following a single thread or descending one subtree
+    pub fn successor(&self, value: &T) -> Option<&T> {
+        self.find(value)?.successor().map(|node| &node.value)
+    }
+
+    /// returns the value preceding `value`, which must be present
+    pub fn predecessor(&self, value: &T) -> Option<&T> {
+        self.find(value)?.predecessor().map(|node| &node.value)
+    }
+
+    /// returns a double-ended iterator over the values in ascending
+    /// order which holds no more than the nodes at either end
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            front: self.root.as_ref().map(|node| node.leftmost()),
+            back: self.root.as_ref().map(|node| node.rightmost()),
+            len: self.len,
+        }
+    }
+
+    /// returns the first pair of consecutive values of the threaded
+    /// traversal that are not in strictly ascending order, which only
+    /// happens when the ordering of values changes while in the tree
+    pub fn is_bst(&self) -> Result<(), (&T, &T)> {
+        let mut pairs = self.iter().zip(self.iter().skip(1));
+        match pairs.find(|(previous, value)| previous >= value) {
+            Some(pair) => Err(pair),
+            None => Ok(()),
+        }
+    }
+
+    /// [`is_bst`](Self::is_bst) returning [`Error::Invariant`] at the
+    /// address of the second value of the offending pair, in the
+    /// context of the tree
+    pub fn validate(&self) -> crate::Result<()> {
+        self.is_bst().map_err(|(_, value)| {
+            Error::Invariant {
+                addr: std::ptr::from_ref(value).addr(),
+                invariant: "values in strictly ascending order",
+            }
+            .context::<ThreadedTree<T>>("validate", std::ptr::from_ref(self).addr())
+        })
+    }
+
+    /// moves the values out in ascending order, leaving the tree
+    /// empty, each node being deallocated as its value is yielded
+    pub fn drain(&mut self) -> IntoIter<T> {
+        std::mem::take(self).into_iter()
+    }
+
+    /// empties the tree in ascending order, passing each value to
+    /// `visitor` right before dropping it
+    pub fn drop_with(&mut self, mut visitor: impl FnMut(&mut T)) {
+        for mut value in self.drain() {
+            visitor(&mut value);
+        }
+    }
+
+    fn find(&self, value: &T) -> Option<&Node<T>> {
+        let mut node = self.root.as_ref()?;
+        loop {
+            node = match value.cmp(&node.value) {
+                Ordering::Equal => return Some(node),
+                Ordering::Less if node.left_thread => return None,
+                Ordering::Greater if node.right_thread => return None,
+                Ordering::Less => node.left.as_ref()?,
+                Ordering::Greater => node.right.as_ref()?,
+            };
+        }
+    }
+}
+
+/// follows the owning links only, the threads are merely forgotten
+impl<T: Pointee + Ord> Drop for ThreadedTree<T> {
+    fn drop(&mut self) {
+        let mut pending = vec![std::mem::replace(&mut self.root, UniquePointer::null())];
+        while let Some(mut pointer) = pending.pop() {
+            if let Some(node) = pointer.take() {
+                if !node.left_thread {
+                    pending.push(node.left);
+                }
+                if !node.right_thread {
+                    pending.push(node.right);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Pointee + Ord> Default for ThreadedTree<T> {
+    fn default() -> ThreadedTree<T> {
+        ThreadedTree::new()
+    }
+}
+
+impl<T: Pointee + Ord> FromIterator<T> for ThreadedTree<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> ThreadedTree<T> {
+        let mut tree = ThreadedTree::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<T: Pointee + Ord> Extend<T> for ThreadedTree<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: Pointee + Ord> IntoIterator for ThreadedTree<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// moves the values out of the tree in ascending order,
+    /// deallocating each node as its value is yielded
+    fn into_iter(mut self) -> IntoIter<T> {
+        IntoIter {
+            root: std::mem::replace(&mut self.root, UniquePointer::null()),
+        }
+    }
+}
+
+impl<'a, T: Pointee + Ord> IntoIterator for &'a ThreadedTree<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T: Pointee + Ord + Debug> Debug for ThreadedTree<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// `Iter` iterates over the values of a [`ThreadedTree`] by
+/// following its threads.
+pub struct Iter<'a, T: Pointee> {
+    front: Option<&'a Node<T>>,
+    back: Option<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T: Pointee> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.front?;
+        self.front = node.successor();
+        if let Some(next) = self.front {
+            next.right.prefetch_read();
+        }
+        self.len -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T: Pointee> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.back?;
+        self.back = node.predecessor();
+        if let Some(previous) = self.back {
+            previous.left.prefetch_read();
+        }
+        self.len -= 1;
+        Some(&node.value)
+    }
+}
+
+impl<T: Pointee> ExactSizeIterator for Iter<'_, T> {}
+
+/// `IntoIter` moves the values out of a [`ThreadedTree`] in ascending
+/// order by rotating the minimum up to the root and unlinking it,
+/// discarding the threads on the way.
+pub struct IntoIter<T: Pointee> {
+    root: UniquePointer<Node<T>>,
+}
+
+impl<T: Pointee> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            let node = self.root.as_mut()?;
+            let mut left = std::mem::replace(&mut node.left, UniquePointer::null());
+            let is_thread = std::mem::replace(&mut node.left_thread, true);
+            match left.as_mut() {
+                Some(child) if !is_thread => {
+                    if !child.right_thread {
+                        node.left = std::mem::replace(&mut child.right, UniquePointer::null());
+                        node.left_thread = false;
+                    }
+                    child.right = std::mem::replace(&mut self.root, left);
+                    child.right_thread = false;
+                }
+                _ => {
+                    let node = self.root.take()?;
+                    self.root = if node.right_thread {
+                        UniquePointer::null()
+                    } else {
+                        node.right
+                    };
+                    return Some(node.value);
+                }
+            }
+        }
+    }
+}
+
+/// drops the values that were not iterated over
+impl<T: Pointee> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
@@ -0,0 +1,150 @@
+use crate::UniquePointer;
+
+#[derive(Debug)]
+struct Node {
+    index: usize,
+    rank: usize,
+    parent: UniquePointer<Node>,
+}
+
+/// [UnionFind](Self) is a disjoint-set forest over the elements
+/// `0..len()` in which every element points to its parent through a
+/// read-only [`UniquePointer`](crate::UniquePointer) and roots have
+/// a NULL parent.
+///
+/// [`find`](Self::find) compresses the path it walks such that every
+/// element on it points directly to the root, and
+/// [`union`](Self::union) attaches the root of lower rank below the
+/// root of higher rank.
+///
+/// ```
+/// use unique_pointer::collections::UnionFind;
+///
+/// let mut sets = UnionFind::new(4);
+/// sets.union(0, 1);
+/// sets.union(2, 3);
+/// assert!(sets.same_set(0, 1));
+/// assert!(!sets.same_set(1, 2));
+///
+/// sets.union(1, 3);
+/// assert!(sets.same_set(0, 2));
+/// assert_eq!(sets.sets(), 1);
+/// ```
+pub struct UnionFind {
+    nodes: Vec<UniquePointer<Node>>,
+    sets: usize,
+}
+
+impl UnionFind {
+    /// creates `len` singleton sets
+    pub fn new(len: usize) -> UnionFind {
+        let mut union_find = UnionFind {
+            nodes: Vec::with_capacity(len),
+            sets: 0,
+        };
+        for _ in 0..len {
+            union_find.make_set();
+        }
+        union_find
+    }
+
+    /// adds a singleton set and returns its element
+    pub fn make_set(&mut self) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(UniquePointer::from(Node {
+            index,
+            rank: 0,
+            parent: UniquePointer::null(),
+        }));
+        self.sets += 1;
+        index
+    }
+
+    /// returns the number of elements
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// returns the number of disjoint sets
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+
+    /// returns the parent of `x` or `None` if `x` is a root
+    pub fn parent(&self, x: usize) -> Option<usize> {
+        self.nodes[x]
+            .as_ref()?
+            .parent
+            .as_ref()
+            .map(|parent| parent.index)
+    }
+
+    /// returns the representative of the set of `x`. Panics if `x`
+    /// is out of bounds.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = &self.nodes[x];
+        while let Some(parent) = root.as_ref().map(|node| &node.parent) {
+            if parent.is_null() {
+                break;
+            }
+            root = parent;
+        }
+        let root = match root.as_ref() {
+            Some(root) => root,
+            None => return x,
+        };
+
+        let mut node = self.nodes[x].as_mut();
+        while let Some(current) = node {
+            if current.parent.is_null() {
+                break;
+            }
+            let next = current.parent.as_mut();
+            current.parent = UniquePointer::read_only(root);
+            node = next;
+        }
+        root.index
+    }
+
+    /// merges the sets of `x` and `y`, returns false if they were
+    /// already the same set
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (x, y) = (self.find(x), self.find(y));
+        if x == y {
+            return false;
+        }
+        let (rank_x, rank_y) = (self.rank(x), self.rank(y));
+        let (root, child) = if rank_x < rank_y { (y, x) } else { (x, y) };
+        if let (Some(root), Some(child)) = (self.nodes[root].as_mut(), self.nodes[child].as_mut()) {
+            child.parent = UniquePointer::read_only(root);
+            if rank_x == rank_y {
+                root.rank += 1;
+            }
+        }
+        self.sets -= 1;
+        true
+    }
+
+    /// returns true if `x` and `y` belong to the same set
+    pub fn same_set(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    fn rank(&self, x: usize) -> usize {
+        self.nodes[x].as_ref().map(|node| node.rank).unwrap_or(0)
+    }
+}
+
+/// the parents being read-only copies, every node is owned by `nodes`
+/// alone
+impl Drop for UnionFind {
+    fn drop(&mut self) {
+        for node in self.nodes.iter_mut() {
+            node.take();
+        }
+    }
+}
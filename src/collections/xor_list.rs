@@ -0,0 +1,367 @@
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+use crate::unique_pointer::prefetch;
+use crate::{Pointee, UniquePointer};
+
+/// nodes know neither their predecessor nor their successor, only
+/// the exclusive-or of both addresses, NULL counting as zero
+#[derive(Debug)]
+struct Node<T: Pointee> {
+    value: T,
+    link: usize,
+}
+
+/// returns a read-only [`UniquePointer`] to the node at `addr`, which
+/// must have been returned by [`alloc`], or NULL if `addr` is zero
+fn pointer<T: Pointee>(addr: usize) -> UniquePointer<Node<T>> {
+    if addr == 0 {
+        return UniquePointer::null();
+    }
+    UniquePointer::copy_from_mut_ptr(std::ptr::with_exposed_provenance_mut(addr), 1)
+}
+
+/// allocates `node` and returns its address, the owning
+/// [`UniquePointer`] is turned into a raw pointer given that from now
+/// on the node is only reachable by combining the addresses of its
+/// neighbours
+fn alloc<T: Pointee>(node: Node<T>) -> usize {
+    UniquePointer::provenance_of_mut_ptr(UniquePointer::from(node).into_raw())
+}
+
+/// moves the node at `addr`, which must have been returned by
+/// [`alloc`] and must no longer be linked, out of its memory and
+/// deallocates it
+fn dealloc<T: Pointee>(addr: usize) -> Option<Node<T>> {
+    unsafe { UniquePointer::<Node<T>>::from_raw(std::ptr::with_exposed_provenance_mut(addr)) }.take()
+}
+
+/// [XorList](Self) is a doubly-linked list whose nodes store the
+/// exclusive-or of the addresses of their neighbours in a single
+/// word, so that walking from either end recovers the next address
+/// from the one just left behind: `next = link ^ previous`.
+///
+/// As a consequence the list is reversed in `O(1)` by swapping its
+/// ends.
+///
+/// ```
+/// use unique_pointer::collections::XorList;
+///
+/// let mut list = XorList::new();
+/// list.push_back(2);
+/// list.push_back(3);
+/// list.push_front(1);
+/// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+/// list.reverse();
+/// assert_eq!(list.pop_front(), Some(3));
+/// assert_eq!(list.pop_back(), Some(1));
+/// assert_eq!(list.len(), 1);
+/// ```
+pub struct XorList<T: Pointee> {
+    head: usize,
+    tail: usize,
+    len: usize,
+    nodes: PhantomData<Node<T>>,
+}
+
+impl<T: Pointee> XorList<T> {
+    pub fn new() -> XorList<T> {
+        XorList {
+            head: 0,
+            tail: 0,
+            len: 0,
+            nodes: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        pointer::<T>(self.head).as_ref().map(|node| &node.value)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        pointer::<T>(self.tail).as_ref().map(|node| &node.value)
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        pointer::<T>(self.head).as_mut().map(|node| &mut node.value)
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        pointer::<T>(self.tail).as_mut().map(|node| &mut node.value)
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        XorList::push(&mut self.head, &mut self.tail, value);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        XorList::push(&mut self.tail, &mut self.head, value);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let value = XorList::pop(&mut self.head, &mut self.tail)?;
+        self.len -= 1;
+        Some(value)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let value = XorList::pop(&mut self.tail, &mut self.head)?;
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// reverses the list by swapping its ends, the links of the
+    /// nodes read the same in either direction
+    pub fn reverse(&mut self) {
+        std::mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// returns a double-ended iterator from the front to the back
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            front: (0, self.head),
+            back: (0, self.tail),
+            len: self.len,
+            list: PhantomData,
+        }
+    }
+
+    /// returns a double-ended iterator from the front to the back
+    /// with mutable references to the values
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            front: (0, self.head),
+            back: (0, self.tail),
+            len: self.len,
+            list: PhantomData,
+        }
+    }
+
+    /// moves the values out from the front to the back, leaving the
+    /// list empty, each node being deallocated as its value is
+    /// yielded
+    pub fn drain(&mut self) -> IntoIter<T> {
+        std::mem::take(self).into_iter()
+    }
+
+    /// empties the list from the front to the back, passing each
+    /// value to `visitor` right before dropping it, e.g. to release
+    /// the resources it holds or to persist it
+    pub fn drop_with(&mut self, mut visitor: impl FnMut(&mut T)) {
+        for mut value in self.drain() {
+            visitor(&mut value);
+        }
+    }
+
+    /// links a new node at `end`, the opposite end being `other`
+    fn push(end: &mut usize, other: &mut usize, value: T) {
+        let addr = alloc(Node { value, link: *end });
+        match pointer::<T>(*end).as_mut() {
+            Some(node) => node.link ^= addr,
+            None => *other = addr,
+        }
+        *end = addr;
+    }
+
+    /// unlinks the node at `end`, the opposite end being `other`
+    fn pop(end: &mut usize, other: &mut usize) -> Option<T> {
+        let node = dealloc::<T>(*end)?;
+        match pointer::<T>(node.link).as_mut() {
+            Some(neighbour) => neighbour.link ^= *end,
+            None => *other = 0,
+        }
+        *end = node.link;
+        Some(node.value)
+    }
+}
+
+impl<T: Pointee> Drop for XorList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T: Pointee> Default for XorList<T> {
+    fn default() -> XorList<T> {
+        XorList::new()
+    }
+}
+
+impl<T: Pointee> FromIterator<T> for XorList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> XorList<T> {
+        let mut list = XorList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: Pointee> Extend<T> for XorList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T: Pointee> IntoIterator for XorList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// moves the values out of the list from the front to the back,
+    /// deallocating each node as its value is yielded
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T: Pointee> IntoIterator for &'a XorList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T: Pointee> IntoIterator for &'a mut XorList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T: Pointee + Debug> Debug for XorList<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// `Iter` walks a [`XorList`] from either end by keeping the
+/// addresses of the node it is at and of the one it came from.
+pub struct Iter<'a, T: Pointee> {
+    front: (usize, usize),
+    back: (usize, usize),
+    len: usize,
+    list: PhantomData<&'a XorList<T>>,
+}
+
+impl<'a, T: Pointee> Iter<'a, T> {
+    fn step(position: &mut (usize, usize)) -> Option<&'a T> {
+        let (previous, current) = *position;
+        let node = pointer::<T>(current).as_ref()?;
+        *position = (current, node.link ^ previous);
+        prefetch(std::ptr::with_exposed_provenance::<Node<T>>(position.1), false);
+        Some(&node.value)
+    }
+}
+
+impl<'a, T: Pointee> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Iter::step(&mut self.front)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T: Pointee> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Iter::step(&mut self.back)
+    }
+}
+
+impl<T: Pointee> ExactSizeIterator for Iter<'_, T> {}
+
+/// `IterMut` walks a [`XorList`] from either end just like [`Iter`].
+pub struct IterMut<'a, T: Pointee> {
+    front: (usize, usize),
+    back: (usize, usize),
+    len: usize,
+    list: PhantomData<&'a mut XorList<T>>,
+}
+
+impl<'a, T: Pointee> IterMut<'a, T> {
+    fn step(position: &mut (usize, usize)) -> Option<&'a mut T> {
+        let (previous, current) = *position;
+        let node = pointer::<T>(current).as_mut()?;
+        *position = (current, node.link ^ previous);
+        prefetch(std::ptr::with_exposed_provenance::<Node<T>>(position.1), false);
+        Some(&mut node.value)
+    }
+}
+
+impl<'a, T: Pointee> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        IterMut::step(&mut self.front)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T: Pointee> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        IterMut::step(&mut self.back)
+    }
+}
+
+impl<T: Pointee> ExactSizeIterator for IterMut<'_, T> {}
+
+/// `IntoIter` moves the values out of a [`XorList`] from either end.
+pub struct IntoIter<T: Pointee> {
+    list: XorList<T>,
+}
+
+impl<T: Pointee> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T: Pointee> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T: Pointee> ExactSizeIterator for IntoIter<T> {}
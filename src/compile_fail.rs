@@ -0,0 +1,106 @@
+//! Misuse of the API that must not compile, codified as
+//! `compile_fail` doctests such that changes which loosen the
+//! type-level guarantees the design relies on fail the test-suite.
+//!
+//! ### `UniquePointer` cannot be sent across threads
+//!
+//! ```compile_fail,E0277
+//! use unique_pointer::UniquePointer;
+//!
+//! let pointer = UniquePointer::from(1u8);
+//! std::thread::spawn(move || pointer.try_read());
+//! ```
+//!
+//! ### `UniquePointer` cannot be shared across threads
+//!
+//! ```compile_fail,E0277
+//! use unique_pointer::UniquePointer;
+//!
+//! fn assert_sync<T: Sync>(_: &T) {}
+//! assert_sync(&UniquePointer::from(1u8));
+//! ```
+//!
+//! ### `RefCounter` cannot be sent across threads
+//!
+//! ```compile_fail,E0277
+//! use unique_pointer::RefCounter;
+//!
+//! let refs = RefCounter::new();
+//! std::thread::spawn(move || refs.read());
+//! ```
+//!
+//! ### `propagate` requires `unsafe`
+//!
+//! ```compile_fail,E0133
+//! use unique_pointer::UniquePointer;
+//!
+//! let pointer = UniquePointer::from(1u8);
+//! let copy = pointer.propagate();
+//! ```
+//!
+//! ### `TestPointer` guards cannot outlive `drop_in_place`
+//!
+//! ```compile_fail,E0502
+//! use unique_pointer::TestPointer;
+//!
+//! let mut pointer = TestPointer::from(1u8);
+//! let guard = pointer.inner_ref();
+//! pointer.drop_in_place();
+//! assert_eq!(*guard, 1);
+//! ```
+//!
+//! ### `TestPointer` cannot lend two mutable guards at once
+//!
+//! ```compile_fail,E0499
+//! use unique_pointer::TestPointer;
+//!
+//! let mut pointer = TestPointer::from(1u8);
+//! let first = pointer.inner_mut();
+//! let second = pointer.inner_mut();
+//! *first += *second;
+//! ```
+//!
+//! ### `TestPointer` cannot be sent across threads
+//!
+//! ```compile_fail,E0277
+//! use unique_pointer::TestPointer;
+//!
+//! let pointer = TestPointer::from(1u8);
+//! std::thread::spawn(move || pointer.is_null());
+//! ```
+//!
+//! ### Slice views cannot outlive the pointer
+//!
+//! ```compile_fail,E0597
+//! use unique_pointer::UniquePointer;
+//!
+//! let slice = {
+//!     let pointer = UniquePointer::from(vec![1u8]);
+//!     pointer.as_slice()
+//! };
+//! assert_eq!(slice, &[1]);
+//! ```
+//!
+//! ### `DebugGraph` cannot outlive the pointer
+//!
+//! ```compile_fail,E0597
+//! use std::fmt::Formatter;
+//! use unique_pointer::{GraphNode, UniquePointer};
+//!
+//! #[derive(Debug)]
+//! struct Node(UniquePointer<Node>);
+//! impl GraphNode for Node {
+//!     fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+//!         vec![("next", &self.0)]
+//!     }
+//!     fn label(&self, f: &mut Formatter) -> std::fmt::Result {
+//!         write!(f, "node")
+//!     }
+//! }
+//!
+//! let graph = {
+//!     let pointer = UniquePointer::from(Node(UniquePointer::null()));
+//!     pointer.debug_graph(1)
+//! };
+//! println!("{:?}", graph);
+//! ```
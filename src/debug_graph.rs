@@ -0,0 +1,69 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::{GraphNode, UniquePointer};
+
+/// [DebugGraph](Self) is the formatting wrapper returned by
+/// [`UniquePointer::debug_graph`] which renders the subgraph
+/// reachable from a pointer up to `depth` edges away, naming the
+/// address and reference count of each node.
+///
+/// Edges leading back to a node of the path being rendered are
+/// rendered as `<cycle ADDR>`, edges beyond `depth` as `...` and NULL
+/// edges as `NULL`. `{:?}` renders the subgraph in a single line
+/// whereas `{:#?}` renders one node per line, indented by depth.
+pub struct DebugGraph<'p, T: GraphNode> {
+    pointer: &'p UniquePointer<T>,
+    depth: usize,
+}
+
+impl<'p, T: GraphNode> DebugGraph<'p, T> {
+    pub fn new(pointer: &'p UniquePointer<T>, depth: usize) -> DebugGraph<'p, T> {
+        DebugGraph { pointer, depth }
+    }
+
+    fn fmt_node(
+        pointer: &UniquePointer<T>,
+        depth: usize,
+        path: &mut Vec<usize>,
+        f: &mut Formatter,
+    ) -> std::fmt::Result {
+        let node = match pointer.as_ref() {
+            Some(node) => node,
+            None => return write!(f, "NULL"),
+        };
+        if path.contains(&pointer.addr()) {
+            return write!(f, "<cycle {:016x}>", pointer.addr());
+        }
+        write!(f, "{:016x}[refs={}] ", pointer.addr(), pointer.refs())?;
+        node.label(f)?;
+        let edges = node.edges();
+        if edges.is_empty() {
+            return Ok(());
+        }
+        if depth == 0 {
+            return write!(f, " {{...}}");
+        }
+        path.push(pointer.addr());
+        write!(f, " {{")?;
+        for (index, (name, edge)) in edges.into_iter().enumerate() {
+            if f.alternate() {
+                write!(f, "\n{}{}: ", "    ".repeat(path.len()), name)?;
+            } else {
+                write!(f, "{}{}: ", if index > 0 { ", " } else { "" }, name)?;
+            }
+            DebugGraph::fmt_node(edge, depth - 1, path, f)?;
+        }
+        path.pop();
+        if f.alternate() {
+            write!(f, "\n{}}}", "    ".repeat(path.len()))
+        } else {
+            write!(f, "}}")
+        }
+    }
+}
+
+impl<T: GraphNode> Debug for DebugGraph<'_, T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        DebugGraph::fmt_node(self.pointer, self.depth, &mut Vec::new(), f)
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::GraphNode;
+
+/// runs `body` with a [DeferScope] whose queued deallocations run
+/// once `body` returns or unwinds, children before parents.
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::fmt::Formatter;
+/// use unique_pointer::{defer_scope, GraphNode, UniquePointer};
+///
+/// #[derive(Debug)]
+/// struct Node {
+///     value: char,
+///     parent: UniquePointer<Node>,
+///     child: UniquePointer<Node>,
+/// }
+///
+/// impl GraphNode for Node {
+///     fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+///         vec![("parent", &self.parent), ("child", &self.child)]
+///     }
+///
+///     fn children(&self) -> Vec<&UniquePointer<Node>> {
+///         vec![&self.child]
+///     }
+///
+///     fn label(&self, f: &mut Formatter) -> std::fmt::Result {
+///         write!(f, "{}", self.value)
+///     }
+/// }
+///
+/// let mut parent = Node { value: 'P', parent: UniquePointer::null(), child: UniquePointer::null() };
+/// let mut child = Node { value: 'C', parent: UniquePointer::read_only(&parent), child: UniquePointer::null() };
+/// parent.child = UniquePointer::read_only(&child);
+///
+/// let order = RefCell::new(Vec::new());
+/// defer_scope(|scope| {
+///     scope.dealloc(&mut parent, |node| order.borrow_mut().push(node.value));
+///     scope.dealloc(&mut child, |node| order.borrow_mut().push(node.value));
+/// });
+/// assert_eq!(order.into_inner(), vec!['C', 'P']);
+/// ```
+pub fn defer_scope<'s, T: GraphNode + 's, R>(body: impl FnOnce(&mut DeferScope<'s, T>) -> R) -> R {
+    let mut scope = DeferScope { queue: Vec::new() };
+    body(&mut scope)
+}
+
+/// [DeferScope](Self) queues deallocations requested within
+/// [`defer_scope`] and runs them when dropped, ordered such that
+/// every node is deallocated before the queued nodes it is reachable
+/// from through [`GraphNode::children`], regardless of the order in
+/// which they were queued.
+pub struct DeferScope<'s, T: GraphNode + 's> {
+    queue: Vec<Option<Deferred<'s, T>>>,
+}
+
+type Deferred<'s, T> = (&'s mut T, Box<dyn FnOnce(&mut T) + 's>);
+
+impl<'s, T: GraphNode + 's> DeferScope<'s, T> {
+    /// queues `dealloc` to be called upon `node` at scope exit
+    pub fn dealloc(&mut self, node: &'s mut T, dealloc: impl FnOnce(&mut T) + 's) {
+        self.queue.push(Some((node, Box::new(dealloc))));
+    }
+
+    /// returns the number of deallocations queued so far
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// returns the indexes of the queue in post-order of the
+    /// depth-first traversal of the children of the queued nodes
+    fn order(&self) -> Vec<usize> {
+        let mut queued = BTreeMap::<usize, Vec<usize>>::new();
+        for (index, (node, _)) in self.queue.iter().flatten().enumerate() {
+            queued.entry(address::<T>(node)).or_default().push(index);
+        }
+        let mut visited = BTreeSet::new();
+        let mut order = Vec::with_capacity(self.queue.len());
+        for (node, _) in self.queue.iter().flatten() {
+            let node: &T = node;
+            if !visited.insert(address(node)) {
+                continue;
+            }
+            let mut stack = vec![(node, node.children().into_iter())];
+            while let Some((node, children)) = stack.last_mut() {
+                match children.next() {
+                    Some(child) => {
+                        if let Some(child) = child.as_ref()
+                            && visited.insert(address(child))
+                        {
+                            stack.push((child, child.children().into_iter()));
+                        }
+                    }
+                    None => {
+                        let address = address::<T>(node);
+                        stack.pop();
+                        order.extend(queued.get(&address).into_iter().flatten());
+                    }
+                }
+            }
+        }
+        order
+    }
+}
+
+/// runs the queued deallocations
+impl<T: GraphNode> Drop for DeferScope<'_, T> {
+    fn drop(&mut self) {
+        for index in self.order() {
+            if let Some((node, dealloc)) = self.queue[index].take() {
+                dealloc(node);
+            }
+        }
+    }
+}
+
+fn address<T: GraphNode>(node: &T) -> usize {
+    std::ptr::from_ref(node).addr()
+}
@@ -0,0 +1,135 @@
+use std::fmt::{Display, Formatter};
+
+/// [Error](Self) enumerates the reasons the fallible `try_*` methods
+/// of [`UniquePointer`](crate::UniquePointer), [`RefCounter`](crate::RefCounter)
+/// and the [collections](crate::collections) fail instead of
+/// panicking like their unchecked counterparts.
+///
+/// Errors may be wrapped via [`context`](Self::context) into the
+/// operation that failed because of them, the type it was applied to
+/// and the address it was applied at, any number of times, the
+/// resulting chain being walked via [`chain`](Self::chain) or
+/// [`std::error::Error::source`].
+///
+/// ```
+/// use unique_pointer::{Error, UniquePointer};
+///
+/// let error = Error::NullPointer
+///     .context::<u64>("read", 0)
+///     .context::<UniquePointer<u64>>("clone_from", 0x10);
+/// assert_eq!(error.root_cause(), &Error::NullPointer);
+/// assert_eq!(error.chain().count(), 3);
+/// assert_eq!(
+///     error.to_string(),
+///     "clone_from unique_pointer::unique_pointer::UniquePointer<u64> at 0000000000000010: \
+///      read u64 at 0000000000000000: NULL POINTER"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// the pointer was never allocated
+    NullPointer,
+    /// the pointer at `addr` was allocated but never written to
+    NotWritten { addr: usize },
+    /// the memory at `addr` is shared by `refs` clones
+    Shared { addr: usize, refs: usize },
+    /// the memory at `addr` was frozen
+    Frozen { addr: usize },
+    /// the memory at `addr` was pinned and its value cannot move
+    Pinned { addr: usize },
+    /// the count of `refs` at `addr` cannot be decremented `by`
+    /// without going below zero
+    Underflow { addr: usize, refs: usize, by: usize },
+    /// the value at `addr` was left in an unspecified state by a
+    /// panic unwinding while it was being modified
+    Poisoned { addr: usize },
+    /// the value at `addr` breaks the `invariant` of the structure
+    /// holding it
+    Invariant {
+        addr: usize,
+        invariant: &'static str,
+    },
+    /// `size` bytes aligned to `align` could not be allocated
+    AllocationFailed { size: usize, align: usize },
+    /// `operation` on the `type_name` at `addr` failed because of
+    /// `source`
+    Context {
+        operation: &'static str,
+        type_name: &'static str,
+        addr: usize,
+        source: Box<Error>,
+    },
+}
+
+impl Error {
+    /// wraps `self` as the reason `operation` on the `T` at `addr`
+    /// failed
+    pub fn context<T: ?Sized>(self, operation: &'static str, addr: usize) -> Error {
+        Error::Context {
+            operation,
+            type_name: std::any::type_name::<T>(),
+            addr,
+            source: Box::new(self),
+        }
+    }
+
+    /// returns the error that caused `self` along with every error it
+    /// was wrapped in, the outermost first
+    pub fn chain(&self) -> impl Iterator<Item = &Error> {
+        std::iter::successors(Some(self), |error| match error {
+            Error::Context { source, .. } => Some(source),
+            _ => None,
+        })
+    }
+
+    /// returns the innermost error of the chain, which is `self`
+    /// unless it was wrapped via [`context`](Self::context)
+    pub fn root_cause(&self) -> &Error {
+        self.chain().last().unwrap_or(self)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Error::NullPointer => write!(f, "NULL POINTER"),
+            Error::NotWritten { addr } => write!(f, "{:016x} not written", addr),
+            Error::Shared { addr, refs } => {
+                write!(f, "{:016x} shared by {} clones", addr, refs)
+            }
+            Error::Frozen { addr } => write!(f, "{:016x} frozen", addr),
+            Error::Pinned { addr } => write!(f, "{:016x} pinned", addr),
+            Error::Underflow { addr, refs, by } => {
+                write!(f, "{:016x} has {} refs, cannot drop {}", addr, refs, by)
+            }
+            Error::Poisoned { addr } => write!(f, "{:016x} poisoned", addr),
+            Error::Invariant { addr, invariant } => {
+                write!(f, "{:016x} breaks invariant: {}", addr, invariant)
+            }
+            Error::AllocationFailed { size, align } => {
+                write!(f, "failed to allocate {} bytes aligned to {}", size, align)
+            }
+            Error::Context {
+                operation,
+                type_name,
+                addr,
+                source,
+            } => write!(
+                f,
+                "{} {} at {:016x}: {}",
+                operation, type_name, addr, source
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,181 @@
+use std::alloc::Layout;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{GraphNode, UniquePointer};
+
+/// frees every node reachable from `roots` through
+/// [`GraphNode::edges`] exactly once, regardless of reference
+/// counts, leaves `roots` NULL and returns the number of nodes freed.
+///
+/// Only nodes allocated by a `UniquePointer` are freed, nodes that
+/// are merely referenced through copies, such as those created via
+/// [`read_only`](UniquePointer::read_only) from values on the stack,
+/// are traversed but left untouched. Nodes allocated in a
+/// [`Group`](crate::group::Group) are dropped but their memory is
+/// left to the group.
+///
+/// Nodes are dropped in topological order, children before parents
+/// as told by [`GraphNode::children`], and their memory is only
+/// deallocated once every node has been dropped, such that
+/// [`Drop`] implementations may still read the pointers of the nodes
+/// they link to.
+///
+/// # Safety
+///
+/// Every pointer to the freed nodes other than `roots` dangles
+/// afterwards and must not be dereferenced, and the [`Drop`]
+/// implementation of `T` must not free any node of the graph itself.
+///
+#[cfg_attr(not(feature = "no-panic"), doc = "```")]
+#[cfg_attr(feature = "no-panic", doc = "```ignore")]
+/// use std::fmt::Formatter;
+/// use unique_pointer::{free_graph, GraphNode, UniquePointer};
+///
+/// #[derive(Debug)]
+/// struct Node {
+///     value: String,
+///     parent: UniquePointer<Node>,
+///     child: UniquePointer<Node>,
+/// }
+///
+/// impl GraphNode for Node {
+///     fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+///         vec![("parent", &self.parent), ("child", &self.child)]
+///     }
+///
+///     fn label(&self, f: &mut Formatter) -> std::fmt::Result {
+///         write!(f, "{}", self.value)
+///     }
+/// }
+///
+/// let mut root = UniquePointer::from(Node {
+///     value: String::from("root"),
+///     parent: UniquePointer::null(),
+///     child: UniquePointer::null(),
+/// });
+/// let mut child = UniquePointer::from(Node {
+///     value: String::from("child"),
+///     parent: root.clone(),
+///     child: UniquePointer::null(),
+/// });
+/// root.inner_mut().child = child.clone();
+///
+/// let mut roots = [root];
+/// assert_eq!(unsafe { free_graph(&mut roots) }, 2);
+/// assert!(roots[0].is_null());
+/// ```
+pub unsafe fn free_graph<T: GraphNode>(roots: &mut [UniquePointer<T>]) -> usize {
+    unsafe { free_graph_with(roots, |_| {}) }
+}
+
+/// frees the graph reachable from `roots` like [`free_graph`] but
+/// passes every node it frees to `visitor` right before dropping it,
+/// children before parents, e.g. to log them, release the resources
+/// they hold or persist them.
+///
+/// # Safety
+///
+/// See [`free_graph`], `visitor` must not free any node of the graph
+/// either.
+///
+/// ```
+/// use std::fmt::Formatter;
+/// use unique_pointer::{free_graph_with, GraphNode, UniquePointer};
+///
+/// #[derive(Debug)]
+/// struct Node {
+///     value: u32,
+///     next: UniquePointer<Node>,
+/// }
+///
+/// impl GraphNode for Node {
+///     fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+///         vec![("next", &self.next)]
+///     }
+///
+///     fn label(&self, f: &mut Formatter) -> std::fmt::Result {
+///         write!(f, "{}", self.value)
+///     }
+/// }
+///
+/// let tail = UniquePointer::from(Node { value: 2, next: UniquePointer::null() });
+/// let head = UniquePointer::from(Node { value: 1, next: tail });
+///
+/// let mut values = Vec::new();
+/// let mut roots = [head];
+/// let freed = unsafe { free_graph_with(&mut roots, |node| values.push(node.value)) };
+/// assert_eq!(freed, 2);
+/// assert_eq!(values, vec![2, 1]);
+/// ```
+pub unsafe fn free_graph_with<T: GraphNode>(
+    roots: &mut [UniquePointer<T>],
+    mut visitor: impl FnMut(&mut T),
+) -> usize {
+    let mut nodes = BTreeMap::<usize, &T>::new();
+    let mut owned = BTreeMap::new();
+    let mut pending = roots.iter().collect::<Vec<_>>();
+    while let Some(pointer) = pending.pop() {
+        let Some(node) = pointer.as_ref() else {
+            continue;
+        };
+        if pointer.can_dealloc() {
+            let layout = Layout::from_size_align(std::mem::size_of::<T>(), pointer.align())
+                .unwrap_or_else(|_| Layout::new::<T>());
+            let layout = pointer.group_id().is_none().then_some(layout);
+            owned.insert(pointer.addr(), layout);
+        }
+        if nodes.insert(pointer.addr(), node).is_none() {
+            pending.extend(node.edges().into_iter().map(|(_, edge)| edge));
+        }
+    }
+
+    let mut visited = BTreeSet::new();
+    let mut order = Vec::with_capacity(owned.len());
+    for (&addr, &node) in nodes.iter() {
+        if !visited.insert(addr) {
+            continue;
+        }
+        let mut stack = vec![(addr, node.children().into_iter())];
+        while let Some((addr, children)) = stack.last_mut() {
+            match children.next() {
+                Some(child) => {
+                    if let Some(node) = child.as_ref()
+                        && visited.insert(child.addr())
+                    {
+                        stack.push((child.addr(), node.children().into_iter()));
+                    }
+                }
+                None => {
+                    if owned.contains_key(addr) {
+                        order.push(*addr);
+                    }
+                    stack.pop();
+                }
+            }
+        }
+    }
+    drop(nodes);
+
+    for root in roots.iter_mut() {
+        *root = UniquePointer::null();
+    }
+    for &addr in order.iter() {
+        let node = std::ptr::with_exposed_provenance_mut::<T>(addr);
+        visitor(unsafe { &mut *node });
+        unsafe { std::ptr::drop_in_place(node) };
+        crate::pointer_map::released(addr);
+        crate::pinned::unpin(addr);
+        crate::frozen::thaw(addr);
+    }
+    if std::mem::size_of::<T>() == 0 {
+        return order.len();
+    }
+    for &addr in order.iter() {
+        // memory allocated in a group is released with the group
+        let Some(&Some(layout)) = owned.get(&addr) else {
+            continue;
+        };
+        unsafe { std::alloc::dealloc(std::ptr::with_exposed_provenance_mut(addr), layout) };
+    }
+    order.len()
+}
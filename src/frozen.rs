@@ -0,0 +1,38 @@
+//! The `frozen` module keeps track of the addresses frozen via
+//! [`UniquePointer::freeze`](crate::UniquePointer::freeze), such that
+//! every `UniquePointer` to a frozen address, be it a clone or a
+//! read-only copy, refuses mutable access.
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+
+thread_local! {
+    static FROZEN: RefCell<BTreeSet<usize>> = const { RefCell::new(BTreeSet::new()) };
+}
+
+/// freezes `addr` and returns false if it was already frozen
+pub(crate) fn freeze(addr: usize) -> bool {
+    addr != 0 && FROZEN.with(|frozen| frozen.borrow_mut().insert(addr))
+}
+
+/// returns true if `addr` is frozen, `extern "C"` such that it
+/// cannot unwind under the `no-panic` feature
+pub(crate) extern "C" fn is_frozen(addr: usize) -> bool {
+    addr != 0
+        && FROZEN
+            .try_with(|frozen| {
+                frozen
+                    .try_borrow()
+                    .is_ok_and(|frozen| frozen.contains(&addr))
+            })
+            .unwrap_or(false)
+}
+
+/// forgets `addr` once its memory is released or adopted anew, such
+/// that a later allocation at the same address starts out thawed
+pub(crate) fn thaw(addr: usize) {
+    let _ = FROZEN.try_with(|frozen| {
+        if let Ok(mut frozen) = frozen.try_borrow_mut() {
+            frozen.remove(&addr);
+        }
+    });
+}
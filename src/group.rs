@@ -0,0 +1,148 @@
+//! The `group` module implements [`Group`], a pool of contiguous
+//! chunks backing the memory of the `UniquePointer`s allocated via
+//! [`UniquePointer::alloc_in_group`](crate::UniquePointer::alloc_in_group).
+use std::alloc::Layout;
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// number of bytes of the first chunk of a [`Group`], each following
+/// chunk holding twice as many as the previous one
+const CHUNK: usize = 4096;
+
+static NEXT_GROUP_ID: AtomicU64 = AtomicU64::new(1);
+
+/// [Group](Self) hands out memory for the nodes of a structure from
+/// contiguous chunks, such that nodes allocated one after another
+/// end up next to each other rather than wherever the global
+/// allocator finds room, which keeps traversals on fewer cache lines.
+///
+/// Memory allocated in a group is never released on its own, not
+/// even by [`UniquePointer::take`](crate::UniquePointer::take), but
+/// all at once when the group is dropped, therefore a group must
+/// outlive every `UniquePointer` allocated in it.
+///
+/// ```
+/// use unique_pointer::group::Group;
+/// use unique_pointer::UniquePointer;
+///
+/// let group = Group::new();
+/// let mut a = UniquePointer::<u64>::null();
+/// let mut b = UniquePointer::<u64>::null();
+/// unsafe {
+///     a.alloc_in_group(&group);
+///     b.alloc_in_group(&group);
+/// }
+/// a.write(1);
+/// b.write(2);
+///
+/// assert_eq!(b.addr() - a.addr(), 8);
+/// assert_eq!(group.allocated(), 16);
+/// assert_eq!(a.group_id(), Some(group.id()));
+/// ```
+pub struct Group {
+    pub(crate) id: u64,
+    chunks: RefCell<Vec<Chunk>>,
+}
+
+struct Chunk {
+    ptr: *mut u8,
+    layout: Layout,
+    used: usize,
+}
+
+impl Chunk {
+    fn new(size: usize, align: usize) -> Chunk {
+        let layout = match Layout::from_size_align(size, align) {
+            Ok(layout) => layout,
+            Err(_) => std::alloc::handle_alloc_error(Layout::new::<u8>()),
+        };
+        let ptr = unsafe { std::alloc::alloc_zeroed(layout) };
+        if ptr.is_null() {
+            std::alloc::handle_alloc_error(layout);
+        }
+        Chunk {
+            ptr,
+            layout,
+            used: 0,
+        }
+    }
+
+    /// returns the offset at which `layout` fits in the chunk
+    fn offset_of(&self, layout: Layout) -> Option<usize> {
+        let start = self.ptr.addr() + self.used;
+        let offset = start.next_multiple_of(layout.align()) - self.ptr.addr();
+        (offset + layout.size() <= self.layout.size()).then_some(offset)
+    }
+}
+
+impl Group {
+    pub fn new() -> Group {
+        Group {
+            id: NEXT_GROUP_ID.fetch_add(1, Ordering::Relaxed),
+            chunks: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// returns the opaque id of the group, which is never reused
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// returns the number of bytes handed out so far, padding
+    /// included
+    pub fn allocated(&self) -> usize {
+        self.chunks.borrow().iter().map(|chunk| chunk.used).sum()
+    }
+
+    /// returns the number of chunks allocated so far
+    pub fn chunks(&self) -> usize {
+        self.chunks.borrow().len()
+    }
+
+    /// returns zeroed memory fitting `layout` right after the memory
+    /// handed out last, starting a new chunk when the current one is
+    /// full
+    pub(crate) fn alloc(&self, layout: Layout) -> *mut u8 {
+        let mut chunks = self.chunks.borrow_mut();
+        let fits = chunks.last().and_then(|chunk| chunk.offset_of(layout));
+        let offset = match fits {
+            Some(offset) => offset,
+            None => {
+                let size = chunks.last().map(|chunk| chunk.layout.size() * 2);
+                let size = size.unwrap_or(CHUNK).max(layout.size());
+                chunks.push(Chunk::new(size, layout.align().max(16)));
+                0
+            }
+        };
+        let chunk = chunks.last_mut().expect("a chunk has just been found");
+        chunk.used = offset + layout.size();
+        unsafe { chunk.ptr.add(offset) }
+    }
+}
+
+/// releases every chunk, hence the memory of every `UniquePointer`
+/// allocated in the group
+impl Drop for Group {
+    fn drop(&mut self) {
+        for chunk in self.chunks.get_mut().drain(..) {
+            unsafe { std::alloc::dealloc(chunk.ptr, chunk.layout) };
+        }
+    }
+}
+
+impl Default for Group {
+    fn default() -> Group {
+        Group::new()
+    }
+}
+
+impl Debug for Group {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_struct("Group")
+            .field("id", &self.id)
+            .field("chunks", &self.chunks())
+            .field("allocated", &self.allocated())
+            .finish()
+    }
+}
@@ -1,5 +1,6 @@
 #![allow(unused)]
 #![feature(intra_doc_pointers)]
+#![feature(allocator_api)]
 #![doc(issue_tracker_base_url = "https://github.com/gabrielfalcao/unique-pointer/issues/")]
 //! [UniquePointer] is an experimental data structure that makes
 //! extensive use of unsafe rust to provide a shared pointer
@@ -13,12 +14,41 @@
 //!
 //! > Permits using `UniquePointer<T>` where `T` does not implement `std::fmt::Debug`
 //!
+//! ### `alias-check`
+//!
+//! > Reports overlapping mutable borrows of the same address by
+//! > different `UniquePointer`s in debug builds, see `alias_check`
+//!
+//! ### `no-panic`
+//!
+//! > Removes every panicking method of `UniquePointer` along with its
+//! > `Deref`, `DerefMut`, `AsRef` and `AsMut` implementations, leaving
+//! > only the `try_*` methods which return [`Result`]
+//!
+//! ### `alloc-id`
+//!
+//! > Assigns a monotonically increasing id to each allocation, exposed
+//! > via `UniquePointer::alloc_id` and included in `Debug` output
+//!
+//! ### `clone-stats`
+//!
+//! > Records how many times `UniquePointer`s were cloned or propagated
+//! > and from which call sites, see `clone_stats`
+//!
+//! ### `rc-compat`
+//!
+//! > Adds `UniquePointer::new`, `strong_count`, `get_mut` and
+//! > `try_unwrap`, named after their `Rc` counterparts, along with
+//! > conversions from `Rc<T>` and `Arc<T>` and `try_into_rc` and
+//! > `try_into_arc` back, to ease migrating code from `Rc`
+//!
 //!
 //! # Binary Tree Example
 //!
 //! ### Binary Tree Implementation
 //!
-//! ```
+#![cfg_attr(not(feature = "no-panic"), doc = "```")]
+#![cfg_attr(feature = "no-panic", doc = "```ignore")]
 //! use unique_pointer::{RefCounter, UniquePointer};
 //!
 //! use std::borrow::Cow;
@@ -792,11 +822,18 @@
 //!
 //! ### Testing the Binary Tree
 //!
-//! ```
+#![cfg_attr(
+    not(any(feature = "no-panic", feature = "alias-check", feature = "clone-stats")),
+    doc = "```"
+)]
+#![cfg_attr(
+    any(feature = "no-panic", feature = "alias-check", feature = "clone-stats"),
+    doc = "```ignore"
+)]
 //! #  use std::borrow::Cow;
 //! #  use std::convert::{AsMut, AsRef};
 //! #  use std::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
-//! #  use unique_pointer::{UniquePointer, RefCounter};
+//! #  use unique_pointer::{UniquePointer, RefCounter, TeardownPlan};
 //! #  #[derive(Clone, PartialOrd, Ord, Default, PartialEq, Eq, Hash)]
 //! #  pub enum Value<'c> {
 //! #      #[default]
@@ -1824,12 +1861,20 @@
 //!         assert_eq!(tree.node_e.refs(), 2);
 //!         assert_eq!(tree.node_f.refs(), 2);
 //!
-//!         tree.node_a.dealloc();
-//!         tree.node_b.dealloc();
-//!         tree.node_c.dealloc();
-//!         tree.node_d.dealloc();
-//!         tree.node_e.dealloc();
-//!         tree.node_f.dealloc();
+//!         let torn_down = TeardownPlan::new()
+//!             .node('A', &mut tree.node_a)
+//!             .node('B', &mut tree.node_b)
+//!             .node('C', &mut tree.node_c)
+//!             .node('D', &mut tree.node_d)
+//!             .node('E', &mut tree.node_e)
+//!             .node('F', &mut tree.node_f)
+//!             .child('A', 'B')
+//!             .child('A', 'C')
+//!             .child('B', 'D')
+//!             .child('B', 'E')
+//!             .child('D', 'F')
+//!             .execute(Node::dealloc);
+//!         assert_eq!(torn_down, Ok(6));
 //!
 //!         unsafe { std::mem::transmute::<MitOpenCourseWare6006Tree, MitOpenCourseWare6006Tree<'t>>(tree) }
 //!     }
@@ -2089,7 +2134,8 @@
 //!
 //! ### Testing Node
 //!
-//! ```
+#![cfg_attr(not(feature = "no-panic"), doc = "```")]
+#![cfg_attr(feature = "no-panic", doc = "```ignore")]
 //! #  use std::borrow::Cow;
 //! #  use std::convert::{AsMut, AsRef};
 //! #  use std::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
@@ -3186,12 +3232,53 @@
 //!     }
 //! }
 //!
+pub mod errors;
+#[doc(inline)]
+pub use errors::{Error, Result};
 pub mod traits;
 #[doc(inline)]
-pub use traits::Pointee;
+pub use traits::{GraphNode, Pointee, PointerFamily, PointerLike};
 pub mod unique_pointer;
 #[doc(inline)]
-pub use unique_pointer::UniquePointer;
+pub use unique_pointer::{OnDrop, UniquePointer};
 pub mod refcounter;
 #[doc(inline)]
 pub use refcounter::RefCounter;
+pub mod adapters;
+#[doc(inline)]
+pub use adapters::{
+    BoxPointer, BoxPointerFamily, RcPointer, RcPointerFamily, UniquePointerFamily,
+};
+pub mod debug_graph;
+#[doc(inline)]
+pub use debug_graph::DebugGraph;
+pub mod defer;
+#[doc(inline)]
+pub use defer::{defer_scope, DeferScope};
+pub mod teardown;
+#[doc(inline)]
+pub use teardown::TeardownPlan;
+pub mod free_graph;
+#[doc(inline)]
+pub use free_graph::{free_graph, free_graph_with};
+pub mod audit;
+#[doc(inline)]
+pub use audit::audit;
+mod frozen;
+mod pinned;
+pub mod group;
+pub mod pointer_map;
+pub mod weak;
+#[doc(inline)]
+pub use weak::UniqueWeak;
+pub mod collections;
+pub mod persistent;
+pub mod test_pointer;
+#[doc(inline)]
+pub use test_pointer::{TestPointer, TestPointerFamily};
+#[cfg(feature = "alias-check")]
+pub mod alias_check;
+#[cfg(feature = "clone-stats")]
+pub mod clone_stats;
+#[cfg(doctest)]
+mod compile_fail;
@@ -0,0 +1,13 @@
+//! Immutable data structures whose updates return new versions that
+//! share the unchanged parts of the previous version through
+//! [`UniquePointer`](crate::UniquePointer) clones.
+//!
+//! Every version remains valid and unchanged for as long as it
+//! lives, and the nodes shared among versions are only reclaimed
+//! once the last version holding them is dropped.
+pub mod list;
+#[doc(inline)]
+pub use list::List;
+pub mod map;
+#[doc(inline)]
+pub use map::Map;
@@ -0,0 +1,188 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::{Pointee, UniquePointer};
+
+#[derive(Debug)]
+struct Node<T: Pointee> {
+    value: T,
+    next: UniquePointer<Node<T>>,
+}
+
+/// [List](Self) is an immutable singly-linked list whose versions
+/// share their tails.
+///
+/// [push_front](Self::push_front) and [tail](Self::tail) return a
+/// new version in `O(1)` without copying any value, the version
+/// they are called upon remains unchanged.
+///
+/// ```
+/// use unique_pointer::persistent::List;
+///
+/// let empty = List::new();
+/// let one = empty.push_front(1);
+/// let two = one.push_front(2);
+///
+/// assert_eq!(two.iter().collect::<Vec<_>>(), vec![&2, &1]);
+/// assert_eq!(one.iter().collect::<Vec<_>>(), vec![&1]);
+/// assert_eq!(two.tail(), one);
+/// assert!(empty.is_empty());
+/// ```
+pub struct List<T: Pointee> {
+    head: UniquePointer<Node<T>>,
+    len: usize,
+}
+
+impl<T: Pointee> List<T> {
+    pub fn new() -> List<T> {
+        List {
+            head: UniquePointer::null(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// returns the first value of the list
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    /// returns a new version of the list without its first value,
+    /// which is empty if the list is empty
+    pub fn tail(&self) -> List<T> {
+        match self.head.as_ref() {
+            Some(node) => List {
+                head: node.next.clone(),
+                len: self.len - 1,
+            },
+            None => List::new(),
+        }
+    }
+
+    /// returns a new version of the list with `value` in front of
+    /// the values of this version
+    pub fn push_front(&self, value: T) -> List<T> {
+        List {
+            head: UniquePointer::from(Node {
+                value,
+                next: self.head.clone(),
+            }),
+            len: self.len + 1,
+        }
+    }
+
+    /// returns true if `self` and `other` are the very same version
+    /// or share every node, as opposed to merely holding equal values
+    pub fn ptr_eq(&self, other: &List<T>) -> bool {
+        self.head.addr() == other.head.addr()
+    }
+
+    /// returns an iterator over the values from the front to the back
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_ref(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T: Pointee + Clone> List<T> {
+    /// returns a new version of the list with its values in reverse
+    /// order, which shares no node with this version
+    pub fn reverse(&self) -> List<T> {
+        self.iter()
+            .fold(List::new(), |list, value| list.push_front(value.clone()))
+    }
+}
+
+/// [Iter](Self) walks the nodes of a [`List`] from the front.
+pub struct Iter<'a, T: Pointee> {
+    next: Option<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T: Pointee> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.next?;
+        node.next.prefetch_read();
+        self.next = node.next.as_ref();
+        self.len -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: Pointee> ExactSizeIterator for Iter<'_, T> {}
+
+impl<'a, T: Pointee> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// clones share every node of the list in `O(1)`
+impl<T: Pointee> Clone for List<T> {
+    fn clone(&self) -> List<T> {
+        List {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+/// reclaims the nodes no other version shares, stopping at the first
+/// shared one since every node after it is shared as well
+impl<T: Pointee> Drop for List<T> {
+    fn drop(&mut self) {
+        let mut next = std::mem::replace(&mut self.head, UniquePointer::null());
+        while let Some(node) = next.take() {
+            next = node.next;
+        }
+    }
+}
+
+impl<T: Pointee> Default for List<T> {
+    fn default() -> List<T> {
+        List::new()
+    }
+}
+
+/// builds a list holding the values in iteration order
+impl<T: Pointee> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> List<T> {
+        let values = iter.into_iter().collect::<Vec<T>>();
+        values
+            .into_iter()
+            .rev()
+            .fold(List::new(), |list, value| list.push_front(value))
+    }
+}
+
+impl<T: Pointee + PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &List<T>) -> bool {
+        self.ptr_eq(other) || (self.len == other.len && self.iter().eq(other.iter()))
+    }
+}
+
+impl<T: Pointee + Eq> Eq for List<T> {}
+
+/// renders the values from the front to the back of the list
+impl<T: Pointee + Debug> Debug for List<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
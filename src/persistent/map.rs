@@ -0,0 +1,364 @@
+use std::collections::hash_map::RandomState;
+use std::fmt::{Debug, Formatter};
+use std::hash::{BuildHasher, Hash};
+
+use crate::{Pointee, UniquePointer};
+
+/// number of hash bits consumed by each level of the trie
+const BITS: u32 = 5;
+const MASK: u64 = (1 << BITS) - 1;
+
+#[derive(Debug)]
+enum Node<K: Pointee, V: Pointee> {
+    /// holds one child per bit set in `bitmap`, in the order of
+    /// their bits
+    Branch {
+        bitmap: u32,
+        children: Vec<UniquePointer<Node<K, V>>>,
+    },
+    /// holds the entries whose keys share the very same hash
+    Leaf { hash: u64, entries: Vec<(K, V)> },
+}
+
+/// returns the bit of `hash` at the level `shift` and the position
+/// of the corresponding child among the children of `bitmap`
+fn slot(bitmap: u32, hash: u64, shift: u32) -> (u32, usize) {
+    let bit = 1 << ((hash >> shift) & MASK);
+    (bit, (bitmap & (bit - 1)).count_ones() as usize)
+}
+
+/// [Map](Self) is an immutable hash array mapped trie whose versions
+/// share every node but the ones along the path to the entry they
+/// differ by.
+///
+/// [insert](Self::insert) and [remove](Self::remove) return a new
+/// version in `O(log n)`, the version they are called upon remains
+/// unchanged.
+///
+/// ```
+/// use unique_pointer::persistent::Map;
+///
+/// let empty = Map::new();
+/// let one = empty.insert("one", 1);
+/// let two = one.insert("two", 2);
+///
+/// assert_eq!(two.get(&"one"), Some(&1));
+/// assert_eq!(two.get(&"two"), Some(&2));
+/// assert_eq!(one.get(&"two"), None);
+/// assert_eq!(two.remove(&"two").len(), 1);
+/// assert!(empty.is_empty());
+/// ```
+pub struct Map<K: Pointee + Hash + Eq + Clone, V: Pointee + Clone> {
+    root: UniquePointer<Node<K, V>>,
+    len: usize,
+    hasher: RandomState,
+}
+
+impl<K: Pointee + Hash + Eq + Clone, V: Pointee + Clone> Map<K, V> {
+    pub fn new() -> Map<K, V> {
+        Map {
+            root: UniquePointer::null(),
+            len: 0,
+            hasher: RandomState::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// returns the value stored under `key`
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let hash = self.hasher.hash_one(key);
+        let mut node = self.root.as_ref()?;
+        let mut shift = 0;
+        loop {
+            match node {
+                Node::Branch { bitmap, children } => {
+                    let (bit, position) = slot(*bitmap, hash, shift);
+                    if bitmap & bit == 0 {
+                        return None;
+                    }
+                    node = children[position].as_ref()?;
+                    shift += BITS;
+                }
+                Node::Leaf { entries, .. } => {
+                    return entries
+                        .iter()
+                        .find(|(candidate, _)| candidate == key)
+                        .map(|(_, value)| value);
+                }
+            }
+        }
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// returns a new version of the map with `value` stored under
+    /// `key`, replacing the value previously stored under `key`
+    pub fn insert(&self, key: K, value: V) -> Map<K, V> {
+        let hash = self.hasher.hash_one(&key);
+        let (root, added) = Map::insert_into(&self.root, 0, hash, key, value);
+        Map {
+            root,
+            len: self.len + usize::from(added),
+            hasher: self.hasher.clone(),
+        }
+    }
+
+    /// returns a new version of the map without `key`, which shares
+    /// every node with this version if `key` is absent
+    pub fn remove(&self, key: &K) -> Map<K, V> {
+        let hash = self.hasher.hash_one(key);
+        match Map::remove_from(&self.root, 0, hash, key) {
+            Some(root) => Map {
+                root,
+                len: self.len - 1,
+                hasher: self.hasher.clone(),
+            },
+            None => self.clone(),
+        }
+    }
+
+    /// returns an iterator over the entries in no particular order
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            nodes: vec![std::slice::from_ref(&self.root).iter()],
+            entries: [].iter(),
+            len: self.len,
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// returns the node replacing `node` once `key` is inserted and
+    /// whether `key` was absent
+    fn insert_into(
+        node: &UniquePointer<Node<K, V>>,
+        shift: u32,
+        hash: u64,
+        key: K,
+        value: V,
+    ) -> (UniquePointer<Node<K, V>>, bool) {
+        let Some(current) = node.as_ref() else {
+            return (
+                UniquePointer::from(Node::Leaf {
+                    hash,
+                    entries: vec![(key, value)],
+                }),
+                true,
+            );
+        };
+        match current {
+            Node::Branch { bitmap, children } => {
+                let (bit, position) = slot(*bitmap, hash, shift);
+                let mut children = children.clone();
+                if bitmap & bit == 0 {
+                    let (child, _) =
+                        Map::insert_into(&UniquePointer::null(), shift + BITS, hash, key, value);
+                    children.insert(position, child);
+                    let branch = Node::Branch {
+                        bitmap: bitmap | bit,
+                        children,
+                    };
+                    return (UniquePointer::from(branch), true);
+                }
+                let (child, added) =
+                    Map::insert_into(&children[position], shift + BITS, hash, key, value);
+                children[position] = child;
+                let branch = Node::Branch {
+                    bitmap: *bitmap,
+                    children,
+                };
+                (UniquePointer::from(branch), added)
+            }
+            Node::Leaf {
+                hash: existing,
+                entries,
+            } if *existing == hash => {
+                let mut entries = entries.clone();
+                let added = match entries.iter_mut().find(|(candidate, _)| *candidate == key) {
+                    Some(entry) => {
+                        entry.1 = value;
+                        false
+                    }
+                    None => {
+                        entries.push((key, value));
+                        true
+                    }
+                };
+                (UniquePointer::from(Node::Leaf { hash, entries }), added)
+            }
+            Node::Leaf { hash: existing, .. } => {
+                let (bit, _) = slot(0, *existing, shift);
+                let mut branch = UniquePointer::from(Node::Branch {
+                    bitmap: bit,
+                    children: vec![node.clone()],
+                });
+                let inserted = Map::insert_into(&branch, shift, hash, key, value);
+                branch.take();
+                inserted
+            }
+        }
+    }
+
+    /// returns the node replacing `node` once `key` is removed, which
+    /// is NULL if `node` ends up empty, or `None` if `key` is absent
+    fn remove_from(
+        node: &UniquePointer<Node<K, V>>,
+        shift: u32,
+        hash: u64,
+        key: &K,
+    ) -> Option<UniquePointer<Node<K, V>>> {
+        match node.as_ref()? {
+            Node::Branch { bitmap, children } => {
+                let (bit, position) = slot(*bitmap, hash, shift);
+                if bitmap & bit == 0 {
+                    return None;
+                }
+                let child = Map::remove_from(&children[position], shift + BITS, hash, key)?;
+                let mut children = children.clone();
+                let mut bitmap = *bitmap;
+                if child.is_null() {
+                    children.remove(position);
+                    bitmap &= !bit;
+                } else {
+                    children[position] = child;
+                }
+                let collapse =
+                    children.len() == 1 && matches!(children[0].as_ref(), Some(Node::Leaf { .. }));
+                Some(match children.len() {
+                    0 => UniquePointer::null(),
+                    1 if collapse => children.remove(0),
+                    _ => UniquePointer::from(Node::Branch { bitmap, children }),
+                })
+            }
+            Node::Leaf {
+                hash: existing,
+                entries,
+            } => {
+                if *existing != hash {
+                    return None;
+                }
+                let position = entries.iter().position(|(candidate, _)| candidate == key)?;
+                let mut entries = entries.clone();
+                entries.remove(position);
+                Some(match entries.is_empty() {
+                    true => UniquePointer::null(),
+                    false => UniquePointer::from(Node::Leaf { hash, entries }),
+                })
+            }
+        }
+    }
+}
+
+/// [Iter](Self) walks the trie of a [`Map`] depth-first.
+pub struct Iter<'a, K: Pointee, V: Pointee> {
+    nodes: Vec<std::slice::Iter<'a, UniquePointer<Node<K, V>>>>,
+    entries: std::slice::Iter<'a, (K, V)>,
+    len: usize,
+}
+
+impl<'a, K: Pointee, V: Pointee> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        loop {
+            if let Some((key, value)) = self.entries.next() {
+                self.len -= 1;
+                return Some((key, value));
+            }
+            let nodes = self.nodes.last_mut()?;
+            let Some(node) = nodes.next() else {
+                self.nodes.pop();
+                continue;
+            };
+            match node.as_ref() {
+                Some(Node::Branch { children, .. }) => self.nodes.push(children.iter()),
+                Some(Node::Leaf { entries, .. }) => self.entries = entries.iter(),
+                None => {}
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K: Pointee, V: Pointee> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<'a, K: Pointee + Hash + Eq + Clone, V: Pointee + Clone> IntoIterator for &'a Map<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+/// clones share the whole trie in `O(1)`
+impl<K: Pointee + Hash + Eq + Clone, V: Pointee + Clone> Clone for Map<K, V> {
+    fn clone(&self) -> Map<K, V> {
+        Map {
+            root: self.root.clone(),
+            len: self.len,
+            hasher: self.hasher.clone(),
+        }
+    }
+}
+
+/// reclaims the nodes no other version shares, skipping the subtries
+/// of shared nodes since those are shared as well
+impl<K: Pointee + Hash + Eq + Clone, V: Pointee + Clone> Drop for Map<K, V> {
+    fn drop(&mut self) {
+        let mut pending = vec![std::mem::replace(&mut self.root, UniquePointer::null())];
+        while let Some(mut node) = pending.pop() {
+            if let Some(Node::Branch { children, .. }) = node.take() {
+                pending.extend(children);
+            }
+        }
+    }
+}
+
+impl<K: Pointee + Hash + Eq + Clone, V: Pointee + Clone> Default for Map<K, V> {
+    fn default() -> Map<K, V> {
+        Map::new()
+    }
+}
+
+impl<K: Pointee + Hash + Eq + Clone, V: Pointee + Clone> FromIterator<(K, V)> for Map<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Map<K, V> {
+        iter.into_iter()
+            .fold(Map::new(), |map, (key, value)| map.insert(key, value))
+    }
+}
+
+impl<K: Pointee + Hash + Eq + Clone, V: Pointee + Clone + PartialEq> PartialEq for Map<K, V> {
+    fn eq(&self, other: &Map<K, V>) -> bool {
+        self.len == other.len
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Pointee + Hash + Eq + Clone, V: Pointee + Clone + Eq> Eq for Map<K, V> {}
+
+impl<K: Pointee + Hash + Eq + Clone + Debug, V: Pointee + Clone + Debug> Debug for Map<K, V> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
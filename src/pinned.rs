@@ -0,0 +1,37 @@
+//! The `pinned` module keeps track of the addresses pinned via
+//! [`UniquePointer::pin_address`](crate::UniquePointer::pin_address),
+//! such that every `UniquePointer` to a pinned address, be it a clone
+//! or a read-only copy, refuses to move its value elsewhere.
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+
+thread_local! {
+    static PINNED: RefCell<BTreeSet<usize>> = const { RefCell::new(BTreeSet::new()) };
+}
+
+/// pins `addr` and returns false if it was already pinned
+pub(crate) fn pin(addr: usize) -> bool {
+    addr != 0 && PINNED.with(|pinned| pinned.borrow_mut().insert(addr))
+}
+
+/// returns true if `addr` is pinned
+pub(crate) fn is_pinned(addr: usize) -> bool {
+    addr != 0
+        && PINNED
+            .try_with(|pinned| {
+                pinned
+                    .try_borrow()
+                    .is_ok_and(|pinned| pinned.contains(&addr))
+            })
+            .unwrap_or(false)
+}
+
+/// forgets `addr` once its memory is released, such that a later
+/// allocation at the same address starts out unpinned
+pub(crate) fn unpin(addr: usize) {
+    let _ = PINNED.try_with(|pinned| {
+        if let Ok(mut pinned) = pinned.try_borrow_mut() {
+            pinned.remove(&addr);
+        }
+    });
+}
@@ -0,0 +1,175 @@
+//! The `pointer_map` module implements [`PointerMap`], a map keyed by
+//! the address of `UniquePointer`s whose entries are dropped as soon
+//! as the memory at their address is released.
+use std::cell::{Ref, RefCell, RefMut};
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+use std::rc::{Rc, Weak};
+
+use crate::{Pointee, UniquePointer};
+
+thread_local! {
+    static WATCHERS: RefCell<BTreeMap<usize, Vec<Weak<dyn Evict>>>> =
+        const { RefCell::new(BTreeMap::new()) };
+}
+
+/// the type-erased entries of a [`PointerMap`]
+trait Evict {
+    fn evict(&self, addr: usize);
+}
+
+impl<V> Evict for RefCell<BTreeMap<usize, V>> {
+    fn evict(&self, addr: usize) {
+        let value = match self.try_borrow_mut() {
+            Ok(mut entries) => entries.remove(&addr),
+            Err(_) => None,
+        };
+        drop(value);
+    }
+}
+
+/// drops the entries keyed by `addr` from every [`PointerMap`] of the
+/// current thread, called once the memory at `addr` is released
+pub(crate) fn released(addr: usize) {
+    let watchers = WATCHERS
+        .try_with(|watchers| {
+            watchers
+                .try_borrow_mut()
+                .ok()
+                .and_then(|mut watchers| watchers.remove(&addr))
+        })
+        .ok()
+        .flatten();
+    for watcher in watchers.into_iter().flatten() {
+        if let Some(entries) = watcher.upgrade() {
+            entries.evict(addr);
+        }
+    }
+}
+
+/// [PointerMap](Self) attaches values of type `V` to the memory of
+/// `UniquePointer<T>`s, such as colors, visit marks or memoized
+/// results, without adding fields to `T`.
+///
+/// Entries are keyed by address and dropped as soon as the memory at
+/// their address is released, be it by the last owner letting go of
+/// its reference or by [`take`](UniquePointer::take), such that a
+/// later allocation at the same address does not inherit them.
+/// Clones and read-only copies share the entries of the pointer they
+/// were made from.
+///
+/// Maps only observe the memory released by the thread they were
+/// created in.
+///
+/// ```
+/// use unique_pointer::pointer_map::PointerMap;
+/// use unique_pointer::UniquePointer;
+///
+/// let mut node = UniquePointer::from(1u8);
+/// let clone = node.clone();
+/// let mut colors = PointerMap::new();
+/// colors.insert(&node, "red");
+/// assert_eq!(colors.get(&clone).as_deref(), Some(&"red"));
+///
+/// drop(clone);
+/// assert_eq!(node.take(), Some(1));
+/// assert_eq!(colors.len(), 0);
+/// ```
+pub struct PointerMap<T: Pointee, V: 'static> {
+    entries: Rc<RefCell<BTreeMap<usize, V>>>,
+    pointee: PhantomData<T>,
+}
+
+impl<T: Pointee, V: 'static> PointerMap<T, V> {
+    pub fn new() -> PointerMap<T, V> {
+        PointerMap {
+            entries: Rc::new(RefCell::new(BTreeMap::new())),
+            pointee: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// attaches `value` to the memory of `pointer` and returns the
+    /// value previously attached to it. Values attached to NULL
+    /// pointers are dropped right away.
+    pub fn insert(&mut self, pointer: &UniquePointer<T>, value: V) -> Option<V> {
+        let addr = pointer.addr();
+        if addr == 0 {
+            return None;
+        }
+        let previous = self.entries.borrow_mut().insert(addr, value);
+        if previous.is_none() {
+            let entries: Rc<dyn Evict> = self.entries.clone();
+            let watcher = Rc::downgrade(&entries);
+            WATCHERS.with(|watchers| {
+                let mut watchers = watchers.borrow_mut();
+                let watchers = watchers.entry(addr).or_default();
+                watchers.retain(|watcher| watcher.strong_count() > 0);
+                watchers.push(watcher);
+            });
+        }
+        previous
+    }
+
+    /// returns the value attached to the memory of `pointer`
+    pub fn get(&self, pointer: &UniquePointer<T>) -> Option<Ref<'_, V>> {
+        Ref::filter_map(self.entries.borrow(), |entries| {
+            entries.get(&pointer.addr())
+        })
+        .ok()
+    }
+
+    /// returns the value attached to the memory of `pointer`
+    pub fn get_mut(&mut self, pointer: &UniquePointer<T>) -> Option<RefMut<'_, V>> {
+        RefMut::filter_map(self.entries.borrow_mut(), |entries| {
+            entries.get_mut(&pointer.addr())
+        })
+        .ok()
+    }
+
+    pub fn contains_key(&self, pointer: &UniquePointer<T>) -> bool {
+        self.entries.borrow().contains_key(&pointer.addr())
+    }
+
+    /// detaches the value attached to the memory of `pointer`
+    pub fn remove(&mut self, pointer: &UniquePointer<T>) -> Option<V> {
+        self.entries.borrow_mut().remove(&pointer.addr())
+    }
+
+    pub fn clear(&mut self) {
+        let entries = std::mem::take(&mut *self.entries.borrow_mut());
+        drop(entries);
+    }
+
+    /// returns the addresses holding a value in ascending order
+    pub fn addrs(&self) -> Vec<usize> {
+        self.entries.borrow().keys().copied().collect()
+    }
+}
+
+impl<T: Pointee, V: 'static> Default for PointerMap<T, V> {
+    fn default() -> PointerMap<T, V> {
+        PointerMap::new()
+    }
+}
+
+impl<T: Pointee, V: Debug + 'static> Debug for PointerMap<T, V> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_map()
+            .entries(
+                self.entries
+                    .borrow()
+                    .iter()
+                    .map(|(addr, value)| (format!("{:016x}", addr), value)),
+            )
+            .finish()
+    }
+}
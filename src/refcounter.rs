@@ -52,10 +52,20 @@ impl RefCounter {
         up.incr_by(1);
     }
 
-    /// `incr_by` increments the `RefCounter`
+    /// `incr_by` increments the `RefCounter` saturating at [`usize::MAX`]
     pub fn incr_by(&self, by: usize) {
         let mut up = unsafe { self.meta_mut() };
-        up.write(up.read() + by);
+        up.write(up.read().saturating_add(by));
+    }
+
+    /// `checked_incr_by` increments the `RefCounter` and returns the
+    /// new count or `None`, leaving the count untouched, if it would
+    /// overflow.
+    pub fn checked_incr_by(&self, by: usize) -> Option<usize> {
+        let mut up = unsafe { self.meta_mut() };
+        let data = up.read().checked_add(by)?;
+        up.write(data);
+        Some(data)
     }
 
     /// `decr` decrements the `RefCounter` by one
@@ -73,6 +83,37 @@ impl RefCounter {
         }
     }
 
+    /// `checked_decr_by` decrements the `RefCounter` and returns the
+    /// new count or `None`, leaving the count untouched, if it would
+    /// underflow.
+    pub fn checked_decr_by(&self, by: usize) -> Option<usize> {
+        let mut up = unsafe { self.meta_mut() };
+        let data = up.read().checked_sub(by)?;
+        up.write(data);
+        Some(data)
+    }
+
+    /// `try_decr_by` decrements the `RefCounter` and returns the new
+    /// count or [`Error::Underflow`](crate::Error::Underflow), leaving
+    /// the count untouched, if it would underflow.
+    ///
+    /// ```
+    /// use unique_pointer::{Error, RefCounter};
+    ///
+    /// let refs = RefCounter::from(2);
+    /// assert_eq!(refs.try_decr_by(1), Ok(1));
+    /// assert!(matches!(refs.try_decr_by(2), Err(Error::Underflow { refs: 1, by: 2, .. })));
+    /// assert_eq!(refs.read(), 1);
+    /// ```
+    pub fn try_decr_by(&self, by: usize) -> crate::Result<usize> {
+        let refs = self.read();
+        self.checked_decr_by(by).ok_or(crate::Error::Underflow {
+            addr: self.data.addr(),
+            refs,
+            by,
+        })
+    }
+
     /// `drain` deallocates the memory used by a [`RefCounter`](Self)
     /// resetting its internals so as to behave as though it has been
     /// written `0`.
@@ -94,12 +135,46 @@ impl RefCounter {
         }
     }
 
+    /// `weak` returns the number of [`UniqueWeak`](crate::UniqueWeak)s
+    /// sharing the `RefCounter`, which is stored right after the
+    /// reference count.
+    pub fn weak(&self) -> usize {
+        if self.data.is_null() {
+            0
+        } else {
+            unsafe { self.data.add(1).read() }
+        }
+    }
+
+    /// `incr_weak` increments the weak count by one
+    pub fn incr_weak(&self) {
+        self.alloc();
+        unsafe {
+            let weak = self.data.add(1);
+            weak.write(weak.read().saturating_add(1));
+        }
+    }
+
+    /// `decr_weak` decrements the weak count by one unless already
+    /// zero
+    pub fn decr_weak(&self) {
+        if self.data.is_null() {
+            return;
+        }
+        unsafe {
+            let weak = self.data.add(1);
+            weak.write(weak.read().saturating_sub(1));
+        }
+    }
+
+    /// allocates the reference count, set to one, followed by the
+    /// weak count, set to zero
     fn alloc(&self) {
         if !self.data.is_null() {
             return;
         }
 
-        let layout = Layout::new::<usize>();
+        let layout = Layout::new::<[usize; 2]>();
         let ptr = unsafe {
             let ptr = std::alloc::alloc(layout);
             if ptr.is_null() {
@@ -107,6 +182,7 @@ impl RefCounter {
             }
             ptr as *mut usize
         };
+        unsafe { ptr.add(1).write(0) };
         let mut up = unsafe { self.meta_mut() };
         up.data = ptr;
         up.write(1);
@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+
+use crate::{Error, GraphNode, Result};
+
+/// [TeardownPlan](Self) tears down a set of interdependent nodes,
+/// such as the fixtures of a test, in an order computed from the
+/// parent/child relationships recorded between them: every node is
+/// torn down after all of its children, parents last, regardless of
+/// the order the nodes were registered in.
+///
+/// Nodes are registered under a key via [`node`](Self::node), their
+/// relationships recorded via [`child`](Self::child) or, for nodes
+/// implementing [`GraphNode`], read from the nodes themselves via
+/// [`record_children`](Self::record_children).
+///
+/// ```
+/// use unique_pointer::TeardownPlan;
+///
+/// let (mut a, mut b, mut c) = (String::from("A"), String::from("B"), String::from("C"));
+/// let mut order = Vec::new();
+/// let torn_down = TeardownPlan::new()
+///     .node('A', &mut a)
+///     .node('B', &mut b)
+///     .node('C', &mut c)
+///     .child('A', 'B')
+///     .child('B', 'C')
+///     .execute(|node| order.push(std::mem::take(node)));
+/// assert_eq!(torn_down, Ok(3));
+/// assert_eq!(order, vec!["C", "B", "A"]);
+/// ```
+pub struct TeardownPlan<'s, K: Ord, T> {
+    nodes: Vec<(K, &'s mut T)>,
+    children: Vec<(K, K)>,
+}
+
+impl<'s, K: Ord, T> TeardownPlan<'s, K, T> {
+    pub fn new() -> TeardownPlan<'s, K, T> {
+        TeardownPlan {
+            nodes: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// registers `node` under `key`
+    pub fn node(mut self, key: K, node: &'s mut T) -> TeardownPlan<'s, K, T> {
+        self.nodes.push((key, node));
+        self
+    }
+
+    /// records that the node registered under `child` must be torn
+    /// down before the one registered under `parent`. Relationships
+    /// involving keys never registered are ignored.
+    pub fn child(mut self, parent: K, child: K) -> TeardownPlan<'s, K, T> {
+        self.children.push((parent, child));
+        self
+    }
+
+    /// returns the number of nodes registered so far
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// returns the keys of the registered nodes in the order they
+    /// are torn down or [`Error::Invariant`] at the address of a node
+    /// whose recorded relationships form a cycle
+    pub fn order(&self) -> Result<Vec<&K>> {
+        Ok(self
+            .plan()?
+            .into_iter()
+            .map(|index| &self.nodes[index].0)
+            .collect())
+    }
+
+    /// tears down every registered node via `teardown` in the
+    /// [order](Self::order) computed and returns the number of nodes
+    /// torn down, tearing down none of them if the relationships
+    /// form a cycle
+    pub fn execute(self, mut teardown: impl FnMut(&mut T)) -> Result<usize> {
+        let plan = self.plan()?;
+        let mut nodes = self
+            .nodes
+            .into_iter()
+            .map(|(_, node)| Some(node))
+            .collect::<Vec<_>>();
+        for &index in plan.iter() {
+            if let Some(node) = nodes[index].take() {
+                teardown(node);
+            }
+        }
+        Ok(plan.len())
+    }
+
+    /// returns the indexes of the registered nodes sorted
+    /// topologically, children first
+    fn plan(&self) -> Result<Vec<usize>> {
+        let indexes = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, (key, _))| (key, index))
+            .collect::<BTreeMap<&K, usize>>();
+        let mut pending = vec![0usize; self.nodes.len()];
+        let mut parents = vec![Vec::new(); self.nodes.len()];
+        for (parent, child) in self.children.iter() {
+            if let (Some(&parent), Some(&child)) = (indexes.get(parent), indexes.get(child))
+                && parent != child
+            {
+                pending[parent] += 1;
+                parents[child].push(parent);
+            }
+        }
+
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut ready = (0..self.nodes.len())
+            .rev()
+            .filter(|&index| pending[index] == 0)
+            .collect::<Vec<_>>();
+        while let Some(index) = ready.pop() {
+            order.push(index);
+            for &parent in parents[index].iter() {
+                pending[parent] -= 1;
+                if pending[parent] == 0 {
+                    ready.push(parent);
+                }
+            }
+        }
+        match pending.iter().position(|&pending| pending > 0) {
+            Some(index) => Err(Error::Invariant {
+                addr: std::ptr::from_ref::<T>(self.nodes[index].1).addr(),
+                invariant: "parent/child relationships without cycles",
+            }),
+            None => Ok(order),
+        }
+    }
+}
+
+impl<'s, K: Ord + Clone, T: GraphNode> TeardownPlan<'s, K, T> {
+    /// records the relationships between the registered nodes told
+    /// by [`GraphNode::children`], matching nodes by address, hence
+    /// nodes moved since their pointers were taken go unmatched.
+    pub fn record_children(mut self) -> TeardownPlan<'s, K, T> {
+        let keys = self
+            .nodes
+            .iter()
+            .map(|(key, node)| (std::ptr::from_ref::<T>(node).addr(), key.clone()))
+            .collect::<BTreeMap<usize, K>>();
+        for (parent, node) in self.nodes.iter() {
+            for child in node.children() {
+                if let Some(child) = keys.get(&child.addr()) {
+                    self.children.push((parent.clone(), child.clone()));
+                }
+            }
+        }
+        self
+    }
+}
+
+impl<K: Ord, T> Default for TeardownPlan<'_, K, T> {
+    fn default() -> Self {
+        TeardownPlan::new()
+    }
+}
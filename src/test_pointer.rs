@@ -0,0 +1,223 @@
+use std::cell::{Ref, RefCell, RefMut};
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+use crate::{PointerFamily, PointerLike};
+
+/// [TestPointer](Self) mirrors the API of
+/// [`UniquePointer`](crate::UniquePointer) on top of
+/// [`Rc<RefCell<T>>`](std::cell::RefCell) and therefore without any
+/// `unsafe` code, such that container logic can be unit-tested
+/// without risk of undefined behavior.
+///
+/// Like [`UniquePointer`](crate::UniquePointer), clones share the
+/// pointee, [`write`](Self::write) replaces the pointee of every
+/// clone and [`drop_in_place`](Self::drop_in_place) only nullifies
+/// the pointer it is called upon. Unlike
+/// [`UniquePointer`](crate::UniquePointer), references are lent
+/// through [`Ref`] and [`RefMut`] guards, so aliasing mistakes panic
+/// instead of going unnoticed.
+///
+/// ```
+/// use unique_pointer::TestPointer;
+///
+/// let mut pointer = TestPointer::from(1);
+/// let shared = pointer.clone();
+/// *pointer.inner_mut() += 1;
+/// assert_eq!(*shared.inner_ref(), 2);
+/// assert_eq!(shared.refs(), 2);
+/// ```
+pub struct TestPointer<T> {
+    inner: Option<Rc<RefCell<T>>>,
+}
+
+impl<T> TestPointer<T> {
+    /// creates a NULL [TestPointer](Self) ready to be written via
+    /// [write](Self::write).
+    pub fn null() -> TestPointer<T> {
+        TestPointer { inner: None }
+    }
+
+    /// returns the address of the pointee or zero if null
+    pub fn addr(&self) -> usize {
+        match &self.inner {
+            Some(inner) => inner.as_ptr() as usize,
+            None => 0,
+        }
+    }
+
+    /// returns the number of [TestPointer](Self) sharing the pointee
+    pub fn refs(&self) -> usize {
+        match &self.inner {
+            Some(inner) => Rc::strong_count(inner),
+            None => 0,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.inner.is_none()
+    }
+
+    pub fn is_not_null(&self) -> bool {
+        !self.is_null()
+    }
+
+    pub fn is_written(&self) -> bool {
+        self.is_not_null()
+    }
+
+    /// writes `data` into the pointee shared by every clone of this
+    /// pointer, or into a new pointee if null
+    pub fn write(&mut self, data: T) {
+        match &self.inner {
+            Some(inner) => {
+                inner.replace(data);
+            }
+            None => self.inner = Some(Rc::new(RefCell::new(data))),
+        }
+    }
+
+    /// swaps the pointees of `self` and `other`
+    pub fn swap(&mut self, other: &mut Self) {
+        std::mem::swap(&mut self.inner, &mut other.inner)
+    }
+
+    /// obtains a read-only guard to the pointee. Panics if the
+    /// pointer is null or mutably borrowed.
+    pub fn inner_ref(&self) -> Ref<'_, T> {
+        match &self.inner {
+            Some(inner) => inner.borrow(),
+            None => panic!("NULL POINTER"),
+        }
+    }
+
+    /// obtains a mutable guard to the pointee. Panics if the pointer
+    /// is null or already borrowed.
+    pub fn inner_mut(&mut self) -> RefMut<'_, T> {
+        match &self.inner {
+            Some(inner) => inner.borrow_mut(),
+            None => panic!("NULL POINTER"),
+        }
+    }
+
+    pub fn as_ref(&self) -> Option<Ref<'_, T>> {
+        self.inner.as_ref().map(|inner| inner.borrow())
+    }
+
+    pub fn as_mut(&mut self) -> Option<RefMut<'_, T>> {
+        self.inner.as_ref().map(|inner| inner.borrow_mut())
+    }
+
+    /// nullifies this pointer, dropping the pointee once no other
+    /// clone shares it
+    pub fn drop_in_place(&mut self) {
+        self.inner = None;
+    }
+}
+
+impl<T: Clone> TestPointer<T> {
+    /// returns a copy of the pointee. Panics if the pointer is null.
+    pub fn read(&self) -> T {
+        self.inner_ref().clone()
+    }
+
+    /// returns a copy of the pointee if not null
+    pub fn try_read(&self) -> Option<T> {
+        self.as_ref().map(|data| data.clone())
+    }
+
+    /// writes a copy of `data` into the pointee
+    pub fn write_ref(&mut self, data: &T) {
+        self.write(data.clone())
+    }
+}
+
+impl<T> Clone for TestPointer<T> {
+    fn clone(&self) -> TestPointer<T> {
+        TestPointer {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> Default for TestPointer<T> {
+    fn default() -> TestPointer<T> {
+        TestPointer::null()
+    }
+}
+
+impl<T> From<T> for TestPointer<T> {
+    fn from(data: T) -> TestPointer<T> {
+        let mut pointer = TestPointer::null();
+        pointer.write(data);
+        pointer
+    }
+}
+
+impl<T: Debug> Debug for TestPointer<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match &self.inner {
+            Some(inner) => write!(
+                f,
+                "TestPointer{:016x}[src={:#?}][refs={}]",
+                self.addr(),
+                inner,
+                self.refs()
+            ),
+            None => write!(f, "TestPointer{:016x}[refs=0]", 0),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for TestPointer<T> {
+    fn eq(&self, other: &TestPointer<T>) -> bool {
+        match (&self.inner, &other.inner) {
+            (Some(lhs), Some(rhs)) => Rc::ptr_eq(lhs, rhs) || lhs == rhs,
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T> PointerLike<T> for TestPointer<T> {
+    type Ref<'a>
+        = Ref<'a, T>
+    where
+        Self: 'a;
+    type RefMut<'a>
+        = RefMut<'a, T>
+    where
+        Self: 'a;
+
+    fn null() -> TestPointer<T> {
+        TestPointer::null()
+    }
+
+    fn write(&mut self, data: T) {
+        TestPointer::write(self, data)
+    }
+
+    fn as_ref(&self) -> Option<Ref<'_, T>> {
+        TestPointer::as_ref(self)
+    }
+
+    fn as_mut(&mut self) -> Option<RefMut<'_, T>> {
+        TestPointer::as_mut(self)
+    }
+
+    fn clone_shared(&self) -> TestPointer<T> {
+        self.clone()
+    }
+
+    fn is_null(&self) -> bool {
+        TestPointer::is_null(self)
+    }
+}
+
+/// [TestPointerFamily](Self) is the [`PointerFamily`](crate::PointerFamily)
+/// of [`TestPointer`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TestPointerFamily;
+impl PointerFamily for TestPointerFamily {
+    type Pointer<T: Debug + Clone> = TestPointer<T>;
+}
@@ -1,5 +1,6 @@
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::ops::{Deref, DerefMut};
 
 /// The [`crate::Pointee`] trait serves as a contract of sorts to ensure
 /// that types used in [`crate::UniquePointer`] implement
@@ -15,3 +16,211 @@ impl<T: Debug> Pointee for T {}
 impl<T> Pointee for T {}
 // pub trait Pointee: Sized + Debug {}
 // impl<T: Sized + Debug> Pointee for T {}
+
+/// The [`crate::PointerLike`] trait is the common ground between
+/// [`crate::UniquePointer`] and other pointer backends such as
+/// [`crate::BoxPointer`] and [`crate::RcPointer`] such that code can
+/// be written once and instantiated with different backends, for
+/// instance to compare them. Recursive data structures name their
+/// backend through a [`crate::PointerFamily`] instead.
+///
+/// Backends lend their pointee through [`PointerLike::Ref`] and
+/// [`PointerLike::RefMut`], which are plain references for all but
+/// guarded backends such as [`crate::TestPointer`].
+///
+/// Because [`crate::UniquePointer`] has inherent methods by the same
+/// names, call them through the trait in generic code, e.g.:
+/// `PointerLike::as_ref(&pointer)`.
+///
+/// ```
+/// use unique_pointer::{BoxPointer, PointerLike, RcPointer, UniquePointer};
+///
+/// fn incr<P: PointerLike<u32>>(pointer: &mut P) -> Option<u32> {
+///     let mut value = PointerLike::as_mut(pointer)?;
+///     *value += 1;
+///     Some(*value)
+/// }
+///
+/// assert_eq!(incr(&mut UniquePointer::from(1)), Some(2));
+/// assert_eq!(incr(&mut BoxPointer::new(1)), Some(2));
+/// assert_eq!(incr(&mut RcPointer::new(1)), Some(2));
+/// assert_eq!(incr(&mut RcPointer::null()), None);
+/// ```
+pub trait PointerLike<T>: Sized {
+    /// guard lending a read-only reference to the pointee
+    type Ref<'a>: Deref<Target = T>
+    where
+        Self: 'a;
+
+    /// guard lending a mutable reference to the pointee
+    type RefMut<'a>: DerefMut<Target = T>
+    where
+        Self: 'a;
+
+    /// creates a pointer to nothing
+    fn null() -> Self;
+
+    /// writes `data` into the pointer, replacing whatever it pointed to
+    fn write(&mut self, data: T);
+
+    /// returns a reference to the pointee or `None` if the pointer is
+    /// null
+    fn as_ref(&self) -> Option<Self::Ref<'_>>;
+
+    /// returns a mutable reference to the pointee or `None` if the
+    /// pointer is null or the backend cannot lend it mutably.
+    fn as_mut(&mut self) -> Option<Self::RefMut<'_>>;
+
+    /// returns another pointer to the same pointee where the backend
+    /// supports sharing, or to a copy of it where it does not.
+    fn clone_shared(&self) -> Self;
+
+    /// returns true if the pointer points to nothing
+    fn is_null(&self) -> bool {
+        self.as_ref().is_none()
+    }
+}
+
+/// The [`crate::PointerFamily`] trait names a pointer backend without
+/// naming its pointee, which allows recursive data structures to be
+/// generic over the backend of their links.
+///
+/// ```
+/// use unique_pointer::{BoxPointerFamily, PointerFamily, PointerLike, UniquePointerFamily};
+///
+/// struct Node<F: PointerFamily> {
+///     value: u32,
+///     next: F::Pointer<Node<F>>,
+/// }
+/// # impl<F: PointerFamily> Clone for Node<F> {
+/// #     fn clone(&self) -> Self {
+/// #         Node { value: self.value, next: self.next.clone_shared() }
+/// #     }
+/// # }
+/// # impl<F: PointerFamily> std::fmt::Debug for Node<F> {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "Node({})", self.value)
+/// #     }
+/// # }
+///
+/// fn sum<F: PointerFamily>(node: &Node<F>) -> u32 {
+///     node.value + PointerLike::as_ref(&node.next).map(|next| sum(&next)).unwrap_or(0)
+/// }
+///
+/// fn pair<F: PointerFamily>() -> Node<F> {
+///     let mut next = F::Pointer::null();
+///     next.write(Node { value: 2, next: F::Pointer::null() });
+///     Node { value: 1, next }
+/// }
+///
+/// assert_eq!(sum(&pair::<UniquePointerFamily>()), 3);
+/// assert_eq!(sum(&pair::<BoxPointerFamily>()), 3);
+/// ```
+pub trait PointerFamily {
+    type Pointer<T: Debug + Clone>: PointerLike<T>;
+}
+
+/// The [`crate::GraphNode`] trait exposes the edges of a node of a
+/// pointer graph, such as a linked list or a tree, such that
+/// [`crate::UniquePointer::debug_graph`] can render the subgraph
+/// reachable from a pointer, up to a given depth, without relying on
+/// the [`Debug`] implementation of the node, which typically either
+/// renders a single node or recurses without bounds.
+///
+/// ```
+/// use std::fmt::Formatter;
+/// use unique_pointer::{GraphNode, UniquePointer};
+///
+/// #[derive(Debug)]
+/// struct Node {
+///     value: u32,
+///     next: UniquePointer<Node>,
+/// }
+///
+/// impl GraphNode for Node {
+///     fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+///         vec![("next", &self.next)]
+///     }
+///
+///     fn label(&self, f: &mut Formatter) -> std::fmt::Result {
+///         write!(f, "{}", self.value)
+///     }
+/// }
+///
+/// let tail = UniquePointer::from(Node { value: 2, next: UniquePointer::null() });
+/// let head = UniquePointer::from(Node { value: 1, next: tail.clone() });
+/// let graph = format!("{:?}", head.debug_graph(1));
+/// assert!(graph.ends_with("] 2 {...}}"));
+///
+/// let graph = format!("{:?}", head.debug_graph(2));
+/// assert!(graph.ends_with("] 2 {next: NULL}}"));
+/// ```
+pub trait GraphNode: crate::Pointee + Sized {
+    /// returns the pointers to the nodes adjacent to `self` paired
+    /// with the names of the edges leading to them
+    fn edges(&self) -> Vec<(&'static str, &crate::UniquePointer<Self>)>;
+
+    /// returns the pointers to the nodes `self` is a parent of, such
+    /// that [`crate::defer_scope`] deallocates them before `self`.
+    /// Defaults to every edge, which graphs whose edges also lead back
+    /// to parents, such as trees linking nodes to their parent, must
+    /// override.
+    fn children(&self) -> Vec<&crate::UniquePointer<Self>> {
+        self.edges().into_iter().map(|(_, edge)| edge).collect()
+    }
+
+    /// writes `self` without its edges
+    fn label(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result;
+
+    /// returns the number of distinct nodes reachable from `self`
+    /// through [`GraphNode::children`], `self` included
+    fn size(&self) -> usize {
+        self.count_if(|_| true)
+    }
+
+    /// returns the number of distinct nodes reachable from `self`
+    /// through [`GraphNode::children`], `self` included, for which
+    /// `predicate` returns true.
+    ///
+    /// The traversal is iterative, hence deep graphs such as long
+    /// linked lists do not overflow the stack.
+    ///
+    /// ```
+    /// # use std::fmt::Formatter;
+    /// # use unique_pointer::{GraphNode, UniquePointer};
+    /// # #[derive(Debug)]
+    /// # struct Node {
+    /// #     value: u32,
+    /// #     next: UniquePointer<Node>,
+    /// # }
+    /// # impl GraphNode for Node {
+    /// #     fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+    /// #         vec![("next", &self.next)]
+    /// #     }
+    /// #     fn label(&self, f: &mut Formatter) -> std::fmt::Result {
+    /// #         write!(f, "{}", self.value)
+    /// #     }
+    /// # }
+    /// let head = (1..=10).rev().fold(UniquePointer::null(), |next, value| {
+    ///     UniquePointer::from(Node { value, next })
+    /// });
+    /// let head = head.as_ref().unwrap();
+    /// assert_eq!(head.size(), 10);
+    /// assert_eq!(head.count_if(|node| node.value % 2 == 0), 5);
+    /// ```
+    fn count_if(&self, mut predicate: impl FnMut(&Self) -> bool) -> usize {
+        let mut visited = std::collections::BTreeSet::new();
+        let mut pending = vec![self];
+        let mut count = 0;
+        while let Some(node) = pending.pop() {
+            if !visited.insert(std::ptr::from_ref(node).addr()) {
+                continue;
+            }
+            if predicate(node) {
+                count += 1;
+            }
+            pending.extend(node.children().into_iter().filter_map(|child| child.as_ref()));
+        }
+        count
+    }
+}
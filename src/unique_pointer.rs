@@ -1,15 +1,44 @@
-use crate::{Pointee, RefCounter};
-use std::alloc::Layout;
+use crate::{DebugGraph, Error, GraphNode, Pointee, PointerLike, RefCounter, Result, UniqueWeak};
+use std::alloc::{Allocator, Global, Layout};
 use std::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
 use std::convert::{AsMut, AsRef};
 use std::fmt::{Debug, Formatter, Pointer};
 use std::hash::{Hash, Hasher};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::mem::{ManuallyDrop, MaybeUninit};
 use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::ptr::NonNull;
+#[cfg(feature = "rc-compat")]
+use std::rc::Rc;
+#[cfg(feature = "rc-compat")]
+use std::sync::Arc;
 
 pub const ISACOPY: u8 = 0b0001;
 pub const ISALLOC: u8 = 0b0010;
 pub const WRITTEN: u8 = 0b0100;
 
+/// [OnDrop](Self) is the policy applied by a `UniquePointer` when
+/// dropped, see [`UniquePointer::set_on_drop`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OnDrop {
+    /// releases the references held by `UniquePointer`, which is the
+    /// default
+    #[default]
+    Release,
+    /// leaves the references held by `UniquePointer` untouched such
+    /// that its memory is never released, as if it were passed to
+    /// [`std::mem::forget`]
+    Leak,
+    /// panics if `UniquePointer` is the last owner of its memory,
+    /// otherwise releases its references
+    #[cfg(not(feature = "no-panic"))]
+    Panic,
+}
+
+#[cfg(feature = "alloc-id")]
+static NEXT_ALLOC_ID: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(1);
+
 /// `UniquePointer` is an experimental data structure that makes
 /// extensive use of unsafe rust to provide a shared pointer
 /// throughout the runtime of a rust program as transparently as
@@ -62,13 +91,15 @@ pub const WRITTEN: u8 = 0b0100;
 /// references in strategic occasions such as incrementing its
 /// reference count within its [Clone] implementation.
 ///
-/// UniquePointer only supports [Sized] types, that is, [Zero-Sized
+/// UniquePointer only supports [Sized] types. [Zero-Sized
 /// Types](https://doc.rust-lang.org/nomicon/exotic-sizes.html#zero-sized-types-zsts)
-/// (ZSTs) are not supported.
+/// (ZSTs) never reach the allocator, their memory being a dangling
+/// pointer aligned like `T`.
 ///
 /// Example
 ///
-/// ```
+#[cfg_attr(not(feature = "no-panic"), doc = "```")]
+#[cfg_attr(feature = "no-panic", doc = "```ignore")]
 /// use unique_pointer::UniquePointer;
 ///
 /// fn create_unique_pointer<'a>() -> UniquePointer<&'a str> {
@@ -90,12 +121,15 @@ pub const WRITTEN: u8 = 0b0100;
 /// # Caveats
 ///
 /// - Only supports types that implement [Debug]
-/// - Does not support [ZSTs](https://doc.rust-lang.org/nomicon/exotic-sizes.html#zero-sized-types-zsts) (Zero-Sized Types)
+/// - [ZSTs](https://doc.rust-lang.org/nomicon/exotic-sizes.html#zero-sized-types-zsts) (Zero-Sized Types) of equal alignment share their address, hence
+///   anything keyed by address such as [`PointerMap`](crate::pointer_map::PointerMap)
+///   or [`pin_address`](Self::pin_address) treats them as one
 /// - [UniquePointer](Self) **IS NOT THREAD SAFE**
 ///
 /// # Lisp Cons Cell Example
 ///
-/// ```
+#[cfg_attr(not(feature = "no-panic"), doc = "```")]
+#[cfg_attr(feature = "no-panic", doc = "```ignore")]
 /// use std::iter::Extend;
 ///
 /// use unique_pointer::{RefCounter, UniquePointer};
@@ -235,7 +269,6 @@ pub const WRITTEN: u8 = 0b0100;
 ///     head: UniquePointer<Value<'c>>,
 ///     tail: UniquePointer<Cell<'c>>,
 ///     refs: RefCounter,
-///     length: usize,
 /// }
 ///
 /// impl<'c> Cell<'c> {
@@ -244,7 +277,6 @@ pub const WRITTEN: u8 = 0b0100;
 ///             head: UniquePointer::<Value<'c>>::null(),
 ///             tail: UniquePointer::<Cell<'c>>::null(),
 ///             refs: RefCounter::null(),
-///             length: 0,
 ///         }
 ///     }
 ///
@@ -261,7 +293,6 @@ pub const WRITTEN: u8 = 0b0100;
 ///     fn write(&mut self, value: Value<'c>) {
 ///         self.head.write(value);
 ///         self.refs.write(1);
-///         self.length = 1;
 ///     }
 ///
 ///     fn swap_head(&mut self, other: &mut Self) {
@@ -324,7 +355,7 @@ pub const WRITTEN: u8 = 0b0100;
 ///     }
 ///
 ///     pub fn is_empty(&self) -> bool {
-///         self.len() > 0
+///         self.len() == 0
 ///     }
 ///
 ///     pub fn len(&self) -> usize {
@@ -463,21 +494,24 @@ pub const WRITTEN: u8 = 0b0100;
 /// ```
 ///
 #[doc(alias = "Pointer")]
-pub struct UniquePointer<T: Pointee> {
+pub struct UniquePointer<T: Pointee, A: Allocator = Global> {
     mut_addr: usize,
     mut_ptr: *mut T,
     refs: RefCounter,
     flags: u8,
+    on_drop: OnDrop,
+    align_shift: u8,
+    group: u64,
+    #[cfg(feature = "alias-check")]
+    handle: usize,
+    #[cfg(feature = "alloc-id")]
+    alloc_id: usize,
+    allocator: A,
 }
-impl<'c, T: Pointee + 'c> UniquePointer<T> {
+impl<T: Pointee> UniquePointer<T> {
     /// creates a NULL `UniquePointer` ready to be written via [write].
     pub fn null() -> UniquePointer<T> {
-        UniquePointer {
-            mut_addr: 0,
-            mut_ptr: std::ptr::null_mut::<T>(),
-            refs: RefCounter::new(),
-            flags: 0,
-        }
+        UniquePointer::null_in(Global)
     }
 
     /// creates a new `UniquePointer` by effectively
@@ -498,96 +532,41 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
         up
     }
 
-    /// is designed for use within the [Clone] implementation
-    /// of `UniquePointer`.
-    ///
-    /// The [copy] method creates a NULL `UniquePointer` flagged as
-    /// [`is_copy`] such that a double-free does not happen in
-    /// [dealloc].
-    fn copy() -> UniquePointer<T> {
-        let mut up = UniquePointer::<T>::null();
-        up.flags = up.flags | (ISACOPY);
-        up
-    }
-
-    /// produces a copy of a `UniquePointer` which is not a copy in
-    /// the sense that [`UniquePointer::is_copy`] returns true.
-    ///
-    /// Because of that rationale a double-free occurs if there are
-    /// two or more "containers" (e.g.: [struct](std#keyword.struct.html)s and [enum](std#keyword.enum.html)s)
-    /// implementing [Drop] and holding the same propagated
-    /// `UniquePointer` instance. For this reason
-    /// [`UniquePointer::propagate`] is unsafe.
-    ///
-    /// [`UniquePointer::propagate`] can be relatively observed as a
-    /// drop-in replacement to [`UniquePointer::clone`] for cases
-    /// when, for instance, swapping `UniquePointer` "instances"
-    /// between instances of `UniquePointer`-containing (structs
-    /// and/or enums) is desired.
-    ///
-    /// Example
+    /// clones every pointer of `pointers` into a vector allocated
+    /// once, such that a large frontier of nodes may be snapshot
+    /// cheaply. See [`dealloc_many`](Self::dealloc_many) to release
+    /// the clones.
     ///
     /// ```
     /// use unique_pointer::UniquePointer;
-    /// use std::fmt::Debug;
-    /// use std::cmp::PartialEq;
-    ///
-    /// #[derive(Clone, Debug, Hash)]
-    /// pub struct BinaryTreeNode<T: Debug> {
-    ///     pub item: T,
-    ///     pub parent: UniquePointer<BinaryTreeNode<T>>,
-    ///     pub left: UniquePointer<BinaryTreeNode<T>>,
-    ///     pub right: UniquePointer<BinaryTreeNode<T>>,
-    /// }
-    /// impl<T: Debug> BinaryTreeNode<T> {
-    ///     pub fn new(item: T) -> BinaryTreeNode<T> {
-    ///         BinaryTreeNode {
-    ///             item,
-    ///             parent: UniquePointer::null(),
-    ///             left: UniquePointer::null(),
-    ///             right: UniquePointer::null(),
-    ///         }
-    ///     }
-    ///
-    ///     pub fn rotate_left(&mut self) {
-    ///         if self.parent.is_null() {
-    ///             if self.right.is_not_null() {
-    ///                 self.parent = unsafe { self.right.propagate() };
-    ///                 self.right = UniquePointer::null();
-    ///             }
-    ///         }
-    ///     }
-    ///
-    ///     pub fn set_parent(&mut self, parent: &mut BinaryTreeNode<T>) {
-    ///         self.parent = UniquePointer::read_only(parent);
-    ///     }
-    ///
-    ///     pub fn set_left(&mut self, left: &mut BinaryTreeNode<T>) {
-    ///         left.set_parent(self);
-    ///         self.left = UniquePointer::read_only(left);
-    ///     }
-    ///
-    ///     pub fn set_right(&mut self, right: &mut BinaryTreeNode<T>) {
-    ///         right.set_parent(self);
-    ///         self.right = UniquePointer::read_only(right);
-    ///     }
-    /// }
     ///
-    /// let mut node_a = BinaryTreeNode::new("A");
-    /// let mut node_b = BinaryTreeNode::new("B");
-    /// let mut node_c = BinaryTreeNode::new("C");
-    /// node_a.set_left(&mut node_b);
-    /// node_a.set_right(&mut node_c);
+    /// let frontier = vec![UniquePointer::from(1u8), UniquePointer::from(2u8)];
+    /// let mut snapshot = UniquePointer::clone_many(&frontier);
+    /// assert_eq!(frontier[0].refs(), 2);
+    /// assert_eq!(snapshot[1].addr(), frontier[1].addr());
     ///
+    /// UniquePointer::dealloc_many(&mut snapshot);
+    /// assert!(snapshot.iter().all(UniquePointer::is_null));
+    /// assert_eq!(frontier[0].refs(), 1);
     /// ```
-    pub unsafe fn propagate(&self) -> UniquePointer<T> {
-        self.incr_ref();
-        let mut back_node = UniquePointer::<T>::null();
-        back_node.set_mut_ptr(self.mut_ptr, false);
-        back_node.refs = self.refs.clone();
-        back_node.flags = self.flags;
-        back_node
+    #[cfg_attr(feature = "clone-stats", track_caller)]
+    pub fn clone_many(pointers: &[UniquePointer<T>]) -> Vec<UniquePointer<T>> {
+        let mut clones = Vec::with_capacity(pointers.len());
+        for pointer in pointers {
+            clones.push(pointer.clone());
+        }
+        clones
+    }
+
+    /// releases the reference held by every pointer of `pointers`,
+    /// like dropping them would, and leaves them NULL such that
+    /// dropping them afterwards releases nothing.
+    pub fn dealloc_many(pointers: &mut [UniquePointer<T>]) {
+        for pointer in pointers {
+            drop(std::mem::replace(pointer, UniquePointer::null()));
+        }
     }
+
     /// `unlock_reference` extends the lifetime of `&T` to `&'t T` and
     /// unlocks `&'t T` into a `&'t mut T`
     ///
@@ -598,7 +577,8 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
     ///
     /// Example
     ///
-    /// ```
+    #[cfg_attr(not(feature = "no-panic"), doc = "```")]
+    #[cfg_attr(feature = "no-panic", doc = "```ignore")]
     /// use std::fmt::Debug;
     /// use unique_pointer::{RefCounter, UniquePointer};
     ///
@@ -689,13 +669,15 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
         let unlocked = unsafe { std::mem::transmute::<&'t T, &'t mut T>(extended) };
         unlocked
     }
+
     /// calls [`UniquePointer::copy_from_ref`] to create a *read-only* `UniquePointer` from a
     /// reference of `T`, useful for iterating over self-referential
     /// data structures.
     ///
     /// Example:
     ///
-    /// ```
+    #[cfg_attr(not(feature = "no-panic"), doc = "```")]
+    #[cfg_attr(feature = "no-panic", doc = "```ignore")]
     /// use unique_pointer::UniquePointer;
     ///
     /// pub struct Data<'r> {
@@ -741,116 +723,939 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
             mut_ptr: ptr,
             refs: refs,
             flags: (ISACOPY | ISALLOC | WRITTEN),
+            on_drop: OnDrop::Release,
+            align_shift: std::mem::align_of::<T>().trailing_zeros() as u8,
+            group: 0,
+            #[cfg(feature = "alias-check")]
+            handle: crate::alias_check::next_handle(),
+            #[cfg(feature = "alloc-id")]
+            alloc_id: 0,
+            allocator: Global,
         }
     }
 
-    /// returns the value containing both the provenance and
-    /// memory address of a pointer
-    pub fn addr(&self) -> usize {
-        self.mut_addr
+    /// [`copy_from_mut_ptr`](Self::copy_from_mut_ptr) taking a
+    /// [`NonNull<T>`], such that pointers held by unsafe code
+    /// standardized on `NonNull` can be walked via *read-only*
+    /// `UniquePointer`s.
+    pub fn copy_from_non_null(ptr: NonNull<T>, refs: usize) -> UniquePointer<T> {
+        UniquePointer::copy_from_mut_ptr(ptr.as_ptr(), refs)
     }
 
-    /// returns the reference count of a `UniquePointer`
-    pub fn refs(&self) -> usize {
-        *self.refs
+    /// creates a NULL `UniquePointer` whose memory, once allocated
+    /// by writing to it, is aligned like `A`, see
+    /// [`alloc_aligned`](Self::alloc_aligned)
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// #[repr(align(64))]
+    /// struct CacheLine;
+    ///
+    /// let data = UniquePointer::<u64>::with_alignment::<CacheLine>();
+    /// assert_eq!(data.align(), 64);
+    /// ```
+    pub fn with_alignment<A>() -> UniquePointer<T> {
+        let mut up = UniquePointer::<T>::null();
+        up.align_shift = std::mem::align_of::<A>()
+            .max(std::mem::align_of::<T>())
+            .next_power_of_two()
+            .trailing_zeros() as u8;
+        up
     }
 
-    /// returns true if the `UniquePointer` is NULL.
-    pub fn is_null(&self) -> bool {
-        let mut_is_null = self.mut_ptr.is_null();
-        #[cfg(feature = "null-check")]
-        if mut_is_null {
-            assert!(self.mut_addr == 0);
-        } else {
-            assert!(self.mut_addr != 0);
+    /// takes ownership of the written memory behind `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be NULL or have been returned by
+    /// [`into_raw`](Self::into_raw) or [`Box::into_raw`] and must not
+    /// be reclaimed more than once.
+    pub unsafe fn from_raw(ptr: *mut T) -> UniquePointer<T> {
+        let mut up = UniquePointer::<T>::null();
+        if ptr.is_null() {
+            return up;
         }
-        let is_null = mut_is_null;
-        is_null
+        up.set_mut_ptr(ptr, false);
+        crate::frozen::thaw(up.addr());
+        up.flags = ISALLOC | WRITTEN;
+        #[cfg(feature = "alloc-id")]
+        {
+            up.alloc_id = NEXT_ALLOC_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+        up
     }
 
-    /// returns true if the `UniquePointer` is not
-    /// NULL. [`UniquePointer::is_not_null`] is a idiomatic shortcut
-    /// to negating a call to [`UniquePointer::is_null`] such that the
-    /// negation is less likely to be clearly visible.
-    pub fn is_not_null(&self) -> bool {
-        !self.is_null()
+    /// consumes `UniquePointer` without deallocating its memory and
+    /// returns the raw pointer to it, whose memory is only released
+    /// once reclaimed via [`from_raw`](Self::from_raw).
+    pub fn into_raw(mut self) -> *mut T {
+        let ptr = self.mut_ptr;
+        self.set_mut_ptr(std::ptr::null_mut::<T>(), false);
+        self.flags = 0;
+        ptr
     }
 
-    /// returns true if the `UniquePointer` is not a
-    /// copy. [`UniquePointer::is_not_copy`] is a idiomatic shortcut
-    /// to negating a call to [`UniquePointer::is_copy`] such that the
-    /// negation is less likely to be clearly visible.
-    pub fn is_not_copy(&self) -> bool {
-        !self.is_copy()
+    /// Returns a `Box<T>` without dropping T, panics if
+    /// [UniquePointer](Self) points to null.
+    ///
+    /// See [into_box](Self::into_box) for a version that returns
+    /// [`Option<Box<T>>`].
+    ///
+    /// Example boxing a type that does not implement Clone
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    /// use std::collections::BTreeMap;
+    /// use std::fmt::{Display, Debug, Formatter};
+    ///
+    /// pub trait Matcher {
+    ///     fn to_str(&self) -> String;
+    ///     fn to_dbg(&self) -> String {
+    ///         format!("{:#?}", self.to_str())
+    ///     }
+    /// }
+    ///
+    /// impl Debug for dyn Matcher {
+    ///     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    ///         write!(f, "{}", self.to_str())
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// pub enum Match {
+    ///     Literal(String),
+    ///     Rule(Box<Rule>),
+    ///     Matcher(Box<dyn Matcher>),
+    ///     Sequence(Vec<Box<dyn Matcher>>),
+    /// }
+    ///
+    /// pub(crate) static mut RULES: BTreeMap<&'static str, UniquePointer<Match>> = BTreeMap::new();
+    ///
+    /// #[allow(static_mut_refs)]
+    /// pub(crate) fn register_match<T: Display>(string: T, r#match: Match) -> Match {
+    ///     unsafe {
+    ///         RULES.insert(string.to_string().leak(), UniquePointer::from_ref(&r#match));
+    ///     }
+    ///     r#match
+    /// }
+    /// #[derive(Debug)]
+    /// pub struct Rule {
+    ///     sym: String,
+    ///     matcher: Match,
+    /// }
+    /// impl Rule {
+    ///     pub fn new<S: ToString>(symbol: S, matcher: impl Into<Match>) -> Rule {
+    ///         Rule {
+    ///             sym: symbol.to_string(),
+    ///             matcher: matcher.into(),
+    ///         }
+    ///     }
+    ///     pub fn symbol(&self) -> &str {
+    ///         self.sym.as_ref()
+    ///     }
+    /// }
+    /// impl From<Rule> for Match {
+    ///     fn from(rule: Rule) -> Match {
+    ///         let rule = UniquePointer::from(rule);
+    ///         let symbol = rule.inner_ref().symbol();
+    ///         register_match(symbol, Match::Rule(rule.into_box_unchecked()))
+    ///     }
+    /// }
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    pub fn into_box_unchecked(&self) -> Box<T> {
+        if self.is_null() {
+            panic!("NULL POINTER: {:#?}", self);
+        }
+        Box::new(self.read())
     }
 
-    /// returns true if the `UniquePointer` is not NULL
-    /// and is not flagged as a copy, meaning it can be deallocated
-    /// without concern for double-free.
-    pub fn can_dealloc(&self) -> bool {
-        ((self.flags & ISALLOC) == ISALLOC) && self.is_not_copy() && self.is_not_null()
+    /// Returns a `Option<Box<T>>` without dropping T, returns `None`
+    /// if pointing to null.
+    ///
+    /// See [into_box_unchecked](Self::into_box_unchecked) for a
+    /// version that returns [`Box<T>`] and
+    /// [try_into_box](Self::try_into_box) for a version that hands
+    /// over the memory instead of copying the value.
+    pub fn into_box(&self) -> Option<Box<T>> {
+        self.try_read().map(Box::new)
     }
 
-    /// returns true if the `UniquePointer` has been
-    /// allocated and therefore is no longer a NULL pointer.
-    pub fn is_allocated(&self) -> bool {
-        let is_allocated = ((self.flags & ISALLOC) == ISALLOC) && self.is_not_null();
-        is_allocated
+    /// creates a `UniquePointer` which adopts the memory of `boxed`
+    /// rather than moving its value.
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// let boxed = Box::new([7u8; 4096]);
+    /// let addr = std::ptr::from_ref(boxed.as_ref()).addr();
+    /// let up = UniquePointer::from_box(boxed);
+    /// assert_eq!(up.addr(), addr);
+    /// assert_eq!(up.try_into_box().map(|boxed| boxed[0]), Ok(7));
+    /// ```
+    pub fn from_box(boxed: Box<T>) -> UniquePointer<T> {
+        unsafe { UniquePointer::from_raw(Box::into_raw(boxed)) }
     }
 
-    /// returns true if the `UniquePointer` has been written to
-    pub fn is_written(&self) -> bool {
-        let is_written = ((self.flags & WRITTEN) == WRITTEN) && self.is_allocated();
-        is_written
+    /// writes `value` to a new `UniquePointer` whose memory is pinned
+    /// via [`pin_address`](Self::pin_address), such that the value
+    /// backs futures and intrusive structures that must not move.
+    ///
+    /// The methods of `UniquePointer` which overwrite the value or
+    /// move it out, such as [`write`](Self::write),
+    /// [`take`](Self::take), [`replace`](Self::replace) and the swaps,
+    /// refuse pinned memory, and dropping the last pointer leaves the
+    /// memory in place. That is checked at runtime rather than
+    /// enforced by the type: mutable references obtained through
+    /// [`inner_mut`](Self::inner_mut) and the like can still move the
+    /// value, such that callers uphold the [`Pin`] contract
+    /// themselves. `UniquePointer` itself is [`Unpin`] like [`Box`],
+    /// as moving it never moves its value.
+    ///
+    /// ```
+    /// use std::marker::PhantomPinned;
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// #[derive(Debug)]
+    /// struct Intrusive {
+    ///     value: u8,
+    ///     _pinned: PhantomPinned,
+    /// }
+    ///
+    /// let mut pinned = UniquePointer::pin(Intrusive { value: 1, _pinned: PhantomPinned });
+    /// let addr = std::ptr::from_ref(&*pinned).addr();
+    /// unsafe { pinned.as_mut().get_unchecked_mut().value = 2 };
+    /// let moved = pinned;
+    /// assert_eq!(moved.value, 2);
+    /// assert_eq!(std::ptr::from_ref(&*moved).addr(), addr);
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    pub fn pin(value: T) -> Pin<UniquePointer<T>> {
+        let up = UniquePointer::from(value);
+        up.pin_address();
+        unsafe { Pin::new_unchecked(up) }
     }
 
-    /// returns true if a `UniquePointer` is a "copy" of
-    /// another `UniquePointer` in the sense that dropping or
-    /// "hard-deallocating" said `UniquePointer` does not incur a
-    /// double-free.
-    pub fn is_copy(&self) -> bool {
-        ((self.flags & ISACOPY) == ISACOPY)
-    }
+    /// allocates memory for a `T` without initializing it, not even
+    /// zeroing it, such that the value can be built in place before
+    /// [`assume_init`](UniquePointer::assume_init) is called.
+    ///
+    #[cfg_attr(not(feature = "no-panic"), doc = "```")]
+    #[cfg_attr(feature = "no-panic", doc = "```ignore")]
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// #[derive(Debug)]
+    /// struct Pair {
+    ///     left: u8,
+    ///     right: String,
+    /// }
+    ///
+    /// let mut pair = UniquePointer::<Pair>::new_uninit();
+    /// let ptr = pair.inner_mut().as_mut_ptr();
+    /// unsafe {
+    ///     (&raw mut (*ptr).left).write(1);
+    ///     (&raw mut (*ptr).right).write(String::from("right"));
+    /// }
+    /// let pair = unsafe { pair.assume_init() };
+    /// assert_eq!((pair.left, pair.right.as_str()), (1, "right"));
+    /// ```
+    pub fn new_uninit() -> UniquePointer<MaybeUninit<T>> {
+        let mut up = UniquePointer::<MaybeUninit<T>>::null();
+        if let Err(Error::AllocationFailed { size, align }) = up.allocate(up.align(), false) {
+            let layout = Layout::from_size_align(size, align).unwrap_or(Layout::new::<T>());
+            std::alloc::handle_alloc_error(layout);
+        }
+        up.flags |= WRITTEN;
+        up
+    }
+
+    /// hands the memory of `UniquePointer` over to a [`Box<T>`]
+    /// without copying the value, under the conditions
+    /// [`take`](Self::take) moves it out, provided it was written and
+    /// allocated with the layout of `T`, that is neither in a
+    /// [`Group`](crate::group::Group) nor via
+    /// [`alloc_aligned`](Self::alloc_aligned). Returns
+    /// `UniquePointer` untouched otherwise.
+    pub fn try_into_box(mut self) -> std::result::Result<Box<T>, UniquePointer<T>> {
+        if !self.can_dealloc()
+            || self.refs > 1
+            || !self.is_written()
+            || self.is_frozen()
+            || self.is_address_pinned()
+            || self.group != 0
+            || self.align() != std::mem::align_of::<T>()
+        {
+            return Err(self);
+        }
+        crate::pointer_map::released(self.addr());
+        if self.refs.weak() > 0 {
+            self.refs.write(0);
+            self.refs = RefCounter::new();
+        }
+        Ok(unsafe { Box::from_raw(self.into_raw()) })
+    }
+
+    /// creates a [`UniqueWeak`] pointing to the memory of
+    /// `UniquePointer` which, unlike a clone, does not keep it alive,
+    /// such as the back-edge of a child to its parent.
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// let mut parent = UniquePointer::from(String::from("parent"));
+    /// let weak = parent.downgrade();
+    /// assert_eq!(parent.weak_count(), 1);
+    /// assert_eq!(weak.upgrade().map(|up| up.addr()), Some(parent.addr()));
+    ///
+    /// assert_eq!(parent.take(), Some(String::from("parent")));
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    pub fn downgrade(&self) -> UniqueWeak<T> {
+        UniqueWeak::from_pointer(self)
+    }
+}
+
+impl<'c, T: Pointee + 'c, A: Allocator> UniquePointer<T, A> {
+    /// creates a NULL `UniquePointer` whose memory, once allocated,
+    /// comes from `allocator` and is returned to it when released,
+    /// such that arena, bump or embedded allocators may back it.
+    ///
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use std::alloc::System;
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// let mut data = UniquePointer::<u64, System>::null_in(System);
+    /// data.write(7);
+    /// assert_eq!(data.take(), Some(7));
+    /// ```
+    pub fn null_in(allocator: A) -> UniquePointer<T, A> {
+        UniquePointer {
+            mut_addr: 0,
+            mut_ptr: std::ptr::null_mut::<T>(),
+            refs: RefCounter::new(),
+            flags: 0,
+            on_drop: OnDrop::Release,
+            align_shift: std::mem::align_of::<T>().trailing_zeros() as u8,
+            group: 0,
+            #[cfg(feature = "alias-check")]
+            handle: crate::alias_check::next_handle(),
+            #[cfg(feature = "alloc-id")]
+            alloc_id: 0,
+            allocator,
+        }
+    }
+
+    /// is designed for use within the [Clone] implementation
+    /// of `UniquePointer`.
+    ///
+    /// The [copy_in] method creates a NULL `UniquePointer` flagged as
+    /// [`is_copy`] such that a double-free does not happen in
+    /// [dealloc].
+    fn copy_in(allocator: A) -> UniquePointer<T, A> {
+        let mut up = UniquePointer::null_in(allocator);
+        up.flags = up.flags | (ISACOPY);
+        up
+    }
+
+    /// produces a copy of a `UniquePointer` which is not a copy in
+    /// the sense that [`UniquePointer::is_copy`] returns true.
+    ///
+    /// Because of that rationale a double-free occurs if there are
+    /// two or more "containers" (e.g.: [struct](std#keyword.struct.html)s and [enum](std#keyword.enum.html)s)
+    /// implementing [Drop] and holding the same propagated
+    /// `UniquePointer` instance. For this reason
+    /// [`UniquePointer::propagate`] is unsafe.
+    ///
+    /// [`UniquePointer::propagate`] can be relatively observed as a
+    /// drop-in replacement to [`UniquePointer::clone`] for cases
+    /// when, for instance, swapping `UniquePointer` "instances"
+    /// between instances of `UniquePointer`-containing (structs
+    /// and/or enums) is desired.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    /// use std::fmt::Debug;
+    /// use std::cmp::PartialEq;
+    ///
+    /// #[derive(Clone, Debug, Hash)]
+    /// pub struct BinaryTreeNode<T: Debug> {
+    ///     pub item: T,
+    ///     pub parent: UniquePointer<BinaryTreeNode<T>>,
+    ///     pub left: UniquePointer<BinaryTreeNode<T>>,
+    ///     pub right: UniquePointer<BinaryTreeNode<T>>,
+    /// }
+    /// impl<T: Debug> BinaryTreeNode<T> {
+    ///     pub fn new(item: T) -> BinaryTreeNode<T> {
+    ///         BinaryTreeNode {
+    ///             item,
+    ///             parent: UniquePointer::null(),
+    ///             left: UniquePointer::null(),
+    ///             right: UniquePointer::null(),
+    ///         }
+    ///     }
+    ///
+    ///     pub fn rotate_left(&mut self) {
+    ///         if self.parent.is_null() {
+    ///             if self.right.is_not_null() {
+    ///                 self.parent = unsafe { self.right.propagate() };
+    ///                 self.right = UniquePointer::null();
+    ///             }
+    ///         }
+    ///     }
+    ///
+    ///     pub fn set_parent(&mut self, parent: &mut BinaryTreeNode<T>) {
+    ///         self.parent = UniquePointer::read_only(parent);
+    ///     }
+    ///
+    ///     pub fn set_left(&mut self, left: &mut BinaryTreeNode<T>) {
+    ///         left.set_parent(self);
+    ///         self.left = UniquePointer::read_only(left);
+    ///     }
+    ///
+    ///     pub fn set_right(&mut self, right: &mut BinaryTreeNode<T>) {
+    ///         right.set_parent(self);
+    ///         self.right = UniquePointer::read_only(right);
+    ///     }
+    /// }
+    ///
+    /// let mut node_a = BinaryTreeNode::new("A");
+    /// let mut node_b = BinaryTreeNode::new("B");
+    /// let mut node_c = BinaryTreeNode::new("C");
+    /// node_a.set_left(&mut node_b);
+    /// node_a.set_right(&mut node_c);
+    ///
+    /// ```
+    #[cfg_attr(feature = "clone-stats", track_caller)]
+    pub unsafe fn propagate(&self) -> UniquePointer<T, A>
+    where
+        A: Clone,
+    {
+        self.incr_ref();
+        let mut back_node = UniquePointer::<T, A>::null_in(self.allocator.clone());
+        back_node.set_mut_ptr(self.mut_ptr, false);
+        back_node.refs = self.refs.clone();
+        back_node.flags = self.flags;
+        back_node.align_shift = self.align_shift;
+        back_node.group = self.group;
+        #[cfg(feature = "alloc-id")]
+        {
+            back_node.alloc_id = self.alloc_id;
+        }
+        #[cfg(feature = "clone-stats")]
+        crate::clone_stats::record(
+            self.addr(),
+            crate::clone_stats::CopyKind::Propagate,
+            std::panic::Location::caller(),
+        );
+        back_node
+    }
+
+    /// returns the value containing both the provenance and
+    /// memory address of a pointer
+    pub fn addr(&self) -> usize {
+        self.mut_addr
+    }
+
+    /// returns the [`addr`](Self::addr) of `UniquePointer` along with
+    /// its raw pointer, whose address matches the former unless
+    /// `UniquePointer` is corrupted, see
+    /// [`debug_assert_provenance`](Self::debug_assert_provenance).
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// let data = UniquePointer::from(1u8);
+    /// let (addr, ptr) = data.addr_and_ptr();
+    /// assert_eq!(addr, ptr.addr());
+    /// assert_eq!(unsafe { *ptr }, 1);
+    /// ```
+    pub fn addr_and_ptr(&self) -> (usize, *const T) {
+        (self.mut_addr, self.mut_ptr.cast_const())
+    }
+
+    /// returns true if both `UniquePointer`s point to the same
+    /// memory, as opposed to [`PartialEq`] which compares the values
+    /// inside. Null pointers are equal to one another, and so are
+    /// zero-sized types of equal alignment.
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// let data = UniquePointer::from(String::from("data"));
+    /// let other = UniquePointer::from(String::from("data"));
+    /// assert!(data == other);
+    /// assert!(!data.ptr_eq(&other));
+    /// assert!(data.ptr_eq(&data.clone()));
+    /// ```
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.mut_ptr, other.mut_ptr)
+    }
+
+    /// returns true if `reference` points to the memory of
+    /// `UniquePointer`
+    pub fn ref_eq(&self, reference: &T) -> bool {
+        std::ptr::eq(self.mut_ptr.cast_const(), reference)
+    }
+
+    /// panics in debug builds if the address of `UniquePointer` has
+    /// drifted apart from the address of its raw pointer, such that
+    /// data structures may assert their pointers are consistent after
+    /// manipulating them.
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
+    pub fn debug_assert_provenance(&self) {
+        let (addr, ptr) = self.addr_and_ptr();
+        debug_assert!(
+            addr == ptr.addr(),
+            "{:016x} does not match its raw pointer {:016x}",
+            addr,
+            ptr.addr()
+        );
+    }
+
+    /// returns the reference count of a `UniquePointer`
+    pub fn refs(&self) -> usize {
+        *self.refs
+    }
+
+    /// returns the number of [`UniqueWeak`]s pointing to the memory of
+    /// `UniquePointer`
+    pub fn weak_count(&self) -> usize {
+        self.refs.weak()
+    }
+
+    /// returns true if the `UniquePointer` is NULL.
+    pub fn is_null(&self) -> bool {
+        let mut_is_null = self.mut_ptr.is_null();
+        #[cfg(feature = "null-check")]
+        if mut_is_null {
+            assert!(self.mut_addr == 0);
+        } else {
+            assert!(self.mut_addr != 0);
+        }
+        let is_null = mut_is_null;
+        is_null
+    }
+
+    /// returns true if the `UniquePointer` is not
+    /// NULL. [`UniquePointer::is_not_null`] is a idiomatic shortcut
+    /// to negating a call to [`UniquePointer::is_null`] such that the
+    /// negation is less likely to be clearly visible.
+    pub fn is_not_null(&self) -> bool {
+        !self.is_null()
+    }
+
+    /// returns true if the `UniquePointer` is not a
+    /// copy. [`UniquePointer::is_not_copy`] is a idiomatic shortcut
+    /// to negating a call to [`UniquePointer::is_copy`] such that the
+    /// negation is less likely to be clearly visible.
+    pub fn is_not_copy(&self) -> bool {
+        !self.is_copy()
+    }
+
+    /// returns true if the `UniquePointer` is not NULL
+    /// and is not flagged as a copy, meaning it can be deallocated
+    /// without concern for double-free.
+    pub fn can_dealloc(&self) -> bool {
+        ((self.flags & ISALLOC) == ISALLOC) && self.is_not_copy() && self.is_not_null()
+    }
+
+    /// returns true if the `UniquePointer` has been
+    /// allocated and therefore is no longer a NULL pointer.
+    pub fn is_allocated(&self) -> bool {
+        let is_allocated = ((self.flags & ISALLOC) == ISALLOC) && self.is_not_null();
+        is_allocated
+    }
+
+    /// returns true if the `UniquePointer` has been written to
+    pub fn is_written(&self) -> bool {
+        let is_written = ((self.flags & WRITTEN) == WRITTEN) && self.is_allocated();
+        is_written
+    }
+
+    /// returns true if a `UniquePointer` is a "copy" of
+    /// another `UniquePointer` in the sense that dropping or
+    /// "hard-deallocating" said `UniquePointer` does not incur a
+    /// double-free.
+    pub fn is_copy(&self) -> bool {
+        ((self.flags & ISACOPY) == ISACOPY)
+    }
 
     /// allocates memory in a null `UniquePointer`
     pub fn alloc(&mut self) {
-        if self.is_allocated() {
-            return;
+        self.alloc_aligned(self.align());
+    }
+
+    /// [`alloc`](Self::alloc) or returns [`Error::AllocationFailed`]
+    /// if the allocator runs out of memory, leaving `UniquePointer`
+    /// NULL rather than aborting the process
+    pub fn try_alloc(&mut self) -> Result<()> {
+        self.try_alloc_aligned(self.align())
+    }
+
+    /// allocates memory in a null `UniquePointer` aligned to at least
+    /// `align` bytes, e.g. 64 to keep hot nodes on a cache line of
+    /// their own, rounded up to a power of two no lower than the
+    /// alignment of `T`.
+    ///
+    /// The alignment is recorded such that the memory is released
+    /// with the layout it was allocated with, hence memory handed
+    /// out by [`into_raw`](Self::into_raw) must not be reclaimed by
+    /// [`from_raw`](Self::from_raw) unless `align` is the alignment
+    /// of `T`.
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// let mut data = UniquePointer::<u8>::null();
+    /// data.alloc_aligned(64);
+    /// data.write(1);
+    /// assert_eq!(data.align(), 64);
+    /// assert_eq!(data.addr() % 64, 0);
+    /// ```
+    pub fn alloc_aligned(&mut self, align: usize) {
+        if let Err(Error::AllocationFailed { size, align }) = self.try_alloc_aligned(align) {
+            let layout = Layout::from_size_align(size, align).unwrap_or(Layout::new::<T>());
+            std::alloc::handle_alloc_error(layout);
         }
+    }
 
-        let layout = Layout::new::<T>();
-        let mut_ptr = unsafe {
-            let ptr = std::alloc::alloc_zeroed(layout);
-            if ptr.is_null() {
-                std::alloc::handle_alloc_error(layout);
+    /// [`alloc_aligned`](Self::alloc_aligned) or returns
+    /// [`Error::AllocationFailed`] if `align` is too large or the
+    /// allocator runs out of memory, leaving `UniquePointer` NULL
+    ///
+    /// ```
+    /// use unique_pointer::{Error, UniquePointer};
+    ///
+    /// let mut data = UniquePointer::<u8>::null();
+    /// assert_eq!(
+    ///     data.try_alloc_aligned(1 << 63),
+    ///     Err(Error::AllocationFailed { size: 1, align: 1 << 63 })
+    /// );
+    /// assert!(data.is_null());
+    /// assert_eq!(data.try_alloc_aligned(16), Ok(()));
+    /// assert_eq!(data.addr() % 16, 0);
+    /// ```
+    pub fn try_alloc_aligned(&mut self, align: usize) -> Result<()> {
+        self.allocate(align, true)
+    }
+
+    /// allocates memory aligned to at least `align` bytes, zeroed
+    /// unless `zeroed` is false
+    fn allocate(&mut self, align: usize, zeroed: bool) -> Result<()> {
+        if self.is_allocated() {
+            return Ok(());
+        }
+        let size = std::mem::size_of::<T>();
+        let align = align
+            .max(std::mem::align_of::<T>())
+            .checked_next_power_of_two()
+            .ok_or(Error::AllocationFailed { size, align })?;
+        let layout = Layout::from_size_align(size, align)
+            .map_err(|_| Error::AllocationFailed { size, align })?;
+        let ptr = if size == 0 {
+            std::ptr::without_provenance_mut::<u8>(layout.align())
+        } else {
+            let allocated = if zeroed {
+                self.allocator.allocate_zeroed(layout)
+            } else {
+                self.allocator.allocate(layout)
+            };
+            match allocated {
+                Ok(ptr) => ptr.as_ptr() as *mut u8,
+                Err(_) => return Err(Error::AllocationFailed { size, align }),
             }
-            ptr as *mut T
         };
+        self.align_shift = layout.align().trailing_zeros() as u8;
+        let mut_ptr = ptr as *mut T;
         self.set_mut_ptr(mut_ptr, false);
+        crate::frozen::thaw(self.addr());
         self.flags |= ISALLOC;
+        #[cfg(feature = "clone-stats")]
+        crate::clone_stats::reset(self.addr());
+        #[cfg(feature = "alloc-id")]
+        {
+            self.alloc_id = NEXT_ALLOC_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+        Ok(())
+    }
+
+    /// returns the alignment of the memory of `UniquePointer`, which
+    /// is the alignment of `T` unless allocated via
+    /// [`alloc_aligned`](Self::alloc_aligned) or
+    /// [`with_alignment`](Self::with_alignment)
+    pub fn align(&self) -> usize {
+        1 << self.align_shift
+    }
+
+    /// allocates memory in a null `UniquePointer` out of `group`,
+    /// right after the memory last allocated in it, aligned like
+    /// [`alloc`](Self::alloc) would. The memory is released together
+    /// with `group` and never by [`from_raw`](Self::from_raw).
+    ///
+    /// See [`Group`](crate::group::Group).
+    ///
+    /// # Safety
+    ///
+    /// `group` must outlive `UniquePointer` along with its clones and
+    /// copies, which dangle once `group` is dropped.
+    pub unsafe fn alloc_in_group(&mut self, group: &crate::group::Group) {
+        if self.is_allocated() {
+            return;
+        }
+        let layout = self.layout();
+        let mut_ptr = group.alloc(layout) as *mut T;
+        self.set_mut_ptr(mut_ptr, false);
+        crate::frozen::thaw(self.addr());
+        self.group = group.id;
+        self.flags |= ISALLOC;
+        #[cfg(feature = "clone-stats")]
+        crate::clone_stats::reset(self.addr());
+        #[cfg(feature = "alloc-id")]
+        {
+            self.alloc_id = NEXT_ALLOC_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+    }
+
+    /// returns the id of the [`Group`](crate::group::Group) the
+    /// memory of `UniquePointer` was allocated in, if any
+    pub fn group_id(&self) -> Option<u64> {
+        (self.group != 0).then_some(self.group)
+    }
+
+    /// returns the id of the allocation held by `UniquePointer`,
+    /// unlike [`addr`](Self::addr) ids are never reused, therefore
+    /// they tell allocations apart across free/realloc cycles.
+    ///
+    /// Ids increase monotonically from 1 in the order in which
+    /// allocations happen, clones share the id of the pointer they
+    /// were cloned from and `0` means the `UniquePointer` holds no
+    /// allocation of its own, either because it is NULL or because it
+    /// was created via [`read_only`](Self::read_only) and the like.
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// let a = UniquePointer::from(1u8);
+    /// let b = UniquePointer::from(2u8);
+    /// assert!(a.alloc_id() < b.alloc_id());
+    /// assert_eq!(a.clone().alloc_id(), a.alloc_id());
+    /// assert_eq!(UniquePointer::<u8>::null().alloc_id(), 0);
+    /// ```
+    #[cfg(feature = "alloc-id")]
+    pub fn alloc_id(&self) -> usize {
+        self.alloc_id
+    }
+
+    /// returns the clones and propagations made, in the current
+    /// thread, of the `UniquePointer`s pointing to the same address
+    /// as this one along with their call sites, see
+    /// [`clone_stats`](crate::clone_stats).
+    #[cfg(feature = "clone-stats")]
+    pub fn clone_stats(&self) -> crate::clone_stats::CloneStats {
+        crate::clone_stats::stats(self.addr())
     }
 
     /// compatibility API to a raw mut pointer's [`pointer::cast_mut`].
+    #[cfg(not(feature = "no-panic"))]
     pub fn cast_mut(&self) -> *mut T {
-        if self.is_null() {
-            panic!("NULL POINTER: {:#?}", self);
-        } else {
-            self.mut_ptr
+        match self.try_cast_mut() {
+            Ok(ptr) => ptr,
+            Err(Error::NullPointer) => panic!("NULL POINTER: {:#?}", self),
+            Err(error) => panic!("{}", error),
         }
     }
 
     /// compatibility API to a raw const pointer's [`pointer::cast_const`].
+    #[cfg(not(feature = "no-panic"))]
     pub fn cast_const(&self) -> *const T {
+        match self.try_cast_const() {
+            Ok(ptr) => ptr,
+            Err(_) => panic!("NULL POINTER: {:#?}", self),
+        }
+    }
+
+    /// returns the internal mut pointer, [`Error::NullPointer`] or
+    /// [`Error::Frozen`]
+    pub fn try_cast_mut(&self) -> Result<*mut T> {
+        self.check_thawed()?;
+        Ok(self.try_cast_const()?.cast_mut())
+    }
+
+    /// returns the internal const pointer or [`Error::NullPointer`]
+    pub fn try_cast_const(&self) -> Result<*const T> {
         if self.is_null() {
-            panic!("NULL POINTER: {:#?}", self);
+            Err(Error::NullPointer)
+        } else {
+            Ok(self.mut_ptr.cast_const())
+        }
+    }
+
+    /// returns the internal pointer as [`NonNull<T>`] or `None` if
+    /// `UniquePointer` is NULL, for unsafe code standardized on
+    /// `NonNull`. Unlike [`try_cast_mut`](Self::try_cast_mut) the
+    /// pointer is returned even if its memory is frozen.
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// let data = UniquePointer::from(7u8);
+    /// let ptr = data.as_non_null().unwrap();
+    /// assert_eq!(ptr.addr().get(), data.addr());
+    /// assert_eq!(unsafe { *ptr.as_ref() }, 7);
+    /// assert!(UniquePointer::<u8>::null().as_non_null().is_none());
+    /// ```
+    pub fn as_non_null(&self) -> Option<NonNull<T>> {
+        NonNull::new(self.mut_ptr)
+    }
+
+    /// freezes the memory of `UniquePointer` such that, from then
+    /// on, every `UniquePointer` to it, including clones and
+    /// read-only copies, refuses mutable access:
+    /// [`try_inner_mut`](Self::try_inner_mut) and
+    /// [`try_cast_mut`](Self::try_cast_mut) return [`Error::Frozen`],
+    /// [`as_mut`](Self::as_mut) and [`take`](Self::take) return
+    /// `None` and writers panic, or leave the memory untouched
+    /// when the `no-panic` feature is enabled.
+    ///
+    /// Frozen memory cannot be thawed, hence it may be shared as a
+    /// read-only lookup structure for as long as it lives. See
+    /// [`freeze_graph`](Self::freeze_graph) to freeze a whole
+    /// structure.
+    ///
+    /// ```
+    /// use unique_pointer::{Error, UniquePointer};
+    ///
+    /// let mut data = UniquePointer::from(1u8);
+    /// let mut clone = data.clone();
+    /// data.freeze();
+    ///
+    /// assert!(clone.is_frozen());
+    /// assert_eq!(clone.as_mut(), None);
+    /// assert_eq!(clone.try_inner_mut(), Err(Error::Frozen { addr: data.addr() }));
+    /// assert_eq!(data.as_ref(), Some(&1));
+    /// ```
+    pub fn freeze(&self) {
+        crate::frozen::freeze(self.addr());
+    }
+
+    /// returns true if the memory of `UniquePointer` was frozen via
+    /// [`freeze`](Self::freeze)
+    pub fn is_frozen(&self) -> bool {
+        crate::frozen::is_frozen(self.addr())
+    }
+
+    fn check_thawed(&self) -> Result<()> {
+        if self.is_frozen() {
+            Err(Error::Frozen { addr: self.addr() })
         } else {
-            self.mut_ptr.cast_const()
+            Ok(())
+        }
+    }
+
+    /// pins the memory of `UniquePointer` such that its value stays
+    /// at [`addr`](Self::addr) for as long as the memory lives: from
+    /// then on every `UniquePointer` to it, including clones and
+    /// read-only copies, refuses to move the value elsewhere, that
+    /// is [`try_swap`](Self::try_swap) returns [`Error::Pinned`],
+    /// [`take`](Self::take) returns `None` and [`swap`](Self::swap)
+    /// panics, or leaves both values in place when the `no-panic`
+    /// feature is enabled.
+    ///
+    /// Self-referential structures may therefore rely on the
+    /// addresses captured by their copies rather than on a
+    /// convention. Pinned memory cannot be unpinned.
+    ///
+    /// ```
+    /// use unique_pointer::{Error, UniquePointer};
+    ///
+    /// let mut data = UniquePointer::from(1u8);
+    /// let mut other = UniquePointer::from(2u8);
+    /// let mut clone = data.clone();
+    /// data.pin_address();
+    ///
+    /// assert!(clone.is_address_pinned());
+    /// assert_eq!(clone.try_swap(&mut other), Err(Error::Pinned { addr: data.addr() }));
+    /// assert_eq!(other.try_swap(&mut data), Err(Error::Pinned { addr: data.addr() }));
+    /// drop(clone);
+    /// assert_eq!(data.take(), None);
+    /// assert_eq!(data.as_ref(), Some(&1));
+    /// ```
+    pub fn pin_address(&self) {
+        crate::pinned::pin(self.addr());
+    }
+
+    /// returns true if the memory of `UniquePointer` was pinned via
+    /// [`pin_address`](Self::pin_address)
+    pub fn is_address_pinned(&self) -> bool {
+        crate::pinned::is_pinned(self.addr())
+    }
+
+    /// pins the memory of `UniquePointer` via
+    /// [`pin_address`](Self::pin_address) and returns a pinned
+    /// reference to its value or `None` if it is NULL or was never
+    /// written
+    pub fn as_pin_ref(&self) -> Option<Pin<&'c T>> {
+        let data = self.as_ref()?;
+        self.pin_address();
+        Some(unsafe { Pin::new_unchecked(data) })
+    }
+
+    /// pins the memory of `UniquePointer` via
+    /// [`pin_address`](Self::pin_address) and returns a pinned
+    /// mutable reference to its value or `None` if it is NULL, was
+    /// never written or is frozen
+    #[cfg_attr(feature = "alias-check", track_caller)]
+    pub fn as_pin_mut(&mut self) -> Option<Pin<&'c mut T>> {
+        let data = self.as_mut()?;
+        self.pin_address();
+        Some(unsafe { Pin::new_unchecked(data) })
+    }
+
+    fn check_unpinned(&self) -> Result<()> {
+        if self.is_address_pinned() {
+            Err(Error::Pinned { addr: self.addr() })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// returns true, unless it panics, if the memory of
+    /// `UniquePointer` is frozen and must therefore be left untouched
+    /// by the infallible writers
+    fn refuse_frozen(&self) -> bool {
+        match self.check_thawed() {
+            Ok(()) => false,
+            #[cfg(not(feature = "no-panic"))]
+            Err(error) => panic!("{}", error),
+            #[cfg(feature = "no-panic")]
+            Err(_) => true,
+        }
+    }
+
+    /// returns true, unless it panics, if the memory of
+    /// `UniquePointer` is pinned such that its value must neither be
+    /// overwritten nor moved out by the infallible writers
+    fn refuse_pinned(&self) -> bool {
+        match self.check_unpinned() {
+            Ok(()) => false,
+            #[cfg(not(feature = "no-panic"))]
+            Err(error) => panic!("{}", error),
+            #[cfg(feature = "no-panic")]
+            Err(_) => true,
         }
     }
 
     /// allocates memory and writes the given value into the
     /// newly allocated area.
     pub fn write(&mut self, data: T) {
+        if self.refuse_frozen() || self.refuse_pinned() {
+            return;
+        }
         self.alloc();
 
         unsafe {
@@ -860,9 +1665,88 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
         self.flags |= (WRITTEN);
     }
 
+    /// writes the given value like [`write`](Self::write) or drops it
+    /// and returns [`Error::Frozen`] or [`Error::Pinned`] if the
+    /// memory of `UniquePointer` is frozen or pinned, or
+    /// [`Error::AllocationFailed`] if it cannot be allocated, such
+    /// that running out of memory can be recovered from.
+    ///
+    /// ```
+    /// use unique_pointer::{Error, UniquePointer};
+    ///
+    /// let mut data = UniquePointer::<u8>::null();
+    /// assert_eq!(data.try_write(1), Ok(()));
+    /// data.freeze();
+    /// assert_eq!(data.try_write(2), Err(Error::Frozen { addr: data.addr() }));
+    /// assert_eq!(data.try_read(), Some(1));
+    /// ```
+    pub fn try_write(&mut self, data: T) -> Result<()> {
+        self.check_thawed()?;
+        self.check_unpinned()?;
+        self.try_alloc()?;
+        unsafe {
+            self.mut_ptr.write(data);
+        }
+        self.flags |= WRITTEN;
+        Ok(())
+    }
+
+    /// writes the given value like [`write`](Self::write) unless
+    /// clones share the memory of `UniquePointer`, in which case the
+    /// value is dropped and [`Error::Shared`] returned, such that
+    /// mutation through an alias is detected rather than silently
+    /// observed by every clone.
+    ///
+    /// Copies created via [`read_only`](Self::read_only) and the like
+    /// are not counted.
+    ///
+    /// ```
+    /// use unique_pointer::{Error, UniquePointer};
+    ///
+    /// let mut data = UniquePointer::from(1u8);
+    /// assert_eq!(data.write_exclusive(2), Ok(()));
+    ///
+    /// let clone = data.clone();
+    /// assert_eq!(data.write_exclusive(3), Err(Error::Shared { addr: data.addr(), refs: 2 }));
+    /// assert_eq!(clone.try_read(), Some(2));
+    /// ```
+    pub fn write_exclusive(&mut self, data: T) -> Result<()> {
+        self.check_thawed()?;
+        self.check_unpinned()?;
+        if self.refs() > 1 {
+            return Err(Error::Shared {
+                addr: self.addr(),
+                refs: self.refs(),
+            });
+        }
+        self.write(data);
+        Ok(())
+    }
+
+    /// writes the given value like [`write`](Self::write) and returns
+    /// the number of other clones that observe it, acknowledging that
+    /// the write goes through every clone sharing the memory.
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// let mut data = UniquePointer::from(1u8);
+    /// let clone = data.clone();
+    /// assert_eq!(data.write_shared(2), 1);
+    /// assert_eq!(clone.try_read(), Some(2));
+    /// ```
+    #[must_use = "the number of clones observing the write should be acknowledged"]
+    pub fn write_shared(&mut self, data: T) -> usize {
+        self.write(data);
+        self.refs().saturating_sub(1)
+    }
+
     /// takes a mutable reference to a value and
     /// writes to a `UniquePointer`
     pub fn write_ref_mut(&mut self, data: &mut T) {
+        if self.refuse_frozen() || self.refuse_pinned() {
+            return;
+        }
         self.alloc();
         unsafe {
             let ptr = data as *mut T;
@@ -874,6 +1758,9 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
     /// takes a read-only reference to a value and
     /// writes to a `UniquePointer`
     pub fn write_ref(&mut self, data: &T) {
+        if self.refuse_frozen() || self.refuse_pinned() {
+            return;
+        }
         self.alloc();
         unsafe {
             let ptr = data as *const T;
@@ -887,6 +1774,12 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
         if self.is_null() && other.is_null() {
             return;
         }
+        if self.refuse_frozen() || other.refuse_frozen() {
+            return;
+        }
+        if self.refuse_pinned() || other.refuse_pinned() {
+            return;
+        }
         if self.mut_ptr.is_null() {
             self.alloc();
         }
@@ -898,153 +1791,215 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
         }
     }
 
+    /// swaps values with other `UniquePointer` like
+    /// [`swap`](Self::swap) or returns [`Error::Frozen`] or
+    /// [`Error::Pinned`] leaving both untouched
+    pub fn try_swap(&mut self, other: &mut Self) -> Result<()> {
+        self.check_thawed()?;
+        other.check_thawed()?;
+        self.check_unpinned()?;
+        other.check_unpinned()?;
+        self.swap(other);
+        Ok(())
+    }
+
     /// reads data from memory `UniquePointer`. Panics if
     /// the pointer is either null or allocated but never written to.
+    #[cfg(not(feature = "no-panic"))]
     pub fn read(&self) -> T {
-        if self.is_null() {
-            panic!("NULL POINTER: {:#?}", self);
-        }
-        if !self.is_written() {
-            panic!("{:#?} not written", self);
+        match self.read_checked() {
+            Ok(data) => data,
+            Err(Error::NullPointer) => panic!("NULL POINTER: {:#?}", self),
+            Err(Error::NotWritten { .. }) => panic!("{:#?} not written", self),
+            Err(error) => panic!("{}", error),
         }
-        let ptr = self.cast_const();
-        unsafe { ptr.read() }
     }
 
     /// reads data from memory `UniquePointer`
     pub fn try_read(&self) -> Option<T> {
-        if self.is_null() {
+        self.read_checked().ok()
+    }
+
+    /// reads data from memory `UniquePointer` or returns the reason
+    /// it cannot be read
+    pub fn read_checked(&self) -> Result<T> {
+        let ptr = self.try_cast_const()?;
+        if !self.is_written() {
+            return Err(Error::NotWritten { addr: self.addr() });
+        }
+        Ok(unsafe { ptr.read() })
+    }
+
+    /// moves the value out of `UniquePointer`, deallocating its
+    /// memory and leaving it NULL.
+    ///
+    /// Returns `None` and leaves `UniquePointer` untouched unless it
+    /// is the sole owner of its memory, that is unless it is neither
+    /// a copy nor shared with clones, or if its memory is frozen or
+    /// pinned. Copies created via
+    /// [`read_only`](Self::read_only) and the like dangle afterwards.
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// let mut data = UniquePointer::from(String::from("data"));
+    /// let copy = data.clone();
+    /// assert_eq!(data.take(), None);
+    /// drop(copy);
+    /// assert_eq!(data.take(), Some(String::from("data")));
+    /// assert!(data.is_null());
+    /// ```
+    pub fn take(&mut self) -> Option<T> {
+        if !self.can_dealloc() || self.refs > 1 || self.is_frozen() || self.is_address_pinned() {
             return None;
         }
-        if self.is_written() {
-            Some(self.read())
-        } else {
-            None
+        let data = self.try_read();
+        crate::pointer_map::released(self.addr());
+        self.set_mut_ptr(std::ptr::null_mut::<T>(), true);
+        self.flags = 0;
+        if self.refs.weak() > 0 {
+            self.refs.write(0);
+            self.refs = RefCounter::new();
+        }
+        data
+    }
+
+    /// writes `value` into the memory of `UniquePointer` and moves
+    /// the value previously written out of it, allocating first if
+    /// null in which case `None` is returned. Clones observe the new
+    /// value. Panics, or drops `value` when the `no-panic` feature is
+    /// enabled, if its memory is frozen or pinned.
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// let mut data = UniquePointer::<u8>::null();
+    /// assert_eq!(data.replace(1), None);
+    /// let copy = data.clone();
+    /// assert_eq!(data.replace(2), Some(1));
+    /// assert_eq!(copy.try_read(), Some(2));
+    /// ```
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        if self.refuse_frozen() || self.refuse_pinned() {
+            return None;
+        }
+        if !self.is_written() {
+            self.write(value);
+            return None;
+        }
+        Some(unsafe { self.mut_ptr.replace(value) })
+    }
+
+    /// obtains a read-only reference to the value inside
+    /// `UniquePointer` but does not increment references
+    #[cfg(not(feature = "no-panic"))]
+    #[cfg_attr(feature = "alias-check", track_caller)]
+    pub fn inner_ref(&self) -> &'c T {
+        match self.try_inner_ref() {
+            Ok(data) => data,
+            Err(_) => panic!("NULL POINTER: {:#?}", self),
+        }
+    }
+
+    /// obtains a mutable reference to the value inside
+    /// `UniquePointer` but does not increment references
+    #[cfg(not(feature = "no-panic"))]
+    #[cfg_attr(feature = "alias-check", track_caller)]
+    pub fn inner_mut(&mut self) -> &'c mut T {
+        match self.try_inner_mut() {
+            Ok(data) => data,
+            Err(Error::NullPointer) => panic!("NULL POINTER: {:#?}", self),
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// writes `value` unless `UniquePointer` was written to already
+    /// and returns a mutable reference to the value inside, see
+    /// [`get_or_insert_with`](Self::get_or_insert_with)
+    #[cfg(not(feature = "no-panic"))]
+    #[cfg_attr(feature = "alias-check", track_caller)]
+    pub fn get_or_insert(&mut self, value: T) -> &mut T {
+        self.get_or_insert_with(|| value)
+    }
+
+    /// allocates and writes the value returned by `f` unless
+    /// `UniquePointer` was written to already and returns a mutable
+    /// reference to the value inside. Panics if its memory is frozen,
+    /// or pinned while not written to yet as [`write`](Self::write)
+    /// refuses it.
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// let mut children = UniquePointer::<Vec<u8>>::null();
+    /// children.get_or_insert_with(Vec::new).push(1);
+    /// children.get_or_insert_with(|| unreachable!()).push(2);
+    /// assert_eq!(children.inner_ref(), &vec![1, 2]);
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    #[cfg_attr(feature = "alias-check", track_caller)]
+    pub fn get_or_insert_with(&mut self, f: impl FnOnce() -> T) -> &mut T {
+        if !self.is_written() {
+            self.write(f());
         }
+        self.inner_mut()
     }
 
     /// obtains a read-only reference to the value inside
-    /// `UniquePointer` but does not increment references
-    pub fn inner_ref(&self) -> &'c T {
+    /// `UniquePointer` or [`Error::NullPointer`]
+    #[cfg_attr(feature = "alias-check", track_caller)]
+    pub fn try_inner_ref(&self) -> Result<&'c T> {
         if self.mut_ptr.is_null() {
-            panic!("NULL POINTER: {:#?}", self);
+            return Err(Error::NullPointer);
         }
-        unsafe { std::mem::transmute::<&T, &'c T>(&*self.cast_const()) }
+        #[cfg(feature = "alias-check")]
+        crate::alias_check::record(
+            self.addr(),
+            self.handle,
+            crate::alias_check::BorrowKind::Shared,
+            std::panic::Location::caller(),
+        );
+        Ok(unsafe { std::mem::transmute::<&T, &'c T>(&*self.mut_ptr.cast_const()) })
     }
 
     /// obtains a mutable reference to the value inside
-    /// `UniquePointer` but does not increment references
-    pub fn inner_mut(&mut self) -> &'c mut T {
+    /// `UniquePointer`, [`Error::NullPointer`] or [`Error::Frozen`]
+    #[cfg_attr(feature = "alias-check", track_caller)]
+    pub fn try_inner_mut(&mut self) -> Result<&'c mut T> {
         if self.mut_ptr.is_null() {
-            panic!("NULL POINTER: {:#?}", self);
+            return Err(Error::NullPointer);
         }
-        unsafe { std::mem::transmute::<&mut T, &'c mut T>(&mut *self.mut_ptr) }
+        self.check_thawed()?;
+        #[cfg(feature = "alias-check")]
+        crate::alias_check::record(
+            self.addr(),
+            self.handle,
+            crate::alias_check::BorrowKind::Mutable,
+            std::panic::Location::caller(),
+        );
+        Ok(unsafe { std::mem::transmute::<&mut T, &'c mut T>(&mut *self.mut_ptr) })
     }
 
     /// compatibility layer to [`std::pointer::as_ref`]
+    #[cfg_attr(feature = "alias-check", track_caller)]
     pub fn as_ref(&self) -> Option<&'c T> {
         if self.is_written() {
-            Some(self.inner_ref())
+            self.try_inner_ref().ok()
         } else {
             None
         }
     }
 
     /// compatibility layer to [`std::pointer::as_mut`](std#primitive.pointer.html#formatting-parameters)
+    #[cfg_attr(feature = "alias-check", track_caller)]
     pub fn as_mut(&mut self) -> Option<&'c mut T> {
         if self.is_written() {
-            Some(self.inner_mut())
+            self.try_inner_mut().ok()
         } else {
             None
         }
     }
 
-    /// Returns a `Box<T>` without dropping T, panics if
-    /// [UniquePointer](Self) points to null.
-    ///
-    /// See [into_box](Self::into_box) for a version that returns
-    /// [`Option<Box<T>>`].
-    ///
-    /// Example boxing a type that does not implement Clone
-    ///
-    /// ```
-    /// use unique_pointer::UniquePointer;
-    /// use std::collections::BTreeMap;
-    /// use std::fmt::{Display, Debug, Formatter};
-    ///
-    /// pub trait Matcher {
-    ///     fn to_str(&self) -> String;
-    ///     fn to_dbg(&self) -> String {
-    ///         format!("{:#?}", self.to_str())
-    ///     }
-    /// }
-    ///
-    /// impl Debug for dyn Matcher {
-    ///     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-    ///         write!(f, "{}", self.to_str())
-    ///     }
-    /// }
-    ///
-    /// #[derive(Debug)]
-    /// pub enum Match {
-    ///     Literal(String),
-    ///     Rule(Box<Rule>),
-    ///     Matcher(Box<dyn Matcher>),
-    ///     Sequence(Vec<Box<dyn Matcher>>),
-    /// }
-    ///
-    /// pub(crate) static mut RULES: BTreeMap<&'static str, UniquePointer<Match>> = BTreeMap::new();
-    ///
-    /// #[allow(static_mut_refs)]
-    /// pub(crate) fn register_match<T: Display>(string: T, r#match: Match) -> Match {
-    ///     unsafe {
-    ///         RULES.insert(string.to_string().leak(), UniquePointer::from_ref(&r#match));
-    ///     }
-    ///     r#match
-    /// }
-    /// #[derive(Debug)]
-    /// pub struct Rule {
-    ///     sym: String,
-    ///     matcher: Match,
-    /// }
-    /// impl Rule {
-    ///     pub fn new<S: ToString>(symbol: S, matcher: impl Into<Match>) -> Rule {
-    ///         Rule {
-    ///             sym: symbol.to_string(),
-    ///             matcher: matcher.into(),
-    ///         }
-    ///     }
-    ///     pub fn symbol(&self) -> &str {
-    ///         self.sym.as_ref()
-    ///     }
-    /// }
-    /// impl From<Rule> for Match {
-    ///     fn from(rule: Rule) -> Match {
-    ///         let rule = UniquePointer::from(rule);
-    ///         let symbol = rule.inner_ref().symbol();
-    ///         register_match(symbol, Match::Rule(rule.into_box_unchecked()))
-    ///     }
-    /// }
-    /// ```
-    pub fn into_box_unchecked(&self) -> Box<T> {
-        if self.is_null() {
-            panic!("NULL POINTER: {:#?}", self);
-        }
-        Box::new(self.read())
-    }
-
-    /// Returns a `Option<Box<T>>` without dropping T, returns `None`
-    /// if pointing to null.
-    ///
-    /// See [into_box_unchecked](Self::into_box_unchecked) for a
-    /// version that returns [`Box<T>`].
-    pub fn into_box(&self) -> Option<Box<T>> {
-        if self.is_null() {
-            return None;
-        }
-        Some(self.into_box_unchecked())
-    }
-
     /// deallocates a `UniquePointer`.
     ///
     /// The [soft] boolean argument indicates whether the
@@ -1066,6 +2021,9 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
         }
         if soft && self.refs > 0 {
             self.decr_ref();
+            if self.refs == 0 && self.is_not_copy() {
+                crate::pointer_map::released(self.addr());
+            }
         } else {
             self.free();
         }
@@ -1081,18 +2039,30 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
     /// `UniquePointer` in question is NOT NULL, then it is
     /// deallocated prior to setting it to NULL.
     fn set_mut_ptr(&mut self, ptr: *mut T, dealloc: bool) {
+        #[cfg(not(feature = "no-panic"))]
+        self.debug_assert_provenance();
         if ptr.is_null() {
             if dealloc && self.is_allocated() {
+                crate::pinned::unpin(self.mut_addr);
+                crate::frozen::thaw(self.mut_addr);
                 self.flags = 0;
                 self.mut_addr = 0;
-                let layout = Layout::new::<T>();
-                unsafe {
-                    std::alloc::dealloc(self.mut_ptr as *mut u8, layout);
-                };
+                let layout = self.layout();
+                if self.group == 0 && layout.size() > 0 {
+                    unsafe {
+                        let ptr = NonNull::new_unchecked(self.mut_ptr as *mut u8);
+                        self.allocator.deallocate(ptr, layout);
+                    };
+                }
+                self.group = 0;
                 self.mut_ptr = std::ptr::null_mut::<T>();
             }
 
             self.set_mut_addr(0);
+            #[cfg(feature = "alloc-id")]
+            {
+                self.alloc_id = 0;
+            }
         } else {
             self.set_mut_addr(UniquePointer::<T>::provenance_of_mut_ptr(ptr));
         }
@@ -1105,6 +2075,38 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
         self.dealloc(true);
     }
 
+    /// sets the policy applied when this very `UniquePointer` is
+    /// dropped, clones and copies start out with
+    /// [`OnDrop::Release`] regardless.
+    ///
+    #[cfg_attr(not(feature = "no-panic"), doc = "```")]
+    #[cfg_attr(feature = "no-panic", doc = "```ignore")]
+    /// use unique_pointer::{OnDrop, UniquePointer};
+    ///
+    /// let mut data = UniquePointer::from(1u8);
+    /// data.set_on_drop(OnDrop::Panic);
+    /// let mut clone = data.clone();
+    /// clone.set_on_drop(OnDrop::Leak);
+    /// drop(clone);
+    /// assert_eq!(data.refs(), 2);
+    /// data.set_on_drop(OnDrop::Release);
+    /// ```
+    pub fn set_on_drop(&mut self, on_drop: OnDrop) {
+        self.on_drop = on_drop;
+    }
+
+    /// returns the policy applied when `UniquePointer` is dropped
+    pub fn on_drop(&self) -> OnDrop {
+        self.on_drop
+    }
+
+    /// returns the layout the memory of `UniquePointer` is allocated
+    /// with
+    fn layout(&self) -> Layout {
+        Layout::from_size_align(std::mem::size_of::<T>(), self.align())
+            .unwrap_or_else(|_| Layout::new::<T>())
+    }
+
     fn set_mut_addr(&mut self, addr: usize) {
         self.mut_addr = addr;
     }
@@ -1120,6 +2122,7 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
             return;
         }
         if !self.is_null() {
+            crate::pointer_map::released(self.addr());
             self.set_mut_ptr(std::ptr::null_mut::<T>(), false);
             self.refs.drain();
         }
@@ -1146,6 +2149,8 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
     ///     }
     /// }
     /// ```
+    #[cfg(not(feature = "no-panic"))]
+    #[cfg_attr(feature = "alias-check", track_caller)]
     pub fn extend_lifetime<'t>(&self) -> &'t T {
         unsafe { std::mem::transmute::<&T, &'t T>(self.inner_ref()) }
     }
@@ -1170,6 +2175,8 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
     ///     }
     /// }
     /// ```
+    #[cfg(not(feature = "no-panic"))]
+    #[cfg_attr(feature = "alias-check", track_caller)]
     pub fn extend_lifetime_mut<'t>(&mut self) -> &'t mut T {
         unsafe { std::mem::transmute::<&mut T, &'t mut T>(self.inner_mut()) }
     }
@@ -1199,6 +2206,257 @@ impl<T: Pointee> UniquePointer<T> {
     pub fn provenance_of_mut(mut ptr: &mut T) -> usize {
         (&raw mut ptr).expose_provenance()
     }
+
+    /// hints the CPU to fetch the memory of `UniquePointer` into its
+    /// caches ahead of reading it, such that pointer-chasing
+    /// traversals overlap the latency of loading the next node with
+    /// the work on the current one.
+    ///
+    /// Prefetching never faults, hence it is a no-op for NULL
+    /// pointers as well as on architectures without prefetch hints.
+    pub fn prefetch_read(&self) {
+        prefetch(self.mut_ptr, false);
+    }
+
+    /// hints the CPU to fetch the memory of `UniquePointer` into its
+    /// caches ahead of writing to it, see
+    /// [`prefetch_read`](Self::prefetch_read)
+    pub fn prefetch_write(&self) {
+        prefetch(self.mut_ptr, true);
+    }
+}
+
+/// issues a prefetch hint for the memory behind `ptr`, which may be
+/// NULL or dangling
+#[inline]
+pub(crate) fn prefetch<T>(ptr: *const T, write: bool) {
+    if ptr.is_null() {
+        return;
+    }
+    #[cfg(target_arch = "x86_64")]
+    unsafe {
+        use std::arch::x86_64::{_mm_prefetch, _MM_HINT_ET0, _MM_HINT_T0};
+        if write {
+            _mm_prefetch::<_MM_HINT_ET0>(ptr.cast());
+        } else {
+            _mm_prefetch::<_MM_HINT_T0>(ptr.cast());
+        }
+    }
+}
+
+impl<T: GraphNode> UniquePointer<T> {
+    /// returns a formatting wrapper that renders, via `{:?}` or
+    /// `{:#?}`, the subgraph reachable from this pointer up to
+    /// `depth` edges away rather than only the pointee, see
+    /// [`DebugGraph`] and [`GraphNode`].
+    pub fn debug_graph(&self, depth: usize) -> DebugGraph<'_, T> {
+        DebugGraph::new(self, depth)
+    }
+
+    /// [freezes](Self::freeze) the memory of `UniquePointer` along
+    /// with that of every node reachable from it through the
+    /// [edges](GraphNode::edges) of its pointee, cycles included.
+    pub fn freeze_graph(&self) {
+        let mut pending = vec![self];
+        while let Some(pointer) = pending.pop() {
+            if !crate::frozen::freeze(pointer.addr()) {
+                continue;
+            }
+            if let Some(node) = pointer.as_ref() {
+                pending.extend(node.edges().into_iter().map(|(_, edge)| edge));
+            }
+        }
+    }
+
+    /// frees the graph reachable from `UniquePointer`, leaving it
+    /// NULL, and passes every node to `visitor` right before dropping
+    /// it, children before parents, returning the number of nodes
+    /// freed.
+    ///
+    /// # Safety
+    ///
+    /// See [`free_graph_with`](crate::free_graph_with).
+    ///
+    /// ```
+    /// use std::fmt::Formatter;
+    /// use unique_pointer::{GraphNode, UniquePointer};
+    ///
+    /// #[derive(Debug)]
+    /// struct Node {
+    ///     name: String,
+    ///     child: UniquePointer<Node>,
+    /// }
+    ///
+    /// impl GraphNode for Node {
+    ///     fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+    ///         vec![("child", &self.child)]
+    ///     }
+    ///
+    ///     fn label(&self, f: &mut Formatter) -> std::fmt::Result {
+    ///         write!(f, "{}", self.name)
+    ///     }
+    /// }
+    ///
+    /// let child = UniquePointer::from(Node {
+    ///     name: String::from("child"),
+    ///     child: UniquePointer::null(),
+    /// });
+    /// let mut root = UniquePointer::from(Node {
+    ///     name: String::from("root"),
+    ///     child,
+    /// });
+    ///
+    /// let mut names = Vec::new();
+    /// assert_eq!(unsafe { root.drop_with(|node| names.push(std::mem::take(&mut node.name))) }, 2);
+    /// assert_eq!(names, vec!["child", "root"]);
+    /// assert!(root.is_null());
+    /// ```
+    pub unsafe fn drop_with(&mut self, visitor: impl FnMut(&mut T)) -> usize {
+        let root = std::mem::replace(self, UniquePointer::null());
+        unsafe { crate::free_graph_with(&mut [root], visitor) }
+    }
+}
+
+/// associated functions named after those of [`Rc`](std::rc::Rc),
+/// such that code migrated from `Rc` to `UniquePointer` compiles by
+/// replacing the type name alone.
+///
+/// ```
+/// use unique_pointer::UniquePointer;
+///
+/// let mut data = UniquePointer::new(String::from("data"));
+/// let clone = data.clone();
+/// assert_eq!(UniquePointer::strong_count(&data), 2);
+/// assert_eq!(UniquePointer::get_mut(&mut data), None);
+///
+/// let clone = UniquePointer::try_unwrap(clone).unwrap_err();
+/// drop(clone);
+/// UniquePointer::get_mut(&mut data).unwrap().push('!');
+/// assert_eq!(UniquePointer::try_unwrap(data), Ok(String::from("data!")));
+/// ```
+#[cfg(feature = "rc-compat")]
+impl<'c, T: Pointee + 'c> UniquePointer<T> {
+    /// [`From<T>`] under the name of [`Rc::new`](std::rc::Rc::new)
+    pub fn new(value: T) -> UniquePointer<T> {
+        UniquePointer::from(value)
+    }
+
+    /// [`refs`](Self::refs) under the name of
+    /// [`Rc::strong_count`](std::rc::Rc::strong_count)
+    pub fn strong_count(this: &Self) -> usize {
+        this.refs()
+    }
+
+    /// returns a mutable reference to the value of `this` unless it
+    /// is shared by clones, NULL or frozen, like
+    /// [`Rc::get_mut`](std::rc::Rc::get_mut)
+    pub fn get_mut(this: &mut Self) -> Option<&'c mut T> {
+        if this.refs() > 1 {
+            return None;
+        }
+        this.try_inner_mut().ok()
+    }
+
+    /// [`take`](Self::take) handing `this` back when it fails, like
+    /// [`Rc::try_unwrap`](std::rc::Rc::try_unwrap)
+    pub fn try_unwrap(mut this: Self) -> std::result::Result<T, Self> {
+        match this.take() {
+            Some(value) => Ok(value),
+            None => Err(this),
+        }
+    }
+
+    /// moves the value of `UniquePointer` into an [`Rc`] under the
+    /// conditions of [`try_unwrap`](Self::try_unwrap), handing
+    /// `UniquePointer` back otherwise.
+    pub fn try_into_rc(self) -> std::result::Result<Rc<T>, UniquePointer<T>> {
+        UniquePointer::try_unwrap(self).map(Rc::new)
+    }
+
+    /// moves the value of `UniquePointer` into an [`Arc`] under the
+    /// conditions of [`try_unwrap`](Self::try_unwrap), handing
+    /// `UniquePointer` back otherwise.
+    pub fn try_into_arc(self) -> std::result::Result<Arc<T>, UniquePointer<T>> {
+        UniquePointer::try_unwrap(self).map(Arc::new)
+    }
+}
+
+impl<T: Pointee, A: Allocator> UniquePointer<MaybeUninit<T>, A> {
+    /// converts `UniquePointer<MaybeUninit<T>>` into
+    /// `UniquePointer<T>` pointing to the same memory, sharing the
+    /// reference count of its clones.
+    ///
+    /// # Safety
+    ///
+    /// The value must have been fully initialized, see
+    /// [`MaybeUninit::assume_init`].
+    pub unsafe fn assume_init(self) -> UniquePointer<T, A> {
+        let this = ManuallyDrop::new(self);
+        unsafe {
+            UniquePointer {
+                mut_addr: this.mut_addr,
+                mut_ptr: this.mut_ptr.cast::<T>(),
+                refs: std::ptr::read(&this.refs),
+                flags: this.flags,
+                on_drop: this.on_drop,
+                align_shift: this.align_shift,
+                group: this.group,
+                #[cfg(feature = "alias-check")]
+                handle: this.handle,
+                #[cfg(feature = "alloc-id")]
+                alloc_id: this.alloc_id,
+                allocator: std::ptr::read(&this.allocator),
+            }
+        }
+    }
+}
+
+impl<T: Pointee> UniquePointer<Vec<T>> {
+    /// returns a slice of the pointee `Vec` or an empty slice if
+    /// the pointer was never written, sparing
+    /// `inner_ref().as_slice()` chains.
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// let up = UniquePointer::from(vec![1, 2, 3]);
+    /// assert_eq!(up.as_slice(), &[1, 2, 3]);
+    /// assert_eq!(UniquePointer::<Vec<u8>>::null().as_slice(), &[]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        match self.as_ref() {
+            Some(vec) => vec.as_slice(),
+            None => &[],
+        }
+    }
+
+    /// returns a mutable slice of the pointee `Vec` or an empty
+    /// slice if the pointer was never written.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match self.as_mut() {
+            Some(vec) => vec.as_mut_slice(),
+            None => &mut [],
+        }
+    }
+}
+
+impl UniquePointer<String> {
+    /// returns the pointee `String` as `&str` or an empty string if
+    /// the pointer was never written.
+    ///
+    /// ```
+    /// use unique_pointer::UniquePointer;
+    ///
+    /// let up = UniquePointer::from(String::from("unique"));
+    /// assert_eq!(up.as_str(), "unique");
+    /// assert_eq!(UniquePointer::<String>::null().as_str(), "");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        match self.as_ref() {
+            Some(string) => string.as_str(),
+            None => "",
+        }
+    }
 }
 
 #[allow(unused)]
@@ -1224,7 +2482,32 @@ impl<'c, T: Pointee + 'c> UniquePointer<T> {
     }
 }
 #[allow(invalid_reference_casting)]
-impl<T: Pointee> UniquePointer<T> {
+impl<T: Pointee, A: Allocator> UniquePointer<T, A> {
+    /// returns a pointer to the memory of `UniquePointer` sharing its
+    /// reference count, which is left untouched
+    pub(crate) fn share(&self) -> UniquePointer<T, A>
+    where
+        A: Clone,
+    {
+        let mut clone = UniquePointer::<T, A>::copy_in(self.allocator.clone());
+        clone.set_mut_ptr(self.mut_ptr, false);
+        clone.refs = self.refs.clone();
+        clone.flags = self.flags;
+        clone.align_shift = self.align_shift;
+        clone.group = self.group;
+        #[cfg(feature = "alloc-id")]
+        {
+            clone.alloc_id = self.alloc_id;
+        }
+        clone
+    }
+
+    /// returns the [`RefCounter`] shared by the clones of
+    /// `UniquePointer`
+    pub(crate) fn ref_counter(&self) -> &RefCounter {
+        &self.refs
+    }
+
     fn incr_ref(&self) {
         if self.is_null() {
             return;
@@ -1239,7 +2522,9 @@ impl<T: Pointee> UniquePointer<T> {
         self.refs.decr();
     }
 }
-impl<T: Pointee> AsRef<T> for UniquePointer<T> {
+#[cfg(not(feature = "no-panic"))]
+impl<T: Pointee, A: Allocator> AsRef<T> for UniquePointer<T, A> {
+    #[cfg_attr(feature = "alias-check", track_caller)]
     fn as_ref(&self) -> &T {
         if self.is_null() {
             panic!("NULL POINTER: {:#?}", self);
@@ -1247,7 +2532,9 @@ impl<T: Pointee> AsRef<T> for UniquePointer<T> {
         self.inner_ref()
     }
 }
-impl<T: Pointee> AsMut<T> for UniquePointer<T> {
+#[cfg(not(feature = "no-panic"))]
+impl<T: Pointee, A: Allocator> AsMut<T> for UniquePointer<T, A> {
+    #[cfg_attr(feature = "alias-check", track_caller)]
     fn as_mut(&mut self) -> &mut T {
         if self.is_null() {
             panic!("NULL POINTER: {:#?}", self);
@@ -1256,23 +2543,39 @@ impl<T: Pointee> AsMut<T> for UniquePointer<T> {
     }
 }
 
-impl<T: Pointee> Deref for UniquePointer<T> {
+#[cfg(not(feature = "no-panic"))]
+impl<T: Pointee, A: Allocator> Deref for UniquePointer<T, A> {
     type Target = T;
 
+    #[cfg_attr(feature = "alias-check", track_caller)]
     fn deref(&self) -> &T {
         self.inner_ref()
     }
 }
 
-impl<T: Pointee> DerefMut for UniquePointer<T> {
+#[cfg(not(feature = "no-panic"))]
+impl<T: Pointee, A: Allocator> DerefMut for UniquePointer<T, A> {
+    #[cfg_attr(feature = "alias-check", track_caller)]
     fn deref_mut(&mut self) -> &mut T {
         self.inner_mut()
     }
 }
 
-impl<T: Pointee> Drop for UniquePointer<T> {
+impl<T: Pointee, A: Allocator> Drop for UniquePointer<T, A> {
     fn drop(&mut self) {
-        self.drop_in_place();
+        #[cfg(feature = "alias-check")]
+        crate::alias_check::release(self.handle);
+        match self.on_drop {
+            OnDrop::Release => self.drop_in_place(),
+            OnDrop::Leak => {}
+            #[cfg(not(feature = "no-panic"))]
+            OnDrop::Panic => {
+                if self.can_dealloc() && self.refs <= 1 && !std::thread::panicking() {
+                    panic!("{:016x} dropped while owning its memory", self.addr());
+                }
+                self.drop_in_place();
+            }
+        }
     }
 }
 
@@ -1286,6 +2589,13 @@ impl<T: Pointee> From<&mut T> for UniquePointer<T> {
         UniquePointer::<T>::from_ref_mut(data)
     }
 }
+/// creates a *read-only* `UniquePointer` via
+/// [`copy_from_non_null`](UniquePointer::copy_from_non_null)
+impl<T: Pointee> From<NonNull<T>> for UniquePointer<T> {
+    fn from(ptr: NonNull<T>) -> UniquePointer<T> {
+        UniquePointer::copy_from_non_null(ptr, 1)
+    }
+}
 impl<T: Pointee> From<T> for UniquePointer<T> {
     fn from(data: T) -> UniquePointer<T> {
         let mut up = UniquePointer::<T>::null();
@@ -1293,16 +2603,40 @@ impl<T: Pointee> From<T> for UniquePointer<T> {
         up
     }
 }
+
+/// moves the value out of `rc` if it holds the only strong
+/// reference, clones it otherwise. Given [`From<T>`], the pointee
+/// must be named, as in `UniquePointer::<T>::from(rc)`.
+#[cfg(feature = "rc-compat")]
+impl<T: Pointee + Clone> From<Rc<T>> for UniquePointer<T> {
+    fn from(rc: Rc<T>) -> UniquePointer<T> {
+        UniquePointer::from(Rc::unwrap_or_clone(rc))
+    }
+}
+
+/// moves the value out of `arc` if it holds the only strong
+/// reference, clones it otherwise
+#[cfg(feature = "rc-compat")]
+impl<T: Pointee + Clone> From<Arc<T>> for UniquePointer<T> {
+    fn from(arc: Arc<T>) -> UniquePointer<T> {
+        UniquePointer::from(Arc::unwrap_or_clone(arc))
+    }
+}
+
 /// The [Clone] implementation of `UniquePointer` is special
 /// because it flags cloned values as clones such that a double-free
 /// doesn not occur.
-impl<T: Pointee> Clone for UniquePointer<T> {
-    fn clone(&self) -> UniquePointer<T> {
+impl<T: Pointee, A: Allocator + Clone> Clone for UniquePointer<T, A> {
+    #[cfg_attr(feature = "clone-stats", track_caller)]
+    fn clone(&self) -> UniquePointer<T, A> {
         self.incr_ref();
-        let mut clone = UniquePointer::<T>::copy();
-        clone.set_mut_ptr(self.mut_ptr, false);
-        clone.refs = self.refs.clone();
-        clone.flags = self.flags;
+        let clone = self.share();
+        #[cfg(feature = "clone-stats")]
+        crate::clone_stats::record(
+            self.addr(),
+            crate::clone_stats::CopyKind::Clone,
+            std::panic::Location::caller(),
+        );
         clone
     }
 }
@@ -1313,19 +2647,19 @@ impl<T: Pointee> Pointer for UniquePointer<T> {
     }
 }
 
-impl<T: Pointee> Debug for UniquePointer<T> {
+impl<T: Pointee, A: Allocator> Debug for UniquePointer<T, A> {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(
             f,
             "UniquePointer{}",
             [
                 format!("{:016x}", self.addr()),
-                if self.is_not_null() {
+                if let Ok(src) = self.try_inner_ref() {
                     [
                         #[cfg(not(feature = "allow-no-debug"))]
-                        format!("[src={:#?}]", self.inner_ref()),
+                        format!("[src={:#?}]", src),
                         #[cfg(feature = "allow-no-debug")]
-                        format!("[src={:p}]", self.inner_ref()),
+                        format!("[src={:p}]", src),
                         format!("[refs={}]", self.refs),
                     ]
                     .join("")
@@ -1338,6 +2672,8 @@ impl<T: Pointee> Debug for UniquePointer<T> {
                     .join("")
                 },
                 format!("[is_copy={}]", self.is_copy()),
+                #[cfg(feature = "alloc-id")]
+                format!("[alloc_id={}]", self.alloc_id),
             ]
             .join("")
         )
@@ -1349,11 +2685,11 @@ impl<T: Pointee + PartialEq> PartialEq<UniquePointer<T>> for UniquePointer<T> {
         if self.addr() == fles.addr() {
             return true;
         }
-        if self.is_null() {
-            let eq = fles.is_null();
-            return eq;
+        match (self.try_inner_ref(), fles.try_inner_ref()) {
+            (Ok(lhs), Ok(rhs)) => lhs.eq(rhs),
+            (Err(_), Err(_)) => true,
+            _ => false,
         }
-        self.inner_ref().eq(fles.inner_ref())
     }
 }
 impl<T: Pointee + Eq> Eq for UniquePointer<T> {}
@@ -1365,7 +2701,9 @@ impl<T: Pointee + PartialOrd> PartialOrd<UniquePointer<T>> for UniquePointer<T>
         if self.addr() == other.addr() {
             return Some(Ordering::Equal);
         }
-        self.inner_ref().partial_cmp(other.inner_ref())
+        self.try_inner_ref()
+            .ok()?
+            .partial_cmp(other.try_inner_ref().ok()?)
     }
 }
 
@@ -1374,7 +2712,7 @@ impl<T: Pointee + PartialOrd> PartialOrd<T> for UniquePointer<T> {
         if self.is_null() {
             return None;
         }
-        self.inner_ref().partial_cmp(other)
+        self.try_inner_ref().ok()?.partial_cmp(other)
     }
 }
 impl<T: Pointee + PartialEq> PartialEq<T> for UniquePointer<T> {
@@ -1382,7 +2720,7 @@ impl<T: Pointee + PartialEq> PartialEq<T> for UniquePointer<T> {
         if self.is_null() {
             return false;
         }
-        self.inner_ref().eq(other)
+        self.try_inner_ref().is_ok_and(|data| data.eq(other))
     }
 }
 
@@ -1391,7 +2729,11 @@ impl<T: Pointee + Ord> Ord for UniquePointer<T> {
         if self.is_null() {
             return Ordering::Less;
         }
-        self.inner_ref().cmp(other.inner_ref())
+        match (self.try_inner_ref(), other.try_inner_ref()) {
+            (Ok(lhs), Ok(rhs)) => lhs.cmp(rhs),
+            (Ok(_), Err(_)) => Ordering::Greater,
+            (Err(_), _) => Ordering::Less,
+        }
     }
 }
 
@@ -1488,3 +2830,112 @@ impl<T: Pointee> Hash for UniquePointer<T> {
 //         T::hash(self, state);
 //     }
 // }
+
+/// The [`std::io::Read`] implementation of `UniquePointer` delegates
+/// to the pointee and errors with [`std::io::ErrorKind::InvalidInput`]
+/// if it was never written.
+///
+/// Since the inherent [`UniquePointer::read`] takes precedence over
+/// [`std::io::Read::read`] in method-call syntax, call the latter as
+/// `Read::read(&mut pointer, buf)` or use any of its provided methods.
+///
+/// ```
+/// use std::io::{Cursor, Read};
+/// use unique_pointer::UniquePointer;
+///
+/// let mut pointer = UniquePointer::from(Cursor::new(b"data".to_vec()));
+/// let mut data = String::new();
+/// pointer.read_to_string(&mut data).unwrap();
+/// assert_eq!(data, "data");
+/// ```
+impl<T: Pointee + Read> Read for UniquePointer<T> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.written_io_mut()?.read(buf)
+    }
+}
+/// The [`std::io::Write`] implementation of `UniquePointer` delegates
+/// to the pointee and errors with [`std::io::ErrorKind::InvalidInput`]
+/// if it was never written.
+///
+/// Since the inherent [`UniquePointer::write`] takes precedence over
+/// [`std::io::Write::write`] in method-call syntax, call the latter as
+/// `Write::write(&mut pointer, buf)` or use any of its provided methods.
+impl<T: Pointee + Write> Write for UniquePointer<T> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.written_io_mut()?.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.written_io_mut()?.flush()
+    }
+}
+/// The [`std::io::Seek`] implementation of `UniquePointer` delegates
+/// to the pointee and errors with [`std::io::ErrorKind::InvalidInput`]
+/// if it was never written.
+impl<T: Pointee + Seek> Seek for UniquePointer<T> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.written_io_mut()?.seek(pos)
+    }
+}
+/// The [`Iterator`] implementation of `UniquePointer` delegates to
+/// the pointee and yields nothing if it was never written.
+impl<T: Pointee + Iterator> Iterator for UniquePointer<T> {
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<T::Item> {
+        self.as_mut()?.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.as_ref() {
+            Some(iter) => iter.size_hint(),
+            None => (0, Some(0)),
+        }
+    }
+}
+impl<'c, T: Pointee + 'c> UniquePointer<T> {
+    fn written_io_mut(&mut self) -> std::io::Result<&'c mut T> {
+        match self.as_mut() {
+            Some(inner) => Ok(inner),
+            None => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("NULL POINTER: {:016x}", self.addr()),
+            )),
+        }
+    }
+}
+
+impl<T: Pointee> PointerLike<T> for UniquePointer<T> {
+    type Ref<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type RefMut<'a>
+        = &'a mut T
+    where
+        Self: 'a;
+
+    fn null() -> UniquePointer<T> {
+        UniquePointer::null()
+    }
+
+    fn write(&mut self, data: T) {
+        UniquePointer::write(self, data)
+    }
+
+    fn as_ref(&self) -> Option<&T> {
+        UniquePointer::as_ref(self)
+    }
+
+    fn as_mut(&mut self) -> Option<&mut T> {
+        UniquePointer::as_mut(self)
+    }
+
+    fn clone_shared(&self) -> UniquePointer<T> {
+        self.clone()
+    }
+
+    fn is_null(&self) -> bool {
+        UniquePointer::is_null(self)
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::{OnDrop, Pointee, UniquePointer};
+
+/// [UniqueWeak](Self) points to the memory of a [`UniquePointer`]
+/// without keeping it alive, such that parent/child structures can
+/// link children back to their parents, or lists link nodes back to
+/// their predecessors, without reference cycles.
+///
+/// [`upgrade`](Self::upgrade) returns a clone of the `UniquePointer`
+/// as long as any of its clones is alive and its memory was not
+/// taken out via [`take`](UniquePointer::take).
+///
+#[cfg_attr(not(feature = "no-panic"), doc = "```")]
+#[cfg_attr(feature = "no-panic", doc = "```ignore")]
+/// use unique_pointer::{UniquePointer, UniqueWeak};
+///
+/// #[derive(Debug)]
+/// struct Node {
+///     value: u8,
+///     parent: UniqueWeak<Node>,
+///     child: UniquePointer<Node>,
+/// }
+///
+/// let mut parent = UniquePointer::from(Node {
+///     value: 1,
+///     parent: UniqueWeak::new(),
+///     child: UniquePointer::null(),
+/// });
+/// let child = UniquePointer::from(Node {
+///     value: 2,
+///     parent: parent.downgrade(),
+///     child: UniquePointer::null(),
+/// });
+/// parent.inner_mut().child = child.clone();
+///
+/// assert_eq!(parent.refs(), 1);
+/// assert_eq!(child.parent.upgrade().map(|parent| parent.value), Some(1));
+/// drop(parent);
+/// assert!(child.parent.upgrade().is_none());
+/// ```
+pub struct UniqueWeak<T: Pointee> {
+    pointer: UniquePointer<T>,
+}
+
+impl<T: Pointee> UniqueWeak<T> {
+    /// creates a [UniqueWeak](Self) which never upgrades
+    pub fn new() -> UniqueWeak<T> {
+        UniqueWeak::from_pointer(&UniquePointer::null())
+    }
+
+    pub(crate) fn from_pointer(pointer: &UniquePointer<T>) -> UniqueWeak<T> {
+        let mut pointer = pointer.share();
+        pointer.set_on_drop(OnDrop::Leak);
+        if !pointer.is_null() {
+            pointer.ref_counter().incr_weak();
+        }
+        UniqueWeak { pointer }
+    }
+
+    /// returns a clone of the `UniquePointer` this was downgraded
+    /// from unless its memory was released
+    pub fn upgrade(&self) -> Option<UniquePointer<T>> {
+        if self.strong_count() == 0 {
+            return None;
+        }
+        Some(self.pointer.clone())
+    }
+
+    /// returns the number of `UniquePointer`s keeping the memory
+    /// alive, zero once released
+    pub fn strong_count(&self) -> usize {
+        if self.pointer.is_null() {
+            0
+        } else {
+            self.pointer.refs()
+        }
+    }
+
+    /// returns the number of [UniqueWeak](Self)s pointing to the
+    /// memory, this one included
+    pub fn weak_count(&self) -> usize {
+        self.pointer.weak_count()
+    }
+
+    /// returns the address the [UniqueWeak](Self) points to, which may
+    /// have been released already
+    pub fn addr(&self) -> usize {
+        self.pointer.addr()
+    }
+}
+
+impl<T: Pointee> Clone for UniqueWeak<T> {
+    fn clone(&self) -> UniqueWeak<T> {
+        UniqueWeak::from_pointer(&self.pointer)
+    }
+}
+
+impl<T: Pointee> Drop for UniqueWeak<T> {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            self.pointer.ref_counter().decr_weak();
+        }
+    }
+}
+
+impl<T: Pointee> Default for UniqueWeak<T> {
+    fn default() -> UniqueWeak<T> {
+        UniqueWeak::new()
+    }
+}
+
+impl<T: Pointee> Debug for UniqueWeak<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "UniqueWeak@{:016x}", self.addr())
+    }
+}
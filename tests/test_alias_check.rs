@@ -0,0 +1,62 @@
+#![cfg(all(feature = "alias-check", debug_assertions, not(feature = "no-panic")))]
+use k9::assert_equal;
+use unique_pointer::{alias_check, UniquePointer};
+
+#[test]
+fn test_alias_check_flags_mutable_aliases() {
+    alias_check::clear();
+    let mut data = UniquePointer::from(1u8);
+    let mut copy = data.clone();
+
+    let first = data.inner_mut();
+    let second = copy.extend_lifetime_mut();
+    *second += *first;
+
+    let violations = alias_check::violations();
+    assert_equal!(violations.len(), 1);
+    assert_equal!(violations[0].addr, data.addr());
+    assert_equal!(violations[0].first.kind, alias_check::BorrowKind::Mutable);
+    assert_equal!(violations[0].second.kind, alias_check::BorrowKind::Mutable);
+    assert_equal!(violations[0].first.location.file(), file!());
+    assert_equal!(
+        violations[0].second.location.line(),
+        violations[0].first.location.line() + 1
+    );
+    alias_check::clear();
+}
+
+#[test]
+fn test_alias_check_flags_mutable_and_shared_overlap() {
+    alias_check::clear();
+    let data = UniquePointer::from(1u8);
+    let mut copy = data.clone();
+
+    let _read = data.inner_ref();
+    let _write = copy.inner_mut();
+
+    let violations = alias_check::violations();
+    assert_equal!(violations.len(), 1);
+    assert_equal!(violations[0].first.kind, alias_check::BorrowKind::Shared);
+    assert_equal!(violations[0].second.kind, alias_check::BorrowKind::Mutable);
+    alias_check::clear();
+}
+
+#[test]
+fn test_alias_check_allows_shared_and_reborrows() {
+    alias_check::clear();
+    let mut data = UniquePointer::from(1u8);
+    let copy = data.clone();
+
+    let _lhs = copy.inner_ref();
+    let _rhs = data.inner_ref();
+    assert_equal!(alias_check::violations().len(), 0);
+
+    *data.inner_mut() += 1;
+    assert_equal!(alias_check::violations().len(), 1);
+
+    drop(copy);
+    *data.inner_mut() += 1;
+    *data.inner_mut() += 1;
+    assert_equal!(alias_check::violations().len(), 1);
+    alias_check::clear();
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "alloc-id")]
+use k9::assert_equal;
+use unique_pointer::UniquePointer;
+
+#[test]
+fn test_alloc_id_increases_per_allocation() {
+    let mut first = UniquePointer::<u64>::null();
+    assert_equal!(first.alloc_id(), 0);
+    first.write(1);
+    let second = UniquePointer::from(2u64);
+    assert_equal!(second.alloc_id() > first.alloc_id(), true);
+}
+
+#[test]
+fn test_alloc_id_shared_by_clones() {
+    let data = UniquePointer::from(1u64);
+    let clone = data.clone();
+    assert_equal!(clone.alloc_id(), data.alloc_id());
+    assert_equal!(UniquePointer::read_only(&1u64).alloc_id(), 0);
+}
+
+#[test]
+fn test_alloc_id_tells_reallocations_apart() {
+    let mut data = UniquePointer::from(1u64);
+    let before = data.alloc_id();
+    data.dealloc(false);
+    assert_equal!(data.alloc_id(), 0);
+    data.write(2);
+    assert_equal!(data.alloc_id() > before, true);
+}
+
+#[test]
+fn test_alloc_id_in_debug() {
+    let data = UniquePointer::from(1u64);
+    assert_equal!(
+        format!("{:?}", data).ends_with(&format!("[alloc_id={}]", data.alloc_id())),
+        true
+    );
+}
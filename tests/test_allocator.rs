@@ -0,0 +1,105 @@
+#![cfg_attr(not(feature = "no-panic"), feature(allocator_api))]
+#![cfg(not(feature = "no-panic"))]
+use std::alloc::{AllocError, Allocator, Global, Layout};
+use std::cell::Cell;
+use std::ptr::NonNull;
+use std::rc::Rc;
+
+use k9::assert_equal;
+use unique_pointer::{Error, UniquePointer};
+
+#[derive(Clone, Default)]
+struct Counting {
+    allocated: Rc<Cell<usize>>,
+    deallocated: Rc<Cell<usize>>,
+}
+
+unsafe impl Allocator for Counting {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.allocated.set(self.allocated.get() + 1);
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.deallocated.set(self.deallocated.get() + 1);
+        unsafe { Global.deallocate(ptr, layout) }
+    }
+}
+
+#[test]
+fn test_allocator_backs_memory() {
+    let allocator = Counting::default();
+    let mut data = UniquePointer::<u64, Counting>::null_in(allocator.clone());
+    data.write(7);
+    assert_equal!(allocator.allocated.get(), 1);
+    assert_equal!(data.read(), 7);
+
+    let clone = data.clone();
+    assert_equal!(data.take(), None);
+    drop(clone);
+    assert_equal!(data.take(), Some(7));
+    assert_equal!(allocator.deallocated.get(), 1);
+}
+
+#[test]
+fn test_allocator_honors_alignment() {
+    let allocator = Counting::default();
+    let mut data = UniquePointer::<u8, Counting>::null_in(allocator.clone());
+    data.alloc_aligned(64);
+    assert_equal!(data.addr() % 64, 0);
+    data.write(1);
+    assert_equal!(data.take(), Some(1));
+    assert_equal!(allocator.deallocated.get(), 1);
+}
+
+#[test]
+fn test_allocator_skipped_for_zero_sized_types() {
+    let allocator = Counting::default();
+    let mut unit = UniquePointer::<(), Counting>::null_in(allocator.clone());
+    unit.write(());
+    assert_equal!(unit.take(), Some(()));
+    assert_equal!(allocator.allocated.get(), 0);
+    assert_equal!(allocator.deallocated.get(), 0);
+}
+
+#[test]
+fn test_allocator_travels_with_propagated_pointers() {
+    let allocator = Counting::default();
+    let mut data = UniquePointer::<u64, Counting>::null_in(allocator.clone());
+    data.write(7);
+    let mut propagated = unsafe { data.propagate() };
+    assert_equal!(propagated.take(), None);
+    drop(data);
+    assert_equal!(propagated.take(), Some(7));
+    assert_equal!(allocator.allocated.get(), 1);
+    assert_equal!(allocator.deallocated.get(), 1);
+}
+
+#[derive(Clone, Copy)]
+struct Exhausted;
+
+unsafe impl Allocator for Exhausted {
+    fn allocate(&self, _: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        Err(AllocError)
+    }
+
+    unsafe fn deallocate(&self, _: NonNull<u8>, _: Layout) {}
+}
+
+#[test]
+fn test_try_alloc_and_try_write_report_exhaustion() {
+    let mut data = UniquePointer::<u64, Exhausted>::null_in(Exhausted);
+    assert_equal!(
+        data.try_alloc(),
+        Err(Error::AllocationFailed { size: 8, align: 8 })
+    );
+    assert_equal!(
+        data.try_write(1),
+        Err(Error::AllocationFailed { size: 8, align: 8 })
+    );
+    assert_equal!(data.is_null(), true);
+    assert_equal!(data.try_read(), None);
+
+    let mut unit = UniquePointer::<(), Exhausted>::null_in(Exhausted);
+    assert_equal!(unit.try_write(()), Ok(()));
+}
@@ -0,0 +1,63 @@
+use std::rc::Rc;
+
+use k9::assert_equal;
+use unique_pointer::collections::ArrayList;
+
+#[test]
+fn test_array_list_wraps_around() {
+    let mut list = ArrayList::<u32, 4>::new();
+    assert_equal!(list.capacity(), 4);
+    for value in 1..=4 {
+        assert_equal!(list.push_back(value), Ok(()));
+    }
+    assert_equal!(list.is_full(), true);
+    assert_equal!(list.push_front(0), Err(0));
+    assert_equal!(list.pop_front(), Some(1));
+    assert_equal!(list.pop_front(), Some(2));
+    assert_equal!(list.push_back(5), Ok(()));
+    assert_equal!(list.push_back(6), Ok(()));
+    assert_equal!(list.as_slices(), (&[3, 4][..], &[5, 6][..]));
+    assert_equal!(format!("{:?}", list), "[3, 4, 5, 6]");
+    assert_equal!(
+        list.iter().rev().copied().collect::<Vec<_>>(),
+        vec![6, 5, 4, 3]
+    );
+
+    list.reverse();
+    for value in list.iter_mut() {
+        *value *= 10;
+    }
+    assert_equal!(list.front(), Some(&60));
+    assert_equal!(list.back(), Some(&30));
+    if let Some(back) = list.back_mut() {
+        *back += 1;
+    }
+    assert_equal!(list.drain().rev().collect::<Vec<_>>(), vec![31, 40, 50, 60]);
+    assert_equal!(list.is_empty(), true);
+    assert_equal!(list.pop_back(), None);
+}
+
+#[test]
+fn test_array_list_drops_remaining_values() {
+    let value = Rc::new(());
+    let mut list = (0..3).map(|_| value.clone()).collect::<ArrayList<_, 3>>();
+    assert_equal!(list.pop_front().is_some(), true);
+    list.push_back(value.clone()).unwrap();
+    assert_equal!(Rc::strong_count(&value), 4);
+
+    let mut values = list.into_iter();
+    assert_equal!(values.next_back().is_some(), true);
+    assert_equal!(Rc::strong_count(&value), 3);
+    drop(values);
+    assert_equal!(Rc::strong_count(&value), 1);
+
+    let mut empty = ArrayList::<Rc<()>, 0>::new();
+    assert_equal!(empty.push_front(value.clone()).is_err(), true);
+    assert_equal!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+#[should_panic(expected = "ArrayList capacity of 2 exceeded")]
+fn test_array_list_extend_beyond_capacity() {
+    let _ = (0..3).collect::<ArrayList<u8, 2>>();
+}
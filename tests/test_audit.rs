@@ -0,0 +1,59 @@
+#![cfg(not(feature = "no-panic"))]
+use std::fmt::Formatter;
+
+use k9::assert_equal;
+use unique_pointer::audit::{audit, Finding};
+use unique_pointer::{GraphNode, UniquePointer};
+
+#[derive(Debug)]
+struct Node {
+    value: u8,
+    prev: UniquePointer<Node>,
+    next: UniquePointer<Node>,
+}
+
+impl GraphNode for Node {
+    fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+        vec![("prev", &self.prev), ("next", &self.next)]
+    }
+
+    fn label(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+fn node(value: u8) -> UniquePointer<Node> {
+    UniquePointer::from(Node {
+        value,
+        prev: UniquePointer::null(),
+        next: UniquePointer::null(),
+    })
+}
+
+#[test]
+fn test_audit_reports_released_pointers() {
+    let [mut a, mut b, mut c] = [1, 2, 3].map(node);
+    a.inner_mut().next = b.clone();
+    b.inner_mut().prev = UniquePointer::read_only(a.inner_ref());
+    b.inner_mut().next = c.clone();
+    c.inner_mut().prev = UniquePointer::read_only(b.inner_ref());
+    assert_equal!(audit(&[a.clone(), c.clone()]), vec![]);
+
+    c.drop_in_place();
+    c.drop_in_place();
+    let findings = audit(&[a.clone()]);
+    assert_equal!(
+        findings,
+        vec![Finding::Released {
+            addr: b.next.addr(),
+            edge: "next"
+        }]
+    );
+    assert_equal!(
+        findings[0].to_string(),
+        format!(
+            "next: {:016x} written but without references",
+            b.next.addr()
+        )
+    );
+}
@@ -0,0 +1,52 @@
+use std::rc::Rc;
+
+use k9::assert_equal;
+use unique_pointer::collections::BinaryHeap;
+
+#[test]
+fn test_binary_heap_push_pop() {
+    let mut heap = BinaryHeap::new();
+    assert_equal!(heap.is_empty(), true);
+    assert_equal!(heap.pop(), None);
+    assert_equal!(heap.peek(), None);
+
+    for item in [5, 1, 8, 3, 9, 2] {
+        heap.push(item);
+    }
+    assert_equal!(heap.len(), 6);
+    assert_equal!(heap.peek(), Some(&9));
+
+    let mut popped = Vec::new();
+    while let Some(item) = heap.pop() {
+        popped.push(item);
+    }
+    assert_equal!(popped, vec![9, 8, 5, 3, 2, 1]);
+    assert_equal!(heap.is_empty(), true);
+}
+
+#[test]
+fn test_binary_heap_from_vec_into_sorted_vec() {
+    let heap = BinaryHeap::from(vec![4, 10, 3, 5, 1, 10]);
+    assert_equal!(heap.peek(), Some(&10));
+    assert_equal!(heap.into_sorted_vec(), vec![1, 3, 4, 5, 10, 10]);
+
+    let heap = ["b", "c", "a"].into_iter().collect::<BinaryHeap<_>>();
+    assert_equal!(format!("{:?}", heap), r#"["c", "b", "a"]"#);
+    assert_equal!(heap.into_vec(), vec!["c", "b", "a"]);
+}
+
+#[test]
+fn test_binary_heap_drops_elements() {
+    let item = Rc::new(1);
+    {
+        let mut heap = BinaryHeap::new();
+        heap.extend([item.clone(), item.clone()]);
+        assert_equal!(Rc::strong_count(&item), 3);
+    }
+    assert_equal!(Rc::strong_count(&item), 1);
+
+    let sorted = BinaryHeap::from(vec![item.clone()]).into_sorted_vec();
+    assert_equal!(Rc::strong_count(&item), 2);
+    drop(sorted);
+    assert_equal!(Rc::strong_count(&item), 1);
+}
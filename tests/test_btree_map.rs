@@ -0,0 +1,116 @@
+use std::rc::Rc;
+
+use k9::assert_equal;
+use unique_pointer::collections::BTreeMapLike;
+
+#[test]
+fn test_btree_map_insert_splits_nodes() {
+    let mut map = BTreeMapLike::new();
+    assert_equal!(map.is_empty(), true);
+    assert_equal!(map.height(), 1);
+    for key in 0..200u32 {
+        assert_equal!(map.insert((key * 37) % 200, key), None);
+    }
+    assert_equal!(map.len(), 200);
+    assert_equal!(map.height() > 2, true);
+    assert_equal!(map.insert(37, 0), Some(1));
+
+    assert_equal!(
+        map.keys().copied().collect::<Vec<u32>>(),
+        (0..200).collect::<Vec<u32>>()
+    );
+    assert_equal!(map.get(&74), Some(&2));
+    assert_equal!(map.get(&200), None);
+    assert_equal!(map.first(), Some((&0, &0)));
+    assert_equal!(map.last(), Some((&199, &((199 * 173) % 200))));
+
+    *map.get_mut(&74).unwrap() = 1000;
+    assert_equal!(map.get(&74), Some(&1000));
+}
+
+#[test]
+fn test_btree_map_remove_merges_nodes() {
+    let mut map = (0..200u32)
+        .map(|key| (key, key))
+        .collect::<BTreeMapLike<_, _>>();
+    let height = map.height();
+
+    for key in (0..200u32).filter(|key| key % 3 != 0) {
+        assert_equal!(map.remove(&key), Some(key), "removing {}", key);
+        assert_equal!(map.contains_key(&key), false);
+    }
+    assert_equal!(map.remove(&1), None);
+    assert_equal!(map.len(), 67);
+    assert_equal!(
+        map.values().copied().collect::<Vec<u32>>(),
+        (0..200u32).step_by(3).collect::<Vec<u32>>()
+    );
+
+    for key in (0..200u32).step_by(3).rev() {
+        assert_equal!(map.remove(&key), Some(key));
+    }
+    assert_equal!(map.is_empty(), true);
+    assert_equal!(map.height() < height, true);
+    assert_equal!(map.first(), None);
+    assert_equal!(map.last(), None);
+    assert_equal!(format!("{:?}", map), "{}");
+}
+
+#[test]
+fn test_btree_map_drops_entries() {
+    let value = Rc::new(0);
+    {
+        let mut map = (0..50)
+            .map(|key| (key, value.clone()))
+            .collect::<BTreeMapLike<_, _>>();
+        assert_equal!(Rc::strong_count(&value), 51);
+        for key in 0..25 {
+            drop(map.remove(&key));
+        }
+        assert_equal!(Rc::strong_count(&value), 26);
+    }
+    assert_equal!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn test_btree_map_entry() {
+    use unique_pointer::collections::btree_map::Entry;
+
+    let mut map = BTreeMapLike::new();
+    for key in 0..100u32 {
+        *map.entry(key % 10).or_insert(0) += 1;
+    }
+    assert_equal!(map.len(), 10);
+    assert_equal!(map.values().copied().collect::<Vec<u32>>(), vec![10; 10]);
+
+    for key in 0..100u32 {
+        map.entry(key).and_modify(|count| *count *= 2).or_default();
+    }
+    assert_equal!(map.len(), 100);
+    assert_equal!(map.height() > 2, true);
+    assert_equal!(map.get(&9), Some(&20));
+    assert_equal!(map.get(&10), Some(&0));
+    assert_equal!(
+        map.keys().copied().collect::<Vec<u32>>(),
+        (0..100).collect::<Vec<u32>>()
+    );
+
+    match map.entry(42) {
+        Entry::Occupied(mut entry) => {
+            assert_equal!(entry.key(), &42);
+            assert_equal!(entry.insert(7), 0);
+            assert_equal!(entry.get(), &7);
+        }
+        Entry::Vacant(_) => panic!("42 is present"),
+    }
+    match map.entry(420) {
+        Entry::Occupied(_) => panic!("420 is absent"),
+        Entry::Vacant(entry) => {
+            assert_equal!(entry.key(), &420);
+            *entry.insert(1) += 1;
+        }
+    }
+    assert_equal!(map.get(&42), Some(&7));
+    assert_equal!(map.get(&420), Some(&2));
+    assert_equal!(map.len(), 101);
+}
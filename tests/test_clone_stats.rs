@@ -0,0 +1,69 @@
+#![cfg(feature = "clone-stats")]
+use k9::assert_equal;
+use unique_pointer::{clone_stats, UniquePointer};
+
+#[test]
+fn test_clone_stats_counts_copies_by_site() {
+    clone_stats::clear();
+    let data = UniquePointer::from(1u64);
+    assert_equal!(data.clone_stats().copies(), 0);
+
+    let (first, first_line) = (data.clone(), line!());
+    let (second, second_line) = (first.clone(), line!());
+    let (third, third_line) = (unsafe { second.propagate() }, line!());
+
+    let stats = third.clone_stats();
+    assert_equal!(stats, data.clone_stats());
+    assert_equal!(stats.clones, 2);
+    assert_equal!(stats.propagations, 1);
+    assert_equal!(
+        stats
+            .origins
+            .iter()
+            .map(|origin| (origin.kind, origin.location.file(), origin.location.line()))
+            .collect::<Vec<_>>(),
+        vec![
+            (clone_stats::CopyKind::Clone, file!(), first_line),
+            (clone_stats::CopyKind::Clone, file!(), second_line),
+            (clone_stats::CopyKind::Propagate, file!(), third_line),
+        ]
+    );
+    clone_stats::clear();
+}
+
+#[test]
+fn test_clone_stats_reset_on_alloc() {
+    clone_stats::clear();
+    let mut data = UniquePointer::<u64>::null();
+    let null = data.clone();
+    assert_equal!(null.clone_stats().copies(), 0);
+
+    data.write(1);
+    let _copy = data.clone();
+    assert_equal!(data.clone_stats().clones, 1);
+    assert_equal!(
+        data.clone_stats()
+            .to_string()
+            .starts_with("1 clones, 0 propagations\n  1x clone at "),
+        true
+    );
+    clone_stats::clear();
+}
+
+#[test]
+fn test_clone_stats_clone_many_records_caller() {
+    clone_stats::clear();
+    let data = UniquePointer::from(1u64);
+    let pointers = vec![data.clone(), data.clone()];
+    let (clones, line) = (UniquePointer::clone_many(&pointers), line!());
+    assert_equal!(
+        data.clone_stats()
+            .origins
+            .iter()
+            .filter(|origin| origin.location.line() == line)
+            .count(),
+        2
+    );
+    drop(clones);
+    clone_stats::clear();
+}
@@ -0,0 +1,129 @@
+use std::rc::Rc;
+
+use k9::assert_equal;
+use unique_pointer::collections::{
+    BTreeMapLike, BinaryHeap, HashMap, Queue, SplayTree, Stack, ThreadedTree, XorList,
+};
+
+#[test]
+fn test_sequences_iterate_in_insertion_order() {
+    let mut list = (1..=4u32).collect::<XorList<u32>>();
+    for value in &mut list {
+        *value *= 10;
+    }
+    assert_equal!((&list).into_iter().sum::<u32>(), 100);
+    assert_equal!(
+        list.into_iter().rev().collect::<Vec<u32>>(),
+        vec![40, 30, 20, 10]
+    );
+
+    let mut stack = (1..=3u32).collect::<Stack<u32>>();
+    stack.iter_mut().for_each(|value| *value += 1);
+    assert_equal!(stack.iter().copied().collect::<Vec<u32>>(), vec![2, 3, 4]);
+    assert_equal!(stack.into_iter().collect::<Vec<u32>>(), vec![2, 3, 4]);
+
+    let mut queue = ["a", "b", "c"].into_iter().collect::<Queue<&str>>();
+    for value in &mut queue {
+        if *value == "b" {
+            *value = "B";
+        }
+    }
+    assert_equal!((&queue).into_iter().count(), 3);
+    assert_equal!(
+        queue.into_iter().collect::<Vec<&str>>(),
+        vec!["a", "B", "c"]
+    );
+}
+
+#[test]
+fn test_ordered_collections_iterate_in_ascending_order() {
+    let values = [5u32, 1, 4, 2, 3];
+    let splay = values.into_iter().collect::<SplayTree<u32>>();
+    assert_equal!((&splay).into_iter().count(), 5);
+    assert_equal!(splay.into_iter().collect::<Vec<u32>>(), vec![1, 2, 3, 4, 5]);
+
+    let threaded = values.into_iter().collect::<ThreadedTree<u32>>();
+    assert_equal!((&threaded).into_iter().count(), 5);
+    assert_equal!(
+        threaded.into_iter().collect::<Vec<u32>>(),
+        vec![1, 2, 3, 4, 5]
+    );
+
+    let mut map = (0..200u32)
+        .rev()
+        .map(|key| (key, key))
+        .collect::<BTreeMapLike<u32, u32>>();
+    for (key, value) in &mut map {
+        *value += key;
+    }
+    assert_equal!(
+        (&map).into_iter().map(|(_, value)| *value).sum::<u32>(),
+        2 * 19900
+    );
+    assert_equal!(
+        map.into_iter().collect::<Vec<(u32, u32)>>(),
+        (0..200u32)
+            .map(|key| (key, key * 2))
+            .collect::<Vec<(u32, u32)>>()
+    );
+}
+
+#[test]
+fn test_unordered_collections_iterate_every_element() {
+    let heap = [3u32, 7, 1].into_iter().collect::<BinaryHeap<u32>>();
+    assert_equal!((&heap).into_iter().max(), Some(&7));
+    let mut values = heap.into_iter().collect::<Vec<u32>>();
+    values.sort();
+    assert_equal!(values, vec![1, 3, 7]);
+
+    let mut map = (0..100u32)
+        .map(|key| (key, key))
+        .collect::<HashMap<u32, u32>>();
+    for (_, value) in &mut map {
+        *value += 1;
+    }
+    assert_equal!(map.values_mut().count(), 100);
+    assert_equal!(
+        (&map).into_iter().all(|(key, value)| *value == key + 1),
+        true
+    );
+    let mut entries = map.into_iter().collect::<Vec<(u32, u32)>>();
+    entries.sort();
+    assert_equal!(
+        entries,
+        (0..100u32)
+            .map(|key| (key, key + 1))
+            .collect::<Vec<(u32, u32)>>()
+    );
+}
+
+#[test]
+fn test_partially_consumed_iterators_drop_the_rest() {
+    let value = Rc::new(());
+    let rc = |count: u32| (0..count).map(|index| (index, value.clone()));
+
+    let mut iter = rc(10).collect::<XorList<(u32, Rc<()>)>>().into_iter();
+    iter.next();
+    drop(iter);
+    assert_equal!(Rc::strong_count(&value), 1);
+
+    let mut iter = rc(100).collect::<BTreeMapLike<u32, Rc<()>>>().into_iter();
+    iter.nth(40);
+    drop(iter);
+    assert_equal!(Rc::strong_count(&value), 1);
+
+    let mut iter = rc(100).collect::<HashMap<u32, Rc<()>>>().into_iter();
+    iter.nth(40);
+    drop(iter);
+    assert_equal!(Rc::strong_count(&value), 1);
+
+    let mut iter = rc(100).collect::<SplayTree<(u32, Rc<()>)>>().into_iter();
+    iter.nth(40);
+    drop(iter);
+    assert_equal!(Rc::strong_count(&value), 1);
+
+    let mut iter = rc(100).collect::<ThreadedTree<(u32, Rc<()>)>>().into_iter();
+    iter.nth(40);
+    drop(iter);
+    assert_equal!(Rc::strong_count(&value), 1);
+}
@@ -0,0 +1,63 @@
+#![cfg(not(feature = "no-panic"))]
+use std::fmt::Formatter;
+
+use k9::assert_equal;
+use unique_pointer::{GraphNode, UniquePointer};
+
+#[derive(Debug)]
+struct Node {
+    value: u32,
+    next: UniquePointer<Node>,
+}
+
+impl GraphNode for Node {
+    fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+        vec![("next", &self.next)]
+    }
+
+    fn label(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[test]
+fn test_debug_graph_detects_cycles() {
+    let mut head = UniquePointer::from(Node {
+        value: 1,
+        next: UniquePointer::null(),
+    });
+    let tail = UniquePointer::from(Node {
+        value: 2,
+        next: head.clone(),
+    });
+    head.inner_mut().next = tail.clone();
+
+    assert_equal!(
+        format!("{:?}", head.debug_graph(8)),
+        format!(
+            "{:016x}[refs={}] 1 {{next: {:016x}[refs={}] 2 {{next: <cycle {:016x}>}}}}",
+            head.addr(),
+            head.refs(),
+            tail.addr(),
+            tail.refs(),
+            head.addr()
+        )
+    );
+    assert_equal!(
+        format!("{:#?}", head.debug_graph(8)),
+        format!(
+            "{:016x}[refs={}] 1 {{\n    next: {:016x}[refs={}] 2 {{\n        next: <cycle {:016x}>\n    }}\n}}",
+            head.addr(),
+            head.refs(),
+            tail.addr(),
+            tail.refs(),
+            head.addr()
+        )
+    );
+}
+
+#[test]
+fn test_debug_graph_null() {
+    let head = UniquePointer::<Node>::null();
+    assert_equal!(format!("{:?}", head.debug_graph(1)), "NULL");
+}
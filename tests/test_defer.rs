@@ -0,0 +1,95 @@
+use std::cell::RefCell;
+use std::fmt::Formatter;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use k9::assert_equal;
+use unique_pointer::{defer_scope, GraphNode, UniquePointer};
+
+#[derive(Debug)]
+struct Node {
+    value: char,
+    parent: UniquePointer<Node>,
+    left: UniquePointer<Node>,
+    right: UniquePointer<Node>,
+}
+
+impl Node {
+    fn new(value: char) -> Node {
+        Node {
+            value,
+            parent: UniquePointer::null(),
+            left: UniquePointer::null(),
+            right: UniquePointer::null(),
+        }
+    }
+}
+
+impl GraphNode for Node {
+    fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+        vec![
+            ("parent", &self.parent),
+            ("left", &self.left),
+            ("right", &self.right),
+        ]
+    }
+
+    fn children(&self) -> Vec<&UniquePointer<Node>> {
+        vec![&self.left, &self.right]
+    }
+
+    fn label(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+fn link(parent: &mut Node, left: Option<&mut Node>, right: Option<&mut Node>) {
+    if let Some(left) = left {
+        left.parent = UniquePointer::read_only(parent);
+        parent.left = UniquePointer::read_only(left);
+    }
+    if let Some(right) = right {
+        right.parent = UniquePointer::read_only(parent);
+        parent.right = UniquePointer::read_only(right);
+    }
+}
+
+#[test]
+fn test_defer_scope_deallocates_children_before_parents() {
+    let [mut a, mut b, mut c, mut d, mut e, mut f] = ['A', 'B', 'C', 'D', 'E', 'F'].map(Node::new);
+    link(&mut d, Some(&mut f), None);
+    link(&mut b, Some(&mut d), Some(&mut e));
+    link(&mut a, Some(&mut b), Some(&mut c));
+
+    let order = RefCell::new(String::new());
+    let dealloc = |node: &mut Node| order.borrow_mut().push(node.value);
+    let queued = defer_scope(|scope| {
+        scope.dealloc(&mut f, dealloc);
+        scope.dealloc(&mut a, dealloc);
+        scope.dealloc(&mut c, dealloc);
+        scope.dealloc(&mut b, dealloc);
+        scope.dealloc(&mut e, dealloc);
+        scope.dealloc(&mut d, dealloc);
+        assert_equal!(order.borrow().as_str(), "");
+        scope.len()
+    });
+    assert_equal!(queued, 6);
+    assert_equal!(order.into_inner(), "FDEBCA");
+}
+
+#[test]
+fn test_defer_scope_deallocates_on_unwind() {
+    let [mut parent, mut child] = ['P', 'C'].map(Node::new);
+    link(&mut parent, Some(&mut child), None);
+
+    let order = RefCell::new(String::new());
+    let dealloc = |node: &mut Node| order.borrow_mut().push(node.value);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        defer_scope(|scope| {
+            scope.dealloc(&mut parent, dealloc);
+            scope.dealloc(&mut child, dealloc);
+            panic!("unwinding");
+        })
+    }));
+    assert_equal!(result.is_err(), true);
+    assert_equal!(order.into_inner(), "CP");
+}
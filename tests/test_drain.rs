@@ -0,0 +1,190 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use k9::assert_equal;
+use unique_pointer::collections::{BTreeMapLike, SplayTree, ThreadedTree, UnionFind, XorList};
+use unique_pointer::persistent::{List, Map};
+use unique_pointer::UniquePointer;
+
+thread_local! {
+    static LIVE: Cell<isize> = const { Cell::new(0) };
+}
+
+/// counts the bytes allocated and not yet deallocated by the current
+/// thread, such that tests running in parallel do not interfere
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = LIVE.try_with(|live| live.set(live.get() + layout.size() as isize));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = LIVE.try_with(|live| live.set(live.get() - layout.size() as isize));
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+fn live() -> isize {
+    LIVE.with(|live| live.get())
+}
+
+/// the values are large enough for the memory of their nodes to
+/// dominate any bookkeeping allocations
+type Value = (u32, [u8; 256]);
+const SIZE: isize = std::mem::size_of::<Value>() as isize;
+
+fn value(key: u32) -> Value {
+    (key, [0; 256])
+}
+
+#[test]
+fn test_take_deallocates() {
+    let before = live();
+    let mut data = UniquePointer::from(value(1));
+    assert_equal!(live() - before >= SIZE, true);
+    assert_equal!(data.take().map(|(key, _)| key), Some(1));
+    assert_equal!(live() - before < SIZE, true);
+    assert_equal!(data.is_null(), true);
+    assert_equal!(data.take(), None);
+}
+
+#[test]
+fn test_xor_list_drain_reclaims_memory_incrementally() {
+    let mut list = (0..1000).map(value).collect::<XorList<Value>>();
+    let full = live();
+    let mut drain = list.drain();
+    assert_equal!(list.is_empty(), true);
+    for key in 0..500 {
+        assert_equal!(drain.next().map(|(key, _)| key), Some(key));
+    }
+    assert_equal!(full - live() > 400 * SIZE, true);
+    drop(drain);
+    assert_equal!(full - live() > 900 * SIZE, true);
+}
+
+#[test]
+fn test_tree_drain_reclaims_memory_incrementally() {
+    let mut splay = (0..1000).map(value).collect::<SplayTree<Value>>();
+    let full = live();
+    let mut drain = splay.drain();
+    assert_equal!(splay.is_empty(), true);
+    assert_equal!(drain.by_ref().take(500).count(), 500);
+    assert_equal!(full - live() > 400 * SIZE, true);
+    drop(drain);
+    assert_equal!(full - live() > 900 * SIZE, true);
+
+    let mut threaded = (0..1000)
+        .map(|key| value((key * 7) % 1000))
+        .collect::<ThreadedTree<Value>>();
+    let full = live();
+    let mut drain = threaded.drain();
+    assert_equal!(
+        drain
+            .by_ref()
+            .take(500)
+            .map(|(key, _)| key)
+            .collect::<Vec<u32>>(),
+        (0..500).collect::<Vec<u32>>()
+    );
+    assert_equal!(full - live() > 400 * SIZE, true);
+    drop(drain);
+    assert_equal!(full - live() > 900 * SIZE, true);
+
+    let mut map = (0..1000)
+        .map(|key| (key, value(key)))
+        .collect::<BTreeMapLike<u32, Value>>();
+    let full = live();
+    let mut drain = map.drain();
+    assert_equal!(drain.by_ref().take(500).count(), 500);
+    assert_equal!(full - live() > 400 * SIZE, true);
+    drop(drain);
+    assert_equal!(full - live() > 900 * SIZE, true);
+}
+
+#[test]
+fn test_drop_with_visits_values_as_their_nodes_are_reclaimed() {
+    let mut list = (0..1000).map(value).collect::<XorList<Value>>();
+    let full = live();
+    let mut reclaimed = Vec::new();
+    list.drop_with(|(key, _)| {
+        if *key == 500 {
+            reclaimed.push(full - live());
+        }
+    });
+    assert_equal!(reclaimed[0] > 400 * SIZE, true);
+    assert_equal!(list.is_empty(), true);
+
+    let mut tree = (0..1000)
+        .map(|key| value((key * 7) % 1000))
+        .collect::<SplayTree<Value>>();
+    let mut keys = Vec::new();
+    tree.drop_with(|(key, _)| keys.push(*key));
+    assert_equal!(keys, (0..1000).collect::<Vec<u32>>());
+    assert_equal!(tree.is_empty(), true);
+
+    let mut threaded = (0..1000)
+        .map(|key| value((key * 7) % 1000))
+        .collect::<ThreadedTree<Value>>();
+    let mut keys = Vec::new();
+    threaded.drop_with(|(key, _)| keys.push(*key));
+    assert_equal!(keys, (0..1000).collect::<Vec<u32>>());
+
+    let mut map = (0..1000)
+        .map(|key| (key, value(key)))
+        .collect::<BTreeMapLike<u32, Value>>();
+    let full = live();
+    let mut visited = 0;
+    map.drop_with(|key, (value, _)| {
+        assert_equal!(key, value);
+        visited += 1;
+    });
+    assert_equal!(visited, 1000);
+    assert_equal!(full - live() > 900 * SIZE, true);
+}
+
+#[test]
+fn test_union_find_reclaims_its_nodes() {
+    let mut sets = UnionFind::new(1000);
+    for x in 1..1000 {
+        sets.union(x - 1, x);
+    }
+    assert_equal!(sets.find(999), sets.find(0));
+    let full = live();
+    drop(sets);
+    // every node holds its index, its rank and its parent
+    let node = 2 * std::mem::size_of::<usize>() + std::mem::size_of::<UniquePointer<u8>>();
+    assert_equal!(full - live() > 1000 * node as isize, true);
+}
+
+#[test]
+fn test_persistent_versions_reclaim_unshared_nodes() {
+    let base = (0..1000).map(value).collect::<List<Value>>();
+    let version = base.push_front(value(1000));
+    let full = live();
+    drop(base);
+    assert_equal!(full - live() < SIZE, true);
+    assert_equal!(version.len(), 1001);
+    drop(version);
+    assert_equal!(full - live() > 1000 * SIZE, true);
+
+    let base = (0..1000)
+        .map(|key| (key, value(key)))
+        .collect::<Map<u32, Value>>();
+    let before = live();
+    let version = base.insert(1000, value(1000));
+    // measured rather than derived from `SIZE`, such that the
+    // bookkeeping of features like `alias-check` is accounted for
+    let copied = live() - before;
+    let full = live();
+    drop(base);
+    // only the nodes along the path copied by `insert` are released
+    assert_equal!(full - live() < 2 * copied, true);
+    assert_equal!(version.len(), 1001);
+    drop(version);
+    assert_equal!(full - live() > 1000 * SIZE, true);
+}
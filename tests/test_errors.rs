@@ -0,0 +1,140 @@
+use std::error::Error as _;
+
+use k9::assert_equal;
+use unique_pointer::collections::{SplayTree, ThreadedTree};
+use unique_pointer::{Error, RefCounter, UniquePointer};
+
+#[test]
+fn test_error_context_chain() {
+    let error = Error::NotWritten { addr: 0x20 }
+        .context::<u32>("read", 0x20)
+        .context::<UniquePointer<u32>>("clone", 0x10);
+    assert_equal!(error.root_cause(), &Error::NotWritten { addr: 0x20 });
+    assert_equal!(
+        error
+            .chain()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "clone unique_pointer::unique_pointer::UniquePointer<u32> at 0000000000000010: \
+             read u32 at 0000000000000020: 0000000000000020 not written"
+                .to_string(),
+            "read u32 at 0000000000000020: 0000000000000020 not written".to_string(),
+            "0000000000000020 not written".to_string(),
+        ]
+    );
+    assert_equal!(
+        error.source().map(|source| source.to_string()),
+        Some("read u32 at 0000000000000020: 0000000000000020 not written".to_string())
+    );
+    assert_equal!(Error::NullPointer.source().is_none(), true);
+    assert_equal!(Error::NullPointer.root_cause(), &Error::NullPointer);
+}
+
+#[test]
+fn test_error_display() {
+    assert_equal!(
+        Error::Underflow {
+            addr: 1,
+            refs: 1,
+            by: 2
+        }
+        .to_string(),
+        "0000000000000001 has 1 refs, cannot drop 2"
+    );
+    assert_equal!(
+        Error::Poisoned { addr: 1 }.to_string(),
+        "0000000000000001 poisoned"
+    );
+    assert_equal!(
+        Error::Invariant {
+            addr: 1,
+            invariant: "sorted"
+        }
+        .to_string(),
+        "0000000000000001 breaks invariant: sorted"
+    );
+    assert_equal!(
+        Error::AllocationFailed { size: 8, align: 4 }.to_string(),
+        "failed to allocate 8 bytes aligned to 4"
+    );
+}
+
+#[test]
+fn test_refcounter_try_decr_by_reports_underflow() {
+    let refs = RefCounter::from(3);
+    assert_equal!(refs.try_decr_by(2), Ok(1));
+    assert_equal!(
+        matches!(
+            refs.try_decr_by(2),
+            Err(Error::Underflow { refs: 1, by: 2, .. })
+        ),
+        true
+    );
+    assert_equal!(refs.read(), 1);
+    assert_equal!(RefCounter::null().try_decr_by(1).is_err(), true);
+}
+
+#[test]
+fn test_try_alloc_aligned_reports_allocation_failure() {
+    let mut data = UniquePointer::<u64>::null();
+    assert_equal!(
+        data.try_alloc_aligned(usize::MAX),
+        Err(Error::AllocationFailed {
+            size: 8,
+            align: usize::MAX
+        })
+    );
+    assert_equal!(
+        data.try_alloc_aligned(1 << (usize::BITS - 1)).is_err(),
+        true
+    );
+    assert_equal!(data.is_null(), true);
+    assert_equal!(data.try_alloc_aligned(32), Ok(()));
+    data.write(1);
+    assert_equal!(data.align(), 32);
+    assert_equal!(data.try_read(), Some(1));
+}
+
+#[test]
+fn test_trees_validate_ordering() {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Key(std::cell::Cell<u32>);
+
+    let splay = (0..10u32)
+        .map(|value| Key(std::cell::Cell::new(value)))
+        .collect::<SplayTree<Key>>();
+    let threaded = (0..10u32)
+        .map(|value| Key(std::cell::Cell::new(value)))
+        .collect::<ThreadedTree<Key>>();
+    assert_equal!(splay.validate(), Ok(()));
+    assert_equal!(threaded.validate(), Ok(()));
+
+    splay.iter().nth(4).unwrap().0.set(7);
+    threaded.iter().nth(4).unwrap().0.set(7);
+    for (error, value) in [
+        (splay.validate().unwrap_err(), splay.iter().nth(5).unwrap()),
+        (
+            threaded.validate().unwrap_err(),
+            threaded.iter().nth(5).unwrap(),
+        ),
+    ] {
+        assert_equal!(
+            error.root_cause(),
+            &Error::Invariant {
+                addr: std::ptr::from_ref(value).addr(),
+                invariant: "values in strictly ascending order",
+            }
+        );
+        assert_equal!(
+            matches!(
+                error,
+                Error::Context {
+                    operation: "validate",
+                    ..
+                }
+            ),
+            true
+        );
+    }
+}
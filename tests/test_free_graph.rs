@@ -0,0 +1,196 @@
+#![cfg(not(feature = "no-panic"))]
+use std::cell::Cell;
+use std::fmt::Formatter;
+use std::rc::Rc;
+
+use k9::assert_equal;
+use unique_pointer::group::Group;
+use unique_pointer::{free_graph, free_graph_with, GraphNode, UniquePointer};
+
+/// counts how many times the nodes holding it were dropped
+#[derive(Debug)]
+struct Dropped(Rc<Cell<usize>>);
+
+impl Drop for Dropped {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    value: char,
+    dropped: Dropped,
+    parent: UniquePointer<Node>,
+    left: UniquePointer<Node>,
+    right: UniquePointer<Node>,
+}
+
+impl GraphNode for Node {
+    fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+        vec![
+            ("parent", &self.parent),
+            ("left", &self.left),
+            ("right", &self.right),
+        ]
+    }
+
+    fn children(&self) -> Vec<&UniquePointer<Node>> {
+        vec![&self.left, &self.right]
+    }
+
+    fn label(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+fn node(value: char, dropped: &Rc<Cell<usize>>) -> UniquePointer<Node> {
+    UniquePointer::from(Node {
+        value,
+        dropped: Dropped(dropped.clone()),
+        parent: UniquePointer::null(),
+        left: UniquePointer::null(),
+        right: UniquePointer::null(),
+    })
+}
+
+fn set_left(parent: &mut UniquePointer<Node>, left: &mut UniquePointer<Node>) {
+    left.inner_mut().parent = parent.clone();
+    parent.inner_mut().left = left.clone();
+}
+
+fn set_right(parent: &mut UniquePointer<Node>, right: &mut UniquePointer<Node>) {
+    right.inner_mut().parent = parent.clone();
+    parent.inner_mut().right = right.clone();
+}
+
+#[test]
+fn test_free_graph_frees_each_node_once() {
+    let dropped = Rc::new(Cell::new(0));
+    let [mut a, mut b, mut c, mut d, mut e, mut f] =
+        ['A', 'B', 'C', 'D', 'E', 'F'].map(|value| node(value, &dropped));
+    set_left(&mut d, &mut f);
+    set_left(&mut b, &mut d);
+    set_right(&mut b, &mut e);
+    set_left(&mut a, &mut b);
+    set_right(&mut a, &mut c);
+    assert_equal!(b.refs(), 4);
+
+    let mut roots = [a, e.clone()];
+    assert_equal!(unsafe { free_graph(&mut roots) }, 6);
+    assert_equal!(dropped.get(), 6);
+    assert_equal!(roots.iter().all(UniquePointer::is_null), true);
+    assert_equal!(unsafe { free_graph(&mut roots) }, 0);
+}
+
+#[test]
+fn test_free_graph_leaves_borrowed_nodes_untouched() {
+    let dropped = Rc::new(Cell::new(0));
+    let mut parent = node('P', &dropped);
+    let child = Node {
+        value: 'C',
+        dropped: Dropped(dropped.clone()),
+        parent: parent.clone(),
+        left: UniquePointer::null(),
+        right: UniquePointer::null(),
+    };
+    parent.inner_mut().left = UniquePointer::read_only(&child);
+
+    let mut roots = [parent];
+    assert_equal!(unsafe { free_graph(&mut roots) }, 1);
+    assert_equal!(dropped.get(), 1);
+    assert_equal!(child.value, 'C');
+    std::mem::forget(child);
+}
+
+#[test]
+fn test_free_graph_with_visits_children_before_parents() {
+    let dropped = Rc::new(Cell::new(0));
+    let [mut a, mut b, mut c, mut d] = ['A', 'B', 'C', 'D'].map(|value| node(value, &dropped));
+    set_left(&mut b, &mut d);
+    set_left(&mut a, &mut b);
+    set_right(&mut a, &mut c);
+
+    let mut visited = Vec::new();
+    let mut roots = [a];
+    let freed = unsafe {
+        free_graph_with(&mut roots, |node| {
+            visited.push((node.value, node.dropped.0.get()));
+        })
+    };
+    assert_equal!(freed, 4);
+    assert_equal!(dropped.get(), 4);
+    let position = |value| visited.iter().position(|(v, _)| *v == value);
+    assert_equal!(position('D') < position('B'), true);
+    assert_equal!(position('B') < position('A'), true);
+    assert_equal!(position('C') < position('A'), true);
+    assert_equal!(
+        visited
+            .iter()
+            .map(|(_, dropped)| *dropped)
+            .collect::<Vec<_>>(),
+        vec![0, 1, 2, 3]
+    );
+}
+
+#[test]
+fn test_drop_with_frees_the_graph_of_a_pointer() {
+    let dropped = Rc::new(Cell::new(0));
+    let [mut a, mut b] = ['A', 'B'].map(|value| node(value, &dropped));
+    set_left(&mut a, &mut b);
+
+    let mut visited = Vec::new();
+    assert_equal!(unsafe { a.drop_with(|node| visited.push(node.value)) }, 2);
+    assert_equal!(visited, vec!['B', 'A']);
+    assert_equal!(dropped.get(), 2);
+    assert_equal!(a.is_null(), true);
+}
+
+#[test]
+fn test_free_graph_leaves_group_memory_to_the_group() {
+    let dropped = Rc::new(Cell::new(0));
+    let group = Group::new();
+    let [mut a, mut b, mut c] = ['A', 'B', 'C'].map(|value| {
+        let mut up = UniquePointer::<Node>::null();
+        unsafe { up.alloc_in_group(&group) };
+        up.write(Node {
+            value,
+            dropped: Dropped(dropped.clone()),
+            parent: UniquePointer::null(),
+            left: UniquePointer::null(),
+            right: UniquePointer::null(),
+        });
+        up
+    });
+    set_left(&mut a, &mut b);
+    set_right(&mut a, &mut c);
+
+    let mut roots = [a];
+    assert_equal!(unsafe { free_graph(&mut roots) }, 3);
+    assert_equal!(dropped.get(), 3);
+    drop((b, c));
+    drop(group);
+}
+
+#[test]
+fn test_free_graph_frees_aligned_nodes_with_their_alignment() {
+    let dropped = Rc::new(Cell::new(0));
+    let [mut a, mut b] = ['A', 'B'].map(|value| {
+        let mut up = UniquePointer::<Node>::null();
+        up.alloc_aligned(256);
+        up.write(Node {
+            value,
+            dropped: Dropped(dropped.clone()),
+            parent: UniquePointer::null(),
+            left: UniquePointer::null(),
+            right: UniquePointer::null(),
+        });
+        up
+    });
+    set_left(&mut a, &mut b);
+    assert_equal!(b.align(), 256);
+
+    let mut roots = [a];
+    assert_equal!(unsafe { free_graph(&mut roots) }, 2);
+    assert_equal!(dropped.get(), 2);
+}
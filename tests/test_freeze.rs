@@ -0,0 +1,123 @@
+use k9::assert_equal;
+use unique_pointer::{free_graph, Error, GraphNode, UniquePointer};
+
+#[derive(Debug)]
+struct Node {
+    value: u32,
+    next: UniquePointer<Node>,
+}
+
+impl GraphNode for Node {
+    fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+        vec![("next", &self.next)]
+    }
+
+    fn label(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[test]
+fn test_freeze_refuses_mutable_access() {
+    let mut data = UniquePointer::from(String::from("frozen"));
+    let mut clone = data.clone();
+    let mut copy = UniquePointer::read_only(clone.as_ref().unwrap());
+    assert_equal!(data.is_frozen(), false);
+
+    clone.freeze();
+    for pointer in [&mut data, &mut clone, &mut copy] {
+        let addr = pointer.addr();
+        assert_equal!(pointer.is_frozen(), true);
+        assert_equal!(pointer.as_mut(), None);
+        assert_equal!(pointer.try_inner_mut(), Err(Error::Frozen { addr }));
+        assert_equal!(pointer.try_cast_mut(), Err(Error::Frozen { addr }));
+        assert_equal!(
+            pointer.write_exclusive(String::new()),
+            Err(Error::Frozen { addr })
+        );
+        assert_equal!(pointer.try_inner_ref(), Ok(&String::from("frozen")));
+    }
+    drop(clone);
+    assert_equal!(data.take(), None);
+    assert_equal!(
+        Error::Frozen { addr: 1 }.to_string(),
+        "0000000000000001 frozen"
+    );
+
+    let mut other = UniquePointer::from(String::from("thawed"));
+    assert_equal!(other.is_frozen(), false);
+    assert_equal!(other.as_mut().is_some(), true);
+}
+
+#[cfg(not(feature = "no-panic"))]
+#[test]
+#[should_panic(expected = "frozen")]
+fn test_freeze_write_panics() {
+    let mut data = UniquePointer::from(1u8);
+    data.freeze();
+    data.write(2);
+}
+
+#[cfg(feature = "no-panic")]
+#[test]
+fn test_freeze_write_leaves_memory_untouched() {
+    let mut data = UniquePointer::from(1u8);
+    data.freeze();
+    data.write(2);
+    assert_equal!(data.try_read(), Some(1));
+}
+
+#[test]
+fn test_freeze_graph() {
+    let mut head = UniquePointer::from(Node {
+        value: 1,
+        next: UniquePointer::null(),
+    });
+    let mut tail = UniquePointer::from(Node {
+        value: 2,
+        next: head.clone(),
+    });
+    if let Some(node) = head.as_mut() {
+        node.next = tail.clone();
+    }
+    let mut detached = UniquePointer::from(Node {
+        value: 3,
+        next: UniquePointer::null(),
+    });
+
+    head.freeze_graph();
+    assert_equal!(head.is_frozen(), true);
+    assert_equal!(tail.is_frozen(), true);
+    assert_equal!(tail.as_mut().is_none(), true);
+    assert_equal!(detached.as_mut().map(|node| node.value), Some(3));
+    assert_equal!(
+        head.as_ref()
+            .and_then(|node| node.next.as_ref())
+            .map(|node| node.value),
+        Some(2)
+    );
+}
+
+#[test]
+fn test_freeze_is_forgotten_once_memory_is_released() {
+    let boxed = Box::new(1u128);
+    UniquePointer::read_only(boxed.as_ref()).freeze();
+    drop(boxed);
+    let mut data = UniquePointer::from_box(Box::new(5u128));
+    assert_equal!(data.is_frozen(), false);
+    assert_equal!(data.write_exclusive(6), Ok(()));
+
+    let head = UniquePointer::from(Node {
+        value: 1,
+        next: UniquePointer::null(),
+    });
+    head.freeze_graph();
+    let mut roots = [head];
+    assert_equal!(unsafe { free_graph(&mut roots) }, 1);
+    let mut node = UniquePointer::from(Node {
+        value: 2,
+        next: UniquePointer::null(),
+    });
+    assert_equal!(node.is_frozen(), false);
+    assert_equal!(node.as_mut().map(|node| node.value), Some(2));
+}
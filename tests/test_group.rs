@@ -0,0 +1,89 @@
+use k9::assert_equal;
+use unique_pointer::group::Group;
+use unique_pointer::UniquePointer;
+
+#[derive(Debug, PartialEq)]
+struct Node {
+    value: u64,
+    next: UniquePointer<Node>,
+}
+
+fn list_in(group: &Group, len: u64) -> UniquePointer<Node> {
+    let mut head = UniquePointer::<Node>::null();
+    for value in (0..len).rev() {
+        let mut node = UniquePointer::<Node>::null();
+        unsafe { node.alloc_in_group(group) };
+        node.write(Node { value, next: head });
+        head = node;
+    }
+    head
+}
+
+#[test]
+fn test_group_allocates_contiguously() {
+    let group = Group::new();
+    let head = list_in(&group, 4);
+    let mut addrs = Vec::new();
+    let mut node = head.as_ref();
+    while let Some(current) = node {
+        addrs.push(std::ptr::from_ref(current).addr());
+        node = current.next.as_ref();
+    }
+    let size = std::mem::size_of::<Node>();
+    assert_equal!(
+        addrs
+            .windows(2)
+            .map(|pair| pair[0] - pair[1])
+            .collect::<Vec<_>>(),
+        vec![size, size, size]
+    );
+    assert_equal!(group.allocated(), 4 * size);
+    assert_equal!(group.chunks(), 1);
+    assert_equal!(head.group_id(), Some(group.id()));
+    assert_equal!(head.clone().group_id(), Some(group.id()));
+    assert_equal!(UniquePointer::from(1u8).group_id(), None);
+}
+
+#[test]
+fn test_group_grows_by_chunks() {
+    let group = Group::new();
+    let mut pointers = Vec::new();
+    for value in 0..1000u64 {
+        let mut pointer = UniquePointer::<[u64; 4]>::null();
+        unsafe { pointer.alloc_in_group(&group) };
+        pointer.write([value; 4]);
+        pointers.push(pointer);
+    }
+    assert_equal!(group.allocated(), 32_000);
+    assert_equal!(group.chunks(), 4);
+    assert_equal!(pointers[999].try_read(), Some([999; 4]));
+
+    let mut big = UniquePointer::<[u8; 100_000]>::null();
+    unsafe { big.alloc_in_group(&group) };
+    big.write([1; 100_000]);
+    assert_equal!(group.chunks(), 5);
+    assert_equal!(big.try_read().map(|bytes| bytes[99_999]), Some(1));
+}
+
+#[test]
+fn test_group_alignment_and_take() {
+    #[repr(align(64))]
+    struct CacheLine;
+
+    let group = Group::new();
+    let mut byte = UniquePointer::<u8>::null();
+    unsafe { byte.alloc_in_group(&group) };
+    let mut aligned = UniquePointer::<u64>::with_alignment::<CacheLine>();
+    unsafe { aligned.alloc_in_group(&group) };
+    let addr = aligned.addr();
+    assert_equal!(addr % 64, 0);
+
+    // memory taken from a group stays with the group
+    aligned.write(7);
+    assert_equal!(aligned.take(), Some(7));
+    assert_equal!(aligned.group_id(), None);
+    assert_equal!(group.allocated(), addr - byte.addr() + 8);
+    byte.write(1);
+    assert_equal!(byte.try_read(), Some(1));
+    assert_equal!(Group::new().id() > group.id(), true);
+}
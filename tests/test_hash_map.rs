@@ -0,0 +1,66 @@
+use std::rc::Rc;
+
+use k9::assert_equal;
+use unique_pointer::collections::HashMap;
+
+#[test]
+fn test_hash_map_insert_get_remove() {
+    let mut map = HashMap::new();
+    assert_equal!(map.is_empty(), true);
+    assert_equal!(map.insert("a", 1), None);
+    assert_equal!(map.insert("b", 2), None);
+    assert_equal!(map.insert("a", 3), Some(1));
+    assert_equal!(map.len(), 2);
+
+    assert_equal!(map.get(&"a"), Some(&3));
+    assert_equal!(map.get(&"c"), None);
+    assert_equal!(map.contains_key(&"b"), true);
+
+    *map.get_mut(&"b").unwrap() += 10;
+    assert_equal!(map.get(&"b"), Some(&12));
+
+    assert_equal!(map.remove(&"a"), Some(3));
+    assert_equal!(map.remove(&"a"), None);
+    assert_equal!(map.len(), 1);
+    assert_equal!(format!("{:?}", map), r#"{"b": 12}"#);
+}
+
+#[test]
+fn test_hash_map_chains_and_resizes() {
+    let mut map = HashMap::with_buckets(1);
+    for key in 0..100u32 {
+        map.insert(key, key * 2);
+    }
+    assert_equal!(map.len(), 100);
+    assert_equal!(map.buckets() >= 128, true);
+    for key in 0..100u32 {
+        assert_equal!(map.get(&key), Some(&(key * 2)));
+    }
+    for key in (0..100u32).step_by(2) {
+        assert_equal!(map.remove(&key), Some(key * 2));
+    }
+    let mut keys = map.keys().copied().collect::<Vec<u32>>();
+    keys.sort();
+    assert_equal!(keys, (1..100u32).step_by(2).collect::<Vec<u32>>());
+    assert_equal!(map.values().count(), 50);
+
+    map.resize(1);
+    assert_equal!(map.buckets(), 1);
+    assert_equal!(map.get(&99), Some(&198));
+    assert_equal!(map.iter().count(), 50);
+}
+
+#[test]
+fn test_hash_map_drops_entries() {
+    let value = Rc::new(0);
+    {
+        let mut map = (0..10)
+            .map(|key| (key, value.clone()))
+            .collect::<HashMap<_, _>>();
+        assert_equal!(Rc::strong_count(&value), 11);
+        drop(map.remove(&3));
+        drop(map.insert(4, value.clone()));
+        assert_equal!(Rc::strong_count(&value), 10);
+    }
+    assert_equal!(Rc::strong_count(&value), 1);
+}
@@ -0,0 +1,40 @@
+use std::rc::Rc;
+
+use k9::assert_equal;
+use unique_pointer::collections::Interner;
+
+#[test]
+fn test_interner_deduplicates_values() {
+    let mut interner = Interner::new();
+    let words = "the quick fox jumps over the lazy dog the end"
+        .split(' ')
+        .map(|word| interner.intern(word.to_string()))
+        .collect::<Vec<_>>();
+    assert_equal!(words.len(), 10);
+    assert_equal!(interner.len(), 8);
+    assert_equal!(words[0].addr(), words[5].addr());
+    assert_equal!(words[0].addr(), words[8].addr());
+    assert_equal!(words[0].refs(), 4);
+    assert_equal!(interner.contains(&"fox".to_string()), true);
+    assert_equal!(interner.get(&"cat".to_string()).is_none(), true);
+    assert_equal!(interner.evict(), 0);
+}
+
+#[test]
+fn test_interner_evicts_values_no_longer_referenced() {
+    let value = Rc::new(1u8);
+    let mut interner = Interner::new();
+    let kept = interner.intern(value.clone());
+    let evicted = interner.intern(Rc::new(2u8));
+    assert_equal!(interner.intern(value.clone()).addr(), kept.addr());
+    assert_equal!(Rc::strong_count(&value), 2);
+
+    drop(evicted);
+    assert_equal!(interner.evict(), 1);
+    assert_equal!(interner.len(), 1);
+    assert_equal!(format!("{:?}", interner), "{1}");
+
+    drop(interner);
+    assert_equal!(kept.as_ref().map(|value| **value), Some(1));
+    assert_equal!(Rc::strong_count(&value), 2);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "no-panic")]
+use k9::assert_equal;
+use unique_pointer::{Error, RefCounter, UniquePointer};
+
+/// `NoPanic` is dropped during unwinding only, in which case it
+/// references a symbol that does not exist. Once optimized, code
+/// guarded by it links only if the optimizer proves that no panic
+/// can unwind through it, akin to the `#[no_panic]` attribute, hence
+/// `cargo test --release --features no-panic --no-run` fails to link
+/// should a panic path creep into the guarded methods.
+#[cfg(not(debug_assertions))]
+struct NoPanic;
+#[cfg(not(debug_assertions))]
+impl Drop for NoPanic {
+    fn drop(&mut self) {
+        unsafe extern "C" {
+            #[link_name = "\n\nERROR: a panic path was found in a function expected not to panic\n\n"]
+            fn trigger() -> !;
+        }
+        unsafe { trigger() }
+    }
+}
+
+macro_rules! no_panic {
+    ($body:expr) => {{
+        #[cfg(not(debug_assertions))]
+        let guard = NoPanic;
+        let result = (|| $body)();
+        #[cfg(not(debug_assertions))]
+        std::mem::forget(guard);
+        result
+    }};
+}
+
+#[inline(never)]
+fn checked_access(pointer: &mut UniquePointer<u64>) -> Result<u64, Error> {
+    no_panic!({
+        let data = *pointer.try_inner_ref()? + pointer.read_checked()?;
+        *pointer.try_inner_mut()? = data;
+        pointer.try_cast_const()?;
+        Ok(data)
+    })
+}
+
+#[test]
+fn test_no_panic_fallible_access() {
+    let mut pointer = UniquePointer::<u64>::null();
+    assert_equal!(checked_access(&mut pointer), Err(Error::NullPointer));
+
+    pointer.write(21);
+    assert_equal!(checked_access(&mut pointer), Ok(42));
+    assert_equal!(pointer.try_read(), Some(42));
+}
+
+#[test]
+fn test_no_panic_not_written() {
+    let mut pointer = UniquePointer::<u64>::null();
+    pointer.alloc();
+    assert_equal!(
+        pointer.read_checked(),
+        Err(Error::NotWritten {
+            addr: pointer.addr()
+        })
+    );
+    assert_equal!(pointer.into_box(), None);
+}
+
+#[test]
+fn test_no_panic_refcounter_checked() {
+    let refs = RefCounter::new();
+    assert_equal!(refs.checked_incr_by(usize::MAX), None);
+    assert_equal!(refs.read(), 1);
+    assert_equal!(refs.checked_decr_by(2), None);
+    assert_equal!(refs.checked_decr_by(1), Some(0));
+    refs.incr_by(usize::MAX);
+    refs.incr_by(1);
+    assert_equal!(refs.read(), usize::MAX);
+}
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+use k9::assert_equal;
+use unique_pointer::persistent::{List, Map};
+
+#[test]
+fn test_list_versions_share_their_tails() {
+    let base = (1..=3).collect::<List<u32>>();
+    let left = base.push_front(10);
+    let right = base.push_front(20);
+
+    assert_equal!(format!("{:?}", base), "[1, 2, 3]");
+    assert_equal!(format!("{:?}", left), "[10, 1, 2, 3]");
+    assert_equal!(format!("{:?}", right), "[20, 1, 2, 3]");
+    assert_equal!(left.tail().ptr_eq(&right.tail()), true);
+    assert_equal!(left.tail().ptr_eq(&base), true);
+    assert_equal!(left.len(), 4);
+    assert_equal!(left.head(), Some(&10));
+
+    drop(base);
+    assert_equal!(
+        left.tail().iter().copied().collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert_equal!(
+        right.reverse(),
+        (1..=3).rev().chain([20]).collect::<List<u32>>()
+    );
+    assert_equal!(List::<u32>::new().tail().is_empty(), true);
+}
+
+#[test]
+fn test_map_versions_remain_unchanged() {
+    let mut versions = vec![Map::new()];
+    for key in 0..1000u32 {
+        let next = versions.last().unwrap().insert(key, key * 2);
+        versions.push(next);
+    }
+    for (len, version) in versions.iter().enumerate().step_by(97) {
+        assert_equal!(version.len(), len);
+        assert_equal!(version.iter().count(), len);
+        for key in 0..1000u32 {
+            assert_equal!(
+                version.get(&key).copied(),
+                (key < len as u32).then_some(key * 2)
+            );
+        }
+    }
+
+    let full = versions.pop().unwrap();
+    drop(versions);
+    let replaced = full.insert(7, 0);
+    assert_equal!(replaced.len(), 1000);
+    assert_equal!(replaced.get(&7), Some(&0));
+    assert_equal!(full.get(&7), Some(&14));
+
+    let evens = (0..1000u32)
+        .filter(|key| key % 2 == 1)
+        .fold(full.clone(), |map, key| map.remove(&key));
+    assert_equal!(evens.len(), 500);
+    assert_equal!(full.len(), 1000);
+    assert_equal!(evens.contains_key(&3), false);
+    assert_equal!(full.contains_key(&3), true);
+    assert_equal!(evens.remove(&3).len(), 500);
+    assert_equal!(
+        evens
+            .iter()
+            .map(|(key, value)| (*key, *value))
+            .collect::<BTreeMap<_, _>>(),
+        (0..1000u32)
+            .step_by(2)
+            .map(|key| (key, key * 2))
+            .collect::<BTreeMap<_, _>>()
+    );
+    let empty = (0..1000u32).fold(full, |map, key| map.remove(&key));
+    assert_equal!(empty.is_empty(), true);
+    assert_equal!(empty, Map::new());
+}
@@ -0,0 +1,136 @@
+use k9::assert_equal;
+use unique_pointer::{Error, UniquePointer};
+
+#[test]
+fn test_pin_address_refuses_moves() {
+    let mut data = UniquePointer::from(String::from("pinned"));
+    let mut clone = data.clone();
+    let mut copy = UniquePointer::read_only(clone.as_ref().unwrap());
+    let mut other = UniquePointer::from(String::from("other"));
+    assert_equal!(data.is_address_pinned(), false);
+
+    clone.pin_address();
+    let addr = data.addr();
+    for pointer in [&mut data, &mut clone, &mut copy] {
+        assert_equal!(pointer.is_address_pinned(), true);
+        assert_equal!(pointer.try_swap(&mut other), Err(Error::Pinned { addr }));
+        assert_equal!(other.try_swap(pointer), Err(Error::Pinned { addr }));
+        assert_equal!(pointer.try_inner_ref(), Ok(&String::from("pinned")));
+        assert_equal!(pointer.addr(), addr);
+    }
+    assert_equal!(other.as_ref(), Some(&String::from("other")));
+
+    // overwriting the value in place would skip dropping it
+    assert_equal!(
+        data.try_write(String::from("rewritten")),
+        Err(Error::Pinned { addr })
+    );
+    assert_equal!(clone.as_ref(), Some(&String::from("pinned")));
+
+    drop(clone);
+    assert_equal!(data.take(), None);
+    assert_equal!(
+        Error::Pinned { addr: 1 }.to_string(),
+        "0000000000000001 pinned"
+    );
+}
+
+#[test]
+fn test_try_swap_unpinned() {
+    let mut left = UniquePointer::from(1u8);
+    let mut right = UniquePointer::from(2u8);
+    assert_equal!(left.try_swap(&mut right), Ok(()));
+    assert_equal!((left.try_read(), right.try_read()), (Some(2), Some(1)));
+
+    right.freeze();
+    assert_equal!(
+        left.try_swap(&mut right),
+        Err(Error::Frozen { addr: right.addr() })
+    );
+}
+
+#[cfg(not(feature = "no-panic"))]
+#[test]
+#[should_panic(expected = "pinned")]
+fn test_pin_address_swap_panics() {
+    let mut data = UniquePointer::from(1u8);
+    let mut other = UniquePointer::from(2u8);
+    data.pin_address();
+    other.swap(&mut data);
+}
+
+#[cfg(not(feature = "no-panic"))]
+#[test]
+#[should_panic(expected = "pinned")]
+fn test_pin_address_replace_panics() {
+    let mut data = UniquePointer::from(1u8);
+    data.pin_address();
+    data.replace(2);
+}
+
+#[cfg(not(feature = "no-panic"))]
+#[test]
+#[should_panic(expected = "pinned")]
+fn test_pin_address_write_panics() {
+    let mut data = UniquePointer::from(String::from("pinned"));
+    data.pin_address();
+    data.write(String::from("rewritten"));
+}
+
+#[cfg(not(feature = "no-panic"))]
+#[test]
+fn test_pin_address_get_or_insert_with_returns_the_written_value() {
+    let mut data = UniquePointer::from(String::from("pinned"));
+    data.pin_address();
+    data.get_or_insert_with(|| unreachable!()).push('!');
+    assert_equal!(data.as_ref(), Some(&String::from("pinned!")));
+}
+
+#[cfg(feature = "no-panic")]
+#[test]
+fn test_pin_address_swap_leaves_values_in_place() {
+    let mut data = UniquePointer::from(1u8);
+    let mut other = UniquePointer::from(2u8);
+    data.pin_address();
+    other.swap(&mut data);
+    assert_equal!((data.as_ref(), other.as_ref()), (Some(&1), Some(&2)));
+}
+
+#[cfg(not(feature = "no-panic"))]
+#[test]
+fn test_pin_keeps_value_in_place() {
+    let pinned = UniquePointer::pin(String::from("pinned"));
+    let addr = std::ptr::from_ref(&*pinned).addr();
+    let clone = pinned.clone();
+    assert_equal!(clone.as_str(), "pinned");
+    drop(pinned);
+
+    let mut up = std::pin::Pin::into_inner(clone);
+    assert_equal!(up.is_address_pinned(), true);
+    assert_equal!(up.addr(), addr);
+    assert_equal!(up.take(), None);
+}
+
+#[test]
+fn test_as_pin_ref_and_as_pin_mut_pin_the_address() {
+    let mut data = UniquePointer::from(String::from("data"));
+    let mut other = UniquePointer::from(String::from("other"));
+    data.as_pin_mut().unwrap().get_mut().push('!');
+    assert_equal!(data.is_address_pinned(), true);
+    assert_equal!(
+        data.try_swap(&mut other),
+        Err(Error::Pinned { addr: data.addr() })
+    );
+    assert_equal!(
+        data.as_pin_ref().map(|data| data.get_ref().clone()),
+        Some(String::from("data!"))
+    );
+
+    let null = UniquePointer::<String>::null();
+    assert_equal!(null.as_pin_ref(), None);
+    assert_equal!(null.is_address_pinned(), false);
+
+    other.freeze();
+    assert_equal!(other.as_pin_mut(), None);
+    assert_equal!(other.is_address_pinned(), false);
+}
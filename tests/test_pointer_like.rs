@@ -0,0 +1,104 @@
+use std::fmt::{Debug, Formatter};
+
+use k9::assert_equal;
+use unique_pointer::{
+    BoxPointer, BoxPointerFamily, PointerFamily, PointerLike, RcPointer, RcPointerFamily,
+    TestPointerFamily, UniquePointer, UniquePointerFamily,
+};
+
+pub struct Stack<F: PointerFamily> {
+    pub item: Option<u32>,
+    pub next: F::Pointer<Stack<F>>,
+}
+impl<F: PointerFamily> Stack<F> {
+    pub fn new() -> Stack<F> {
+        Stack {
+            item: None,
+            next: F::Pointer::null(),
+        }
+    }
+    pub fn push(&mut self, item: u32) {
+        let previous = Stack {
+            item: self.item.take(),
+            next: self.next.clone_shared(),
+        };
+        self.item = Some(item);
+        self.next = F::Pointer::null();
+        self.next.write(previous);
+    }
+    pub fn items(&self) -> Vec<u32> {
+        let mut items = Vec::from_iter(self.item);
+        if let Some(next) = PointerLike::as_ref(&self.next) {
+            items.extend(next.items());
+        }
+        items
+    }
+}
+impl<F: PointerFamily> Default for Stack<F> {
+    fn default() -> Stack<F> {
+        Stack::new()
+    }
+}
+impl<F: PointerFamily> Clone for Stack<F> {
+    fn clone(&self) -> Stack<F> {
+        Stack {
+            item: self.item,
+            next: self.next.clone_shared(),
+        }
+    }
+}
+impl<F: PointerFamily> Debug for Stack<F> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "Stack{:?}", self.items())
+    }
+}
+
+fn stack_of<F: PointerFamily>() -> Stack<F> {
+    let mut stack = Stack::<F>::new();
+    stack.push(1);
+    stack.push(2);
+    stack.push(3);
+    stack
+}
+
+#[test]
+fn test_pointer_like_generic_structure() {
+    assert_equal!(stack_of::<UniquePointerFamily>().items(), vec![3, 2, 1]);
+    assert_equal!(stack_of::<BoxPointerFamily>().items(), vec![3, 2, 1]);
+    assert_equal!(stack_of::<RcPointerFamily>().items(), vec![3, 2, 1]);
+    assert_equal!(stack_of::<TestPointerFamily>().items(), vec![3, 2, 1]);
+}
+
+#[test]
+fn test_pointer_like_null() {
+    assert_equal!(PointerLike::is_null(&UniquePointer::<u8>::null()), true);
+    assert_equal!(PointerLike::is_null(&BoxPointer::<u8>::null()), true);
+    assert_equal!(PointerLike::is_null(&RcPointer::<u8>::null()), true);
+    assert_equal!(PointerLike::is_null(&RcPointer::new(1u8)), false);
+}
+
+#[test]
+fn test_pointer_like_clone_shared_unique_pointer() {
+    let mut pointer = UniquePointer::from(1u8);
+    let shared = PointerLike::clone_shared(&pointer);
+    *PointerLike::as_mut(&mut pointer).unwrap() = 2;
+    assert_equal!(PointerLike::as_ref(&shared), Some(&2));
+}
+
+#[test]
+fn test_pointer_like_clone_shared_box_pointer_copies() {
+    let mut pointer = BoxPointer::new(1u8);
+    let shared = pointer.clone_shared();
+    *pointer.as_mut().unwrap() = 2;
+    assert_equal!(shared.as_ref(), Some(&1));
+}
+
+#[test]
+fn test_pointer_like_clone_shared_rc_pointer() {
+    let mut pointer = RcPointer::new(1u8);
+    assert_equal!(pointer.as_mut().is_some(), true);
+    let shared = pointer.clone_shared();
+    assert_equal!(pointer.refs(), 2);
+    assert_equal!(pointer.as_mut(), None);
+    assert_equal!(shared.as_ref(), Some(&1));
+}
@@ -0,0 +1,123 @@
+use k9::assert_equal;
+use unique_pointer::pointer_map::PointerMap;
+use unique_pointer::{free_graph, GraphNode, UniquePointer};
+
+#[derive(Debug)]
+struct Node {
+    value: u32,
+    next: UniquePointer<Node>,
+}
+
+impl GraphNode for Node {
+    fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+        vec![("next", &self.next)]
+    }
+
+    fn label(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[test]
+fn test_pointer_map_evicts_released_memory() {
+    let mut marks = PointerMap::<u32, bool>::new();
+    let node = UniquePointer::from(1u32);
+    let clone = node.clone();
+    let copy = UniquePointer::read_only(node.as_ref().unwrap());
+    let addr = node.addr();
+    assert_equal!(marks.insert(&node, false), None);
+    assert_equal!(marks.insert(&clone, true), Some(false));
+    assert_equal!(marks.insert(&UniquePointer::null(), true), None);
+    assert_equal!(marks.addrs(), vec![addr]);
+
+    drop(copy);
+    drop(clone);
+    assert_equal!(marks.get(&node).as_deref(), Some(&true));
+    drop(node);
+    assert_equal!(marks.is_empty(), true);
+}
+
+#[test]
+fn test_pointer_map_get_mut_and_remove() {
+    let mut visits = PointerMap::<u32, usize>::new();
+    let mut nodes = (0..3).map(UniquePointer::from).collect::<Vec<_>>();
+    for node in &nodes {
+        visits.insert(node, 0);
+    }
+    for node in nodes.iter().chain(&nodes[1..]) {
+        *visits.get_mut(node).unwrap() += 1;
+    }
+    assert_equal!(
+        nodes
+            .iter()
+            .map(|node| *visits.get(node).unwrap())
+            .collect::<Vec<_>>(),
+        vec![1, 2, 2]
+    );
+    assert_equal!(visits.remove(&nodes[0]), Some(1));
+    assert_equal!(visits.contains_key(&nodes[0]), false);
+
+    nodes[1].dealloc(false);
+    assert_equal!(visits.len(), 1);
+    visits.clear();
+    assert_equal!(visits.is_empty(), true);
+}
+
+#[test]
+fn test_pointer_map_entries_of_several_maps() {
+    let mut colors = PointerMap::<u32, &str>::new();
+    let mut names = PointerMap::<u32, String>::new();
+    let mut node = UniquePointer::from(1u32);
+    colors.insert(&node, "red");
+    names.insert(&node, String::from("one"));
+    {
+        let mut dropped = PointerMap::<u32, u8>::new();
+        dropped.insert(&node, 1);
+    }
+    assert_equal!(node.take(), Some(1));
+    assert_equal!((colors.len(), names.len()), (0, 0));
+}
+
+#[test]
+fn test_pointer_map_values_releasing_pointers() {
+    let mut children = PointerMap::<u32, UniquePointer<u32>>::new();
+    let mut depths = PointerMap::<u32, usize>::new();
+    let parent = UniquePointer::from(1u32);
+    let child = UniquePointer::from(2u32);
+    depths.insert(&child, 1);
+    children.insert(&parent, child);
+    drop(parent);
+    assert_equal!(children.is_empty(), true);
+    assert_equal!(depths.is_empty(), true);
+}
+
+#[test]
+fn test_pointer_map_evicts_memory_freed_as_a_graph() {
+    let mut marks = PointerMap::<Node, bool>::new();
+    let tail = UniquePointer::from(Node {
+        value: 2,
+        next: UniquePointer::null(),
+    });
+    let mut head = UniquePointer::from(Node {
+        value: 1,
+        next: tail.clone(),
+    });
+    let clone = head.clone();
+    marks.insert(&head, false);
+    marks.insert(&tail, true);
+
+    // `clone` and `tail` keep the reference counts above zero
+    assert_equal!(unsafe { head.drop_with(|_| {}) }, 2);
+    assert_equal!(marks.is_empty(), true);
+    drop((clone, tail));
+
+    let head = UniquePointer::from(Node {
+        value: 3,
+        next: UniquePointer::null(),
+    });
+    let clone = head.clone();
+    marks.insert(&head, true);
+    assert_equal!(unsafe { free_graph(&mut [head]) }, 1);
+    assert_equal!(marks.is_empty(), true);
+    drop(clone);
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "rc-compat")]
+use std::rc::Rc;
+use std::sync::Arc;
+
+use k9::assert_equal;
+use unique_pointer::UniquePointer;
+
+#[test]
+fn test_rc_compat_mirrors_rc() {
+    let mut data = UniquePointer::new(vec![1, 2]);
+    assert_equal!(UniquePointer::strong_count(&data), 1);
+    UniquePointer::get_mut(&mut data).unwrap().push(3);
+
+    let clone = data.clone();
+    assert_equal!(UniquePointer::strong_count(&data), 2);
+    assert_equal!(UniquePointer::strong_count(&clone), 2);
+    assert_equal!(UniquePointer::get_mut(&mut data), None);
+
+    let clone = UniquePointer::try_unwrap(clone).unwrap_err();
+    assert_equal!(clone.as_slice(), &[1, 2, 3]);
+    drop(clone);
+    assert_equal!(UniquePointer::strong_count(&data), 1);
+    assert_equal!(UniquePointer::try_unwrap(data), Ok(vec![1, 2, 3]));
+}
+
+#[test]
+fn test_rc_compat_refuses_null_and_frozen() {
+    let mut null = UniquePointer::<u8>::null();
+    assert_equal!(UniquePointer::get_mut(&mut null), None);
+    assert_equal!(UniquePointer::try_unwrap(null).is_err(), true);
+
+    let mut frozen = UniquePointer::new(1u8);
+    frozen.freeze();
+    assert_equal!(UniquePointer::get_mut(&mut frozen), None);
+    assert_equal!(UniquePointer::try_unwrap(frozen).is_err(), true);
+}
+
+#[test]
+fn test_rc_compat_converts_from_and_into_rc() {
+    let rc = Rc::new(String::from("rc"));
+    let shared = rc.clone();
+    let data: UniquePointer<String> = rc.into();
+    assert_equal!(Rc::strong_count(&shared), 1);
+    assert_equal!(data.as_str(), "rc");
+
+    let clone = data.clone();
+    let data = data.try_into_rc().unwrap_err();
+    drop(clone);
+    assert_equal!(data.try_into_rc(), Ok(Rc::new(String::from("rc"))));
+
+    let data = UniquePointer::<Vec<u8>>::from(Arc::new(vec![1u8]));
+    assert_equal!(data.try_into_arc(), Ok(Arc::new(vec![1u8])));
+    assert_equal!(UniquePointer::<u8>::null().try_into_arc().is_err(), true);
+}
@@ -0,0 +1,115 @@
+use std::rc::Rc;
+
+use k9::assert_equal;
+use unique_pointer::collections::SplayTree;
+
+#[test]
+fn test_splay_tree_splays_accessed_values() {
+    let mut tree = SplayTree::new();
+    assert_equal!(tree.root(), None);
+    assert_equal!(tree.contains(&1), false);
+
+    for value in [50, 30, 70, 20, 40, 60, 80] {
+        assert_equal!(tree.insert(value), true);
+        assert_equal!(tree.root(), Some(&value));
+    }
+    assert_equal!(tree.insert(40), false);
+    assert_equal!(tree.root(), Some(&40));
+    assert_equal!(tree.len(), 7);
+
+    assert_equal!(tree.contains(&20), true);
+    assert_equal!(tree.root(), Some(&20));
+    assert_equal!(tree.contains(&65), false);
+    assert_equal!(tree.root() == Some(&60) || tree.root() == Some(&70), true);
+
+    assert_equal!(
+        tree.iter().copied().collect::<Vec<u32>>(),
+        vec![20, 30, 40, 50, 60, 70, 80]
+    );
+}
+
+#[test]
+fn test_splay_tree_remove() {
+    let mut tree = (0..100u32)
+        .map(|value| (value * 37) % 100)
+        .collect::<SplayTree<u32>>();
+    for value in (0..100u32).filter(|value| value % 4 != 0) {
+        assert_equal!(tree.remove(&value), Some(value));
+    }
+    assert_equal!(tree.remove(&1), None);
+    assert_equal!(tree.len(), 25);
+    assert_equal!(tree.is_bst(), Ok(()));
+    assert_equal!(
+        tree.iter().copied().collect::<Vec<u32>>(),
+        (0..100u32).step_by(4).collect::<Vec<u32>>()
+    );
+    for value in (0..100u32).step_by(4) {
+        assert_equal!(tree.contains(&value), true);
+        assert_equal!(tree.remove(&value), Some(value));
+    }
+    assert_equal!(tree.is_empty(), true);
+    assert_equal!(tree.root(), None);
+}
+
+#[test]
+fn test_splay_tree_sequential_access_is_linear() {
+    let count = 1000usize;
+    let mut tree = (0..count).collect::<SplayTree<usize>>();
+    // inserting in ascending order degenerates into a path which a
+    // single access then roughly halves in depth
+    assert_equal!(tree.rotations(), count - 1);
+
+    let before = tree.rotations();
+    for value in 0..count {
+        assert_equal!(tree.contains(&value), true);
+    }
+    // sequential access theorem: accessing every value in order
+    // takes O(n) rotations
+    assert_equal!(tree.rotations() - before < 5 * count, true);
+}
+
+#[test]
+fn test_splay_tree_amortized_access() {
+    let count = 1024usize;
+    let mut tree = (0..count)
+        .map(|value| (value * 389) % count)
+        .collect::<SplayTree<usize>>();
+    let before = tree.rotations();
+    let accesses = 10 * count;
+    for step in 0..accesses {
+        tree.contains(&((step * 577) % count));
+    }
+    // O(m log n) rotations for m accesses over n values
+    let log = (count as f64).log2() as usize;
+    assert_equal!(tree.rotations() - before < 3 * accesses * log, true);
+}
+
+#[test]
+fn test_splay_tree_drops_values() {
+    let value = Rc::new(0);
+    {
+        let mut tree = SplayTree::new();
+        for _ in 0..3 {
+            tree.insert(value.clone());
+        }
+        assert_equal!(tree.len(), 1);
+        assert_equal!(Rc::strong_count(&value), 2);
+    }
+    assert_equal!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn test_splay_tree_is_bst_reports_reordered_values() {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Key(std::cell::Cell<u32>);
+
+    let tree = (0..10u32)
+        .map(|value| Key(std::cell::Cell::new(value)))
+        .collect::<SplayTree<Key>>();
+    assert_equal!(tree.is_bst(), Ok(()));
+
+    let key = tree.iter().nth(4).unwrap();
+    key.0.set(7);
+    let (previous, value) = tree.is_bst().unwrap_err();
+    assert_equal!((previous.0.get(), value.0.get()), (7, 5));
+}
@@ -0,0 +1,38 @@
+use k9::assert_equal;
+use unique_pointer::collections::{Queue, Stack};
+
+#[test]
+fn test_stack() {
+    let mut stack = (1..=3u32).collect::<Stack<u32>>();
+    assert_equal!(stack.len(), 3);
+    assert_equal!(stack.peek(), Some(&3));
+    if let Some(top) = stack.peek_mut() {
+        *top *= 10;
+    }
+    stack.push(4);
+    assert_equal!(format!("{:?}", stack), "[1, 2, 30, 4]");
+    assert_equal!(stack.pop(), Some(4));
+    assert_equal!(stack.pop(), Some(30));
+    assert_equal!(stack.pop(), Some(2));
+    assert_equal!(stack.pop(), Some(1));
+    assert_equal!(stack.pop(), None);
+    assert_equal!(stack.is_empty(), true);
+}
+
+#[test]
+fn test_queue() {
+    let mut queue = Queue::default();
+    assert_equal!(queue.front(), None);
+    queue.extend(["a", "b"]);
+    queue.enqueue("c");
+    assert_equal!(format!("{:?}", queue), r#"["a", "b", "c"]"#);
+    assert_equal!(queue.dequeue(), Some("a"));
+    if let Some(front) = queue.front_mut() {
+        *front = "B";
+    }
+    assert_equal!(queue.front(), Some(&"B"));
+    assert_equal!(queue.len(), 2);
+    assert_equal!(queue.dequeue(), Some("B"));
+    assert_equal!(queue.dequeue(), Some("c"));
+    assert_equal!(queue.dequeue(), None);
+}
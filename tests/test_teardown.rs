@@ -0,0 +1,107 @@
+use std::fmt::Formatter;
+
+use k9::assert_equal;
+use unique_pointer::{Error, GraphNode, TeardownPlan, UniquePointer};
+
+#[derive(Debug)]
+struct Node {
+    value: char,
+    parent: UniquePointer<Node>,
+    left: UniquePointer<Node>,
+    right: UniquePointer<Node>,
+}
+
+impl GraphNode for Node {
+    fn edges(&self) -> Vec<(&'static str, &UniquePointer<Node>)> {
+        vec![
+            ("parent", &self.parent),
+            ("left", &self.left),
+            ("right", &self.right),
+        ]
+    }
+
+    fn children(&self) -> Vec<&UniquePointer<Node>> {
+        vec![&self.left, &self.right]
+    }
+
+    fn label(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+fn node(value: char) -> Node {
+    Node {
+        value,
+        parent: UniquePointer::null(),
+        left: UniquePointer::null(),
+        right: UniquePointer::null(),
+    }
+}
+
+#[test]
+fn test_teardown_plan_tears_down_children_first() {
+    let [mut a, mut b, mut c, mut d] = ['A', 'B', 'C', 'D'].map(node);
+    let mut torn_down = Vec::new();
+    let plan = TeardownPlan::new()
+        .node('A', &mut a)
+        .node('D', &mut d)
+        .node('B', &mut b)
+        .node('C', &mut c)
+        .child('A', 'B')
+        .child('A', 'C')
+        .child('B', 'D')
+        .child('B', 'Z');
+    assert_equal!(plan.len(), 4);
+    let order = plan
+        .order()
+        .unwrap()
+        .into_iter()
+        .copied()
+        .collect::<Vec<_>>();
+    let position = |value| order.iter().position(|key| *key == value);
+    assert_equal!(position('D') < position('B'), true);
+    assert_equal!(position('B') < position('A'), true);
+    assert_equal!(position('C') < position('A'), true);
+
+    assert_equal!(plan.execute(|node| torn_down.push(node.value)), Ok(4));
+    assert_equal!(torn_down, order);
+}
+
+#[test]
+fn test_teardown_plan_records_children_of_graph_nodes() {
+    let [mut a, mut b, mut c] = ['A', 'B', 'C'].map(node);
+    b.left = UniquePointer::read_only(&c);
+    a.right = UniquePointer::read_only(&b);
+    a.left = UniquePointer::read_only(&c);
+
+    let mut torn_down = Vec::new();
+    let plan = TeardownPlan::new()
+        .node(1, &mut a)
+        .node(2, &mut b)
+        .node(3, &mut c)
+        .record_children();
+    assert_equal!(plan.order(), Ok(vec![&3, &2, &1]));
+    assert_equal!(plan.execute(|node| torn_down.push(node.value)), Ok(3));
+    assert_equal!(torn_down, vec!['C', 'B', 'A']);
+}
+
+#[test]
+fn test_teardown_plan_refuses_cycles() {
+    let [mut a, mut b] = ['A', 'B'].map(node);
+    let addr = std::ptr::from_ref(&a).addr();
+    let mut torn_down = 0;
+    let result = TeardownPlan::new()
+        .node('A', &mut a)
+        .node('B', &mut b)
+        .child('A', 'B')
+        .child('B', 'A')
+        .execute(|_| torn_down += 1);
+    assert_equal!(
+        result,
+        Err(Error::Invariant {
+            addr,
+            invariant: "parent/child relationships without cycles",
+        })
+    );
+    assert_equal!(torn_down, 0);
+}
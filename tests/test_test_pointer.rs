@@ -0,0 +1,58 @@
+use k9::assert_equal;
+use unique_pointer::{PointerLike, TestPointer};
+
+#[test]
+fn test_null_test_pointer() {
+    let pointer = TestPointer::<u8>::null();
+    assert_equal!(pointer.is_null(), true);
+    assert_equal!(pointer.is_written(), false);
+    assert_equal!(pointer.refs(), 0);
+    assert_equal!(pointer.try_read(), None);
+}
+
+#[test]
+fn test_test_pointer_write_is_shared_by_clones() {
+    let mut pointer = TestPointer::from(String::from("before"));
+    let shared = pointer.clone();
+    assert_equal!(pointer.refs(), 2);
+    assert_equal!(pointer.addr(), shared.addr());
+
+    pointer.write(String::from("after"));
+    assert_equal!(shared.read(), "after");
+}
+
+#[test]
+fn test_test_pointer_drop_in_place_only_nullifies_self() {
+    let mut pointer = TestPointer::from(1u8);
+    let shared = pointer.clone();
+    pointer.drop_in_place();
+    assert_equal!(pointer.is_null(), true);
+    assert_equal!(shared.read(), 1);
+    assert_equal!(shared.refs(), 1);
+}
+
+#[test]
+fn test_test_pointer_swap() {
+    let mut lhs = TestPointer::from(1u8);
+    let mut rhs = TestPointer::null();
+    lhs.swap(&mut rhs);
+    assert_equal!(lhs.is_null(), true);
+    assert_equal!(rhs.read(), 1);
+}
+
+#[test]
+#[should_panic(expected = "already borrowed")]
+fn test_test_pointer_reports_aliasing() {
+    let mut pointer = TestPointer::from(1u8);
+    let shared = pointer.clone();
+    let _read = shared.inner_ref();
+    *pointer.inner_mut() = 2;
+}
+
+#[test]
+fn test_test_pointer_is_pointer_like() {
+    let mut pointer = <TestPointer<u8> as PointerLike<u8>>::null();
+    PointerLike::write(&mut pointer, 3);
+    *PointerLike::as_mut(&mut pointer).unwrap() += 1;
+    assert_equal!(*PointerLike::as_ref(&pointer).unwrap(), 4);
+}
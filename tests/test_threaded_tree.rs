@@ -0,0 +1,68 @@
+use std::rc::Rc;
+
+use k9::assert_equal;
+use unique_pointer::collections::ThreadedTree;
+
+#[test]
+fn test_threaded_tree_insert_and_traverse() {
+    let mut tree = ThreadedTree::new();
+    assert_equal!(tree.first(), None);
+    assert_equal!(tree.iter().next(), None);
+
+    for value in [50, 30, 70, 20, 40, 60, 80, 35, 65] {
+        assert_equal!(tree.insert(value), true);
+    }
+    assert_equal!(tree.insert(40), false);
+    assert_equal!(tree.len(), 9);
+    assert_equal!(tree.is_bst(), Ok(()));
+    assert_equal!(tree.contains(&35), true);
+    assert_equal!(tree.contains(&36), false);
+    assert_equal!(tree.first(), Some(&20));
+    assert_equal!(tree.last(), Some(&80));
+
+    assert_equal!(
+        tree.iter().copied().collect::<Vec<u32>>(),
+        vec![20, 30, 35, 40, 50, 60, 65, 70, 80]
+    );
+    assert_equal!(
+        tree.iter().rev().copied().collect::<Vec<u32>>(),
+        vec![80, 70, 65, 60, 50, 40, 35, 30, 20]
+    );
+
+    let mut iter = tree.iter();
+    assert_equal!(iter.next(), Some(&20));
+    assert_equal!(iter.next_back(), Some(&80));
+    assert_equal!(iter.len(), 7);
+    assert_equal!(iter.collect::<Vec<&u32>>().len(), 7);
+}
+
+#[test]
+fn test_threaded_tree_successor_and_predecessor() {
+    let tree = (0..64u32)
+        .map(|value| (value * 29) % 64)
+        .collect::<ThreadedTree<u32>>();
+    for value in 0..64u32 {
+        assert_equal!(
+            tree.successor(&value),
+            (value < 63).then_some(value + 1).as_ref()
+        );
+        assert_equal!(tree.predecessor(&value), value.checked_sub(1).as_ref());
+    }
+    assert_equal!(tree.successor(&64), None);
+}
+
+#[test]
+fn test_threaded_tree_drops_values() {
+    let value = Rc::new(());
+    {
+        let tree = (0..100)
+            .map(|index| (index, value.clone()))
+            .collect::<ThreadedTree<(u32, Rc<()>)>>();
+        assert_equal!(Rc::strong_count(&value), 101);
+        assert_equal!(tree.iter().count(), 100);
+    }
+    assert_equal!(Rc::strong_count(&value), 1);
+
+    let tree = (0..10_000u32).collect::<ThreadedTree<u32>>();
+    assert_equal!(tree.iter().next_back(), Some(&9_999));
+}
@@ -0,0 +1,56 @@
+use k9::assert_equal;
+use unique_pointer::collections::UnionFind;
+
+#[test]
+fn test_union_find_union_and_find() {
+    let mut sets = UnionFind::new(6);
+    assert_equal!(sets.len(), 6);
+    assert_equal!(sets.sets(), 6);
+    assert_equal!(sets.find(4), 4);
+
+    assert_equal!(sets.union(0, 1), true);
+    assert_equal!(sets.union(1, 0), false);
+    assert_equal!(sets.union(2, 3), true);
+    assert_equal!(sets.union(4, 5), true);
+    assert_equal!(sets.sets(), 3);
+
+    assert_equal!(sets.same_set(0, 1), true);
+    assert_equal!(sets.same_set(0, 2), false);
+
+    assert_equal!(sets.union(3, 5), true);
+    assert_equal!(sets.same_set(2, 4), true);
+    assert_equal!(sets.same_set(1, 5), false);
+    assert_equal!(sets.sets(), 2);
+
+    let element = sets.make_set();
+    assert_equal!(element, 6);
+    assert_equal!(sets.sets(), 3);
+    assert_equal!(sets.same_set(6, 0), false);
+}
+
+#[test]
+fn test_union_find_union_by_rank_and_path_compression() {
+    let mut sets = UnionFind::new(8);
+    // builds a tree of rank 3 rooted at 0
+    sets.union(0, 1);
+    sets.union(2, 3);
+    sets.union(4, 5);
+    sets.union(6, 7);
+    sets.union(0, 2);
+    sets.union(4, 6);
+    sets.union(0, 4);
+
+    assert_equal!(sets.parent(0), None);
+    assert_equal!(sets.parent(7), Some(6));
+    assert_equal!(sets.parent(6), Some(4));
+    assert_equal!(sets.parent(4), Some(0));
+
+    assert_equal!(sets.find(7), 0);
+    assert_equal!(sets.parent(7), Some(0));
+    assert_equal!(sets.parent(6), Some(0));
+
+    // the lower ranked root goes below the higher ranked one
+    let single = sets.make_set();
+    sets.union(single, 7);
+    assert_equal!(sets.find(single), 0);
+}
@@ -1,9 +1,10 @@
+#![cfg(not(feature = "no-panic"))]
 use std::borrow::Cow;
 use std::cmp::PartialEq;
 use std::fmt::Debug;
 
 use k9::assert_equal;
-use unique_pointer::UniquePointer;
+use unique_pointer::{Error, OnDrop, UniquePointer};
 
 #[derive(Clone, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
 pub enum Value<'t> {
@@ -239,6 +240,62 @@ fn test_unique_pointer_clone() {
     assert_equal!(clone.value.as_mut(), Some(&mut Value::from("updated")));
 }
 
+#[test]
+fn test_unique_pointer_write_exclusive_and_shared() {
+    let mut data = Data {
+        value: UniquePointer::from(Value::from("string")),
+    };
+    assert_equal!(data.value.write_exclusive(Value::from("exclusive")), Ok(()));
+
+    let clone = data.clone();
+    assert_equal!(data.value.refs(), 2);
+    assert_equal!(
+        data.value.write_exclusive(Value::from("aliased")),
+        Err(Error::Shared {
+            addr: data.value.addr(),
+            refs: 2
+        })
+    );
+    assert_equal!(clone.value.read(), Value::from("exclusive"));
+
+    assert_equal!(data.value.write_shared(Value::from("shared")), 1);
+    assert_equal!(clone.value.read(), Value::from("shared"));
+
+    drop(clone);
+    assert_equal!(data.value.write_shared(Value::from("alone")), 0);
+    assert_equal!(data.value.write_exclusive(Value::from("exclusive")), Ok(()));
+}
+
+#[test]
+fn test_unique_pointer_on_drop() {
+    let mut data = UniquePointer::from(String::from("data"));
+    assert_equal!(data.on_drop(), OnDrop::Release);
+    data.set_on_drop(OnDrop::Panic);
+
+    let clone = data.clone();
+    assert_equal!(clone.on_drop(), OnDrop::Release);
+    drop(clone);
+    assert_equal!(data.refs(), 1);
+
+    let mut leaked = data.clone();
+    leaked.set_on_drop(OnDrop::Leak);
+    drop(leaked);
+    assert_equal!(data.refs(), 2);
+
+    let mut copy = UniquePointer::read_only(data.inner_ref());
+    copy.set_on_drop(OnDrop::Panic);
+    drop(copy);
+    assert_equal!(data.as_str(), "data");
+}
+
+#[test]
+#[should_panic(expected = "dropped while owning its memory")]
+fn test_unique_pointer_on_drop_panic() {
+    let mut data = UniquePointer::from(1u8);
+    data.set_on_drop(OnDrop::Panic);
+    drop(data);
+}
+
 #[test]
 fn test_unique_pointer_null() {
     let data = Data {
@@ -577,6 +634,312 @@ fn test_hash<'t>() {
         UniquePointer::from(Value::from("three")),
     );
 }
+#[test]
+fn test_read_write_seek_forwarding() {
+    use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+
+    let mut up = UniquePointer::from(Cursor::new(Vec::<u8>::new()));
+    up.write_all(b"unique pointer").unwrap();
+    up.flush().unwrap();
+    assert_equal!(up.seek(SeekFrom::Start(7)).unwrap(), 7);
+
+    let mut data = String::new();
+    up.read_to_string(&mut data).unwrap();
+    assert_equal!(data, "pointer");
+}
+
+#[test]
+fn test_io_forwarding_on_null() {
+    use std::io::{Cursor, ErrorKind, Read, Write};
+
+    let mut up = UniquePointer::<Cursor<Vec<u8>>>::null();
+    let mut buf = [0u8; 4];
+    assert_equal!(
+        Read::read(&mut up, &mut buf).unwrap_err().kind(),
+        ErrorKind::InvalidInput
+    );
+    assert_equal!(up.flush().unwrap_err().kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn test_iterator_forwarding() {
+    let mut up = UniquePointer::from(vec![1, 2, 3].into_iter());
+    assert_equal!(up.size_hint(), (3, Some(3)));
+    assert_equal!(up.next(), Some(1));
+    assert_equal!(up.collect::<Vec<_>>(), vec![2, 3]);
+
+    let null = UniquePointer::<std::vec::IntoIter<u8>>::null();
+    assert_equal!(null.count(), 0);
+}
+#[test]
+fn test_as_slice_and_as_str() {
+    let mut up = UniquePointer::from(vec![3u8, 2, 1]);
+    up.as_mut_slice().sort();
+    assert_equal!(up.as_slice(), &[1, 2, 3]);
+
+    let mut null = UniquePointer::<Vec<u8>>::null();
+    assert_equal!(null.as_mut_slice().len(), 0);
+
+    let up = UniquePointer::from(String::from("string"));
+    assert_equal!(up.as_str(), "string");
+    assert_equal!(UniquePointer::<String>::null().as_str(), "");
+}
+
+#[test]
+fn test_box_interop_hands_memory_over() {
+    let boxed = Box::new(String::from("boxed"));
+    let addr = std::ptr::from_ref(boxed.as_ref()).addr();
+    let up = UniquePointer::from_box(boxed);
+    assert_equal!(up.addr(), addr);
+    assert_equal!(up.as_str(), "boxed");
+
+    let clone = up.clone();
+    let up = up.try_into_box().unwrap_err();
+    drop(clone);
+    let weak = up.downgrade();
+    let boxed = up.try_into_box().unwrap();
+    assert_equal!(std::ptr::from_ref(boxed.as_ref()).addr(), addr);
+    assert_equal!(boxed.as_str(), "boxed");
+    assert_equal!(weak.upgrade(), None);
+
+    let mut aligned = UniquePointer::<u8>::null();
+    aligned.alloc_aligned(64);
+    aligned.write(1);
+    assert_equal!(aligned.try_into_box().unwrap_err().read(), 1);
+    assert_equal!(UniquePointer::<u8>::null().try_into_box().is_err(), true);
+}
+
+#[test]
+fn test_new_uninit_and_assume_init() {
+    let mut values = UniquePointer::<[u32; 4]>::new_uninit();
+    assert_equal!(values.is_written(), true);
+    let addr = values.addr();
+    let ptr = values.inner_mut().as_mut_ptr();
+    for index in 0..4 {
+        unsafe { (&raw mut (*ptr)[index]).write(index as u32 * 2) };
+    }
+    let clone = values.clone();
+    let mut values = unsafe { values.assume_init() };
+    assert_equal!(values.addr(), addr);
+    assert_equal!(values.refs(), 2);
+    drop(clone);
+    assert_equal!(values.take(), Some([0, 2, 4, 6]));
+
+    let unit = unsafe { UniquePointer::<()>::new_uninit().assume_init() };
+    assert_equal!(unit.read(), ());
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[repr(align(32))]
+struct Marker;
+
+#[test]
+fn test_zero_sized_types() {
+    let mut unit = UniquePointer::<()>::null();
+    unit.alloc();
+    assert_equal!(unit.is_allocated(), true);
+    assert_equal!(unit.addr() % std::mem::align_of::<()>(), 0);
+    unit.write(());
+    assert_equal!(unit.read(), ());
+    assert_equal!(unit.take(), Some(()));
+    assert_equal!(unit.is_null(), true);
+
+    let mut marker = UniquePointer::from(Marker);
+    let clone = marker.clone();
+    assert_equal!(marker.addr() % 32, 0);
+    assert_equal!(clone.inner_ref(), &Marker);
+    drop(clone);
+    assert_equal!(marker.take(), Some(Marker));
+
+    let mut aligned = UniquePointer::<()>::null();
+    aligned.alloc_aligned(64);
+    aligned.write(());
+    assert_equal!(aligned.addr() % 64, 0);
+    assert_equal!(aligned.into_box(), Some(Box::new(())));
+
+    let mut units = (0..3).map(|_| UniquePointer::from(())).collect::<Vec<_>>();
+    UniquePointer::dealloc_many(&mut units);
+    assert_equal!(units.iter().all(UniquePointer::is_null), true);
+}
+
+#[test]
+fn test_addr_and_ptr() {
+    let mut up = UniquePointer::from(7u8);
+    let (addr, ptr) = up.addr_and_ptr();
+    assert_equal!(addr, up.addr());
+    assert_equal!(ptr.addr(), addr);
+    up.debug_assert_provenance();
+
+    let copy = UniquePointer::read_only(up.as_ref().unwrap());
+    assert_equal!(copy.addr_and_ptr(), up.addr_and_ptr());
+    copy.debug_assert_provenance();
+
+    up.drop_in_place();
+    let (addr, ptr) = UniquePointer::<u8>::null().addr_and_ptr();
+    assert_equal!((addr, ptr.is_null()), (0, true));
+}
+
+#[test]
+fn test_non_null_conversions() {
+    let mut up = UniquePointer::from(String::from("non-null"));
+    let ptr = up.as_non_null().unwrap();
+    assert_equal!(ptr.addr().get(), up.addr());
+
+    let copy = UniquePointer::<String>::from(ptr);
+    assert_equal!(copy.is_copy(), true);
+    assert_equal!(copy.as_str(), "non-null");
+    drop(copy);
+
+    let counted = UniquePointer::copy_from_non_null(ptr, 3);
+    assert_equal!((counted.refs(), counted.can_dealloc()), (3, false));
+    drop(counted);
+
+    assert_equal!(up.take(), Some(String::from("non-null")));
+    assert_equal!(up.as_non_null(), None);
+}
+
+#[test]
+fn test_clone_many_and_dealloc_many() {
+    let mut frontier = vec![
+        UniquePointer::from(1u8),
+        UniquePointer::from(2u8),
+        UniquePointer::null(),
+    ];
+    frontier.push(frontier[0].clone());
+    let mut snapshot = UniquePointer::clone_many(&frontier);
+    assert_equal!(snapshot.len(), 4);
+    assert_equal!(snapshot.capacity(), 4);
+    assert_equal!(
+        snapshot.iter().map(UniquePointer::addr).collect::<Vec<_>>(),
+        frontier.iter().map(UniquePointer::addr).collect::<Vec<_>>()
+    );
+    assert_equal!(frontier[0].refs(), 4);
+    assert_equal!(frontier[1].refs(), 2);
+    assert_equal!(snapshot[2].is_null(), true);
+
+    UniquePointer::dealloc_many(&mut snapshot);
+    assert_equal!(snapshot.iter().all(UniquePointer::is_null), true);
+    assert_equal!(frontier[0].refs(), 2);
+    assert_equal!(frontier[1].refs(), 1);
+    drop(snapshot);
+    assert_equal!(frontier[0].refs(), 2);
+    assert_equal!(frontier[1].try_read(), Some(2));
+}
+
+#[test]
+fn test_alloc_aligned() {
+    #[repr(align(64))]
+    struct CacheLine;
+
+    let mut data = UniquePointer::<u32>::with_alignment::<CacheLine>();
+    data.write(1);
+    assert_equal!(data.align(), 64);
+    assert_equal!(data.addr() % 64, 0);
+    let clone = data.clone();
+    assert_equal!(clone.align(), 64);
+    drop(clone);
+    assert_equal!(data.take(), Some(1));
+
+    // the alignment outlives the memory it was allocated with
+    data.write(2);
+    assert_equal!(data.addr() % 64, 0);
+
+    let mut data = UniquePointer::<u64>::null();
+    data.alloc_aligned(3000);
+    assert_equal!(data.align(), 4096);
+    assert_equal!(data.addr() % 4096, 0);
+    data.alloc_aligned(8192);
+    assert_equal!(data.align(), 4096);
+
+    let mut data = UniquePointer::<u64>::null();
+    data.alloc_aligned(1);
+    assert_equal!(data.align(), std::mem::align_of::<u64>());
+    assert_equal!(
+        UniquePointer::from(1u64).align(),
+        std::mem::align_of::<u64>()
+    );
+}
+
+#[test]
+fn test_prefetch() {
+    let mut data = UniquePointer::from(1u64);
+    data.prefetch_read();
+    data.prefetch_write();
+    assert_equal!(data.try_read(), Some(1));
+
+    let null = UniquePointer::<u64>::null();
+    null.prefetch_read();
+    null.prefetch_write();
+
+    // prefetching released memory is harmless
+    let copy = UniquePointer::read_only(data.as_ref().unwrap());
+    data.take();
+    copy.prefetch_read();
+}
+
+#[test]
+fn test_replace() {
+    let mut data = UniquePointer::<String>::null();
+    assert_equal!(data.replace(String::from("first")), None);
+    let clone = data.clone();
+    assert_equal!(
+        data.replace(String::from("second")),
+        Some(String::from("first"))
+    );
+    assert_equal!(clone.inner_ref().as_str(), "second");
+    assert_equal!(data.addr(), clone.addr());
+    drop(clone);
+    assert_equal!(data.take(), Some(String::from("second")));
+}
+
+#[test]
+fn test_get_or_insert_with() {
+    #[derive(Debug)]
+    struct Node {
+        value: u8,
+        left: UniquePointer<Node>,
+    }
+    impl Node {
+        fn new(value: u8) -> Node {
+            Node {
+                value,
+                left: UniquePointer::null(),
+            }
+        }
+    }
+
+    let mut root = Node::new(0);
+    root.left.get_or_insert_with(|| Node::new(0)).value = 1;
+    let addr = root.left.addr();
+    let left = root.left.get_or_insert_with(|| panic!("already written"));
+    assert_equal!(left.value, 1);
+    left.left.get_or_insert(Node::new(2));
+    assert_equal!(root.left.addr(), addr);
+    assert_equal!(root.left.inner_ref().left.inner_ref().value, 2);
+}
+
+#[test]
+fn test_ptr_eq_and_ref_eq() {
+    let data = UniquePointer::from(7u32);
+    let clone = data.clone();
+    let other = UniquePointer::from(7u32);
+    assert_equal!(data == other, true);
+    assert_equal!(data.ptr_eq(&other), false);
+    assert_equal!(data.ptr_eq(&clone), true);
+    assert_equal!(
+        data.ptr_eq(&UniquePointer::read_only(data.inner_ref())),
+        true
+    );
+
+    assert_equal!(data.ref_eq(clone.inner_ref()), true);
+    assert_equal!(data.ref_eq(other.inner_ref()), false);
+    assert_equal!(data.ref_eq(&7), false);
+    assert_equal!(
+        UniquePointer::<u32>::null().ptr_eq(&UniquePointer::null()),
+        true
+    );
+}
+
 #[macro_export]
 macro_rules! assert_nonzero {
     ($value:expr, $desc:literal) => {{
@@ -0,0 +1,88 @@
+#![cfg(not(feature = "no-panic"))]
+use k9::assert_equal;
+use unique_pointer::{UniquePointer, UniqueWeak};
+
+#[derive(Debug)]
+struct Node {
+    value: u32,
+    parent: UniqueWeak<Node>,
+    children: Vec<UniquePointer<Node>>,
+}
+
+impl Node {
+    fn new(value: u32) -> Node {
+        Node {
+            value,
+            parent: UniqueWeak::new(),
+            children: Vec::new(),
+        }
+    }
+}
+
+#[test]
+fn test_weak_back_edges_do_not_keep_parents_alive() {
+    let mut parent = UniquePointer::from(Node::new(1));
+    for value in 2..4 {
+        let mut child = UniquePointer::from(Node::new(value));
+        child.inner_mut().parent = parent.downgrade();
+        parent.inner_mut().children.push(child);
+    }
+    assert_equal!(parent.refs(), 1);
+    assert_equal!(parent.weak_count(), 2);
+
+    let children = parent.inner_ref().children.clone();
+    assert_equal!(
+        children
+            .iter()
+            .map(|child| child.parent.upgrade().map(|parent| parent.value))
+            .collect::<Vec<_>>(),
+        vec![Some(1), Some(1)]
+    );
+
+    let node = parent.take().unwrap();
+    assert_equal!(node.value, 1);
+    assert_equal!(
+        children
+            .iter()
+            .map(|child| child.parent.upgrade().is_none())
+            .collect::<Vec<_>>(),
+        vec![true, true]
+    );
+    assert_equal!(children[0].parent.strong_count(), 0);
+}
+
+#[test]
+fn test_weak_upgrade_keeps_memory_alive() {
+    let mut pointer = UniquePointer::from(7u32);
+    let weak = pointer.downgrade();
+    let upgraded = weak.upgrade().unwrap();
+    assert_equal!(weak.strong_count(), 2);
+    assert_equal!(pointer.take(), None);
+    drop(upgraded);
+    assert_equal!(weak.strong_count(), 1);
+    assert_equal!(pointer.take(), Some(7));
+    assert_equal!(weak.upgrade(), None);
+}
+
+#[test]
+fn test_weak_clone_and_drop_track_weak_count() {
+    let pointer = UniquePointer::from(String::from("weak"));
+    let weak = pointer.downgrade();
+    let clone = weak.clone();
+    assert_equal!(weak.addr(), pointer.addr());
+    assert_equal!(clone.weak_count(), 2);
+    drop(weak);
+    assert_equal!(pointer.weak_count(), 1);
+    drop(clone);
+    assert_equal!(pointer.weak_count(), 0);
+    assert_equal!(pointer.refs(), 1);
+}
+
+#[test]
+fn test_weak_new_never_upgrades() {
+    let weak = UniqueWeak::<u32>::default();
+    assert_equal!(weak.upgrade(), None);
+    assert_equal!(weak.strong_count(), 0);
+    assert_equal!(weak.weak_count(), 0);
+    assert_equal!(UniquePointer::<u32>::null().downgrade().upgrade(), None);
+}
@@ -0,0 +1,71 @@
+use std::rc::Rc;
+
+use k9::assert_equal;
+use unique_pointer::collections::XorList;
+
+#[test]
+fn test_xor_list_push_and_pop_at_both_ends() {
+    let mut list = XorList::new();
+    assert_equal!(list.front(), None);
+    assert_equal!(list.pop_back(), None);
+
+    for value in 0..5u32 {
+        list.push_back(value + 5);
+        list.push_front(4 - value);
+    }
+    assert_equal!(list.len(), 10);
+    assert_equal!(list.front(), Some(&0));
+    assert_equal!(list.back(), Some(&9));
+    assert_equal!(
+        list.iter().copied().collect::<Vec<u32>>(),
+        (0..10).collect::<Vec<u32>>()
+    );
+    assert_equal!(
+        list.iter().rev().copied().collect::<Vec<u32>>(),
+        (0..10).rev().collect::<Vec<u32>>()
+    );
+
+    if let Some(front) = list.front_mut() {
+        *front = 100;
+    }
+    assert_equal!(list.pop_front(), Some(100));
+    assert_equal!(list.pop_back(), Some(9));
+    assert_equal!(list.pop_back(), Some(8));
+    while list.pop_front().is_some() {}
+    assert_equal!(list.is_empty(), true);
+    assert_equal!(list.back(), None);
+
+    list.push_back(1);
+    assert_equal!(list.front(), Some(&1));
+    assert_equal!(list.back(), Some(&1));
+}
+
+#[test]
+fn test_xor_list_reverse_and_iterate_from_both_ends() {
+    let mut list = (1..=6u32).collect::<XorList<u32>>();
+    list.reverse();
+    assert_equal!(format!("{:?}", list), "[6, 5, 4, 3, 2, 1]");
+
+    list.push_back(0);
+    list.push_front(7);
+    let mut iter = list.iter();
+    assert_equal!(iter.next(), Some(&7));
+    assert_equal!(iter.next_back(), Some(&0));
+    assert_equal!(iter.next_back(), Some(&1));
+    assert_equal!(iter.len(), 5);
+    assert_equal!(iter.copied().collect::<Vec<u32>>(), vec![6, 5, 4, 3, 2]);
+}
+
+#[test]
+fn test_xor_list_drops_values() {
+    let value = Rc::new(());
+    {
+        let mut list = XorList::new();
+        for _ in 0..10 {
+            list.push_back(value.clone());
+        }
+        drop(list.pop_back());
+        assert_equal!(Rc::strong_count(&value), 10);
+    }
+    assert_equal!(Rc::strong_count(&value), 1);
+}